@@ -0,0 +1,17 @@
+//! HTTP API over the SolPlace indexer store
+//!
+//! Serves the placements inside a bounding box for a map zoom level. Zoomed in,
+//! cells are listed one by one; zoomed out, they are aggregated per Web Mercator
+//! tile. Lists are paginated with keyset cursors and every response carries an
+//! ETag so map clients can revalidate viewports cheaply. New placements and status
+//! changes are pushed to subscribers of a bounding box as server-sent events.
+
+pub mod error;
+pub mod routes;
+pub mod stream;
+pub mod tiles;
+
+pub use error::*;
+pub use routes::*;
+pub use stream::*;
+pub use tiles::*;
@@ -0,0 +1,96 @@
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::Parser;
+use solplace_api::*;
+use solplace_client::RpcClient;
+use solplace_indexer::{check_reorgs, sync, Store};
+
+#[derive(Parser)]
+#[command(
+    name = "solplace-api",
+    about = "Serve indexed SolPlace placements over HTTP"
+)]
+struct Cli {
+    /// SQLite database written by solplace-indexer
+    #[arg(long, default_value = "solplace.sqlite")]
+    db: PathBuf,
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    listen: SocketAddr,
+    /// Index the program from this JSON-RPC endpoint and stream new placements
+    #[arg(long)]
+    rpc: Option<String>,
+    /// Seconds between polls of `--rpc`
+    #[arg(long, default_value_t = 2)]
+    interval: u64,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    match run(Cli::parse()).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum CliError {
+    #[error(transparent)]
+    Api(#[from] ApiError),
+
+    #[error("{address}: {source}")]
+    Listen {
+        address: SocketAddr,
+        source: std::io::Error,
+    },
+}
+
+async fn run(cli: Cli) -> Result<(), CliError> {
+    let store = Store::open(&cli.db).map_err(ApiError::from)?;
+    let state = AppState::new(store);
+    if let Some(rpc) = cli.rpc {
+        // The indexer writes through its own connection, readers are not blocked on RPC
+        let store = Store::open(&cli.db).map_err(ApiError::from)?;
+        let feed = state.feed().clone();
+        let interval = Duration::from_secs(cli.interval);
+        std::thread::spawn(move || follow(store, RpcClient::new(rpc), feed, interval));
+    }
+
+    let listen = |source| CliError::Listen {
+        address: cli.listen,
+        source,
+    };
+    let listener = tokio::net::TcpListener::bind(cli.listen)
+        .await
+        .map_err(listen)?;
+    eprintln!("listening on {}", cli.listen);
+    axum::serve(listener, router(state)).await.map_err(listen)
+}
+
+/// Keep indexing the program, publishing what lands and what reorgs undo to stream subscribers
+fn follow(mut store: Store, client: RpcClient, feed: PlacementFeed, interval: Duration) {
+    // Start the feed at the end of the journal, older entries are served by replays
+    if let Err(err) = feed.publish(&store) {
+        eprintln!("warning: reading the journal failed: {err}");
+    }
+    loop {
+        let synced = check_reorgs(&mut store, &client)
+            .map(|rollback| {
+                if let Some(rollback) = rollback {
+                    feed.roll_back(&rollback);
+                }
+            })
+            .and_then(|()| sync(&mut store, &client))
+            .and_then(|_| feed.publish(&store));
+        if let Err(err) = synced {
+            eprintln!("warning: sync failed: {err}");
+        }
+        std::thread::sleep(interval);
+    }
+}
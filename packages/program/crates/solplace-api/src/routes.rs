@@ -0,0 +1,254 @@
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use anchor_lang::solana_program::hash::hash;
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+use serde::{Deserialize, Serialize};
+use solplace_client::BoundingBox;
+use solplace_indexer::{Cell, IndexerError, Store};
+
+use crate::*;
+
+/// Page size when the query does not set one
+pub const DEFAULT_LIMIT: usize = 500;
+/// Largest page served
+pub const MAX_LIMIT: usize = 1000;
+
+/// Shared handle on the indexer store and the live placement feed
+#[derive(Clone)]
+pub struct AppState {
+    store: Arc<Mutex<Store>>,
+    feed: PlacementFeed,
+}
+
+impl AppState {
+    pub fn new(store: Store) -> Self {
+        AppState::with_feed(store, PlacementFeed::default())
+    }
+
+    pub fn with_feed(store: Store, feed: PlacementFeed) -> Self {
+        AppState {
+            store: Arc::new(Mutex::new(store)),
+            feed,
+        }
+    }
+
+    pub fn feed(&self) -> &PlacementFeed {
+        &self.feed
+    }
+
+    /// Lock the store, a panicked handler leaves it consistent since writes are transactional
+    pub fn store(&self) -> MutexGuard<'_, Store> {
+        self.store.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Run `query` against the store on the blocking thread pool, so waiting on the lock or
+    /// on SQLite never stalls the async workers
+    pub async fn query<T: Send + 'static>(
+        &self,
+        query: impl FnOnce(&Store) -> Result<T, IndexerError> + Send + 'static,
+    ) -> Result<T, ApiError> {
+        let state = self.clone();
+        match tokio::task::spawn_blocking(move || query(&state.store())).await {
+            Ok(result) => Ok(result?),
+            Err(err) => std::panic::resume_unwind(err.into_panic()),
+        }
+    }
+}
+
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/placements", get(placements))
+        .route("/cells/{lat}/{lng}", get(cell))
+        .route("/stream", get(stream))
+        .with_state(state)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PlacementsQuery {
+    /// `min_lat,min_lng,max_lat,max_lng` in microdegrees, the whole world when absent
+    pub bbox: Option<String>,
+    pub zoom: u8,
+    pub limit: Option<usize>,
+    /// `next_cursor` of the previous page
+    pub cursor: Option<String>,
+}
+
+/// Current occupant of a cell
+#[derive(Debug, Serialize)]
+pub struct CellView {
+    pub lat: i32,
+    pub lng: i32,
+    pub token_mint: String,
+    pub logo_uri: String,
+    pub logo_hash: String,
+    pub placed_by: String,
+    pub placed_at: i64,
+    pub placements: u32,
+    pub slot: u64,
+    pub signature: String,
+    /// Reported past the hide threshold, clients should not render the logo
+    pub hidden: bool,
+}
+
+impl From<Cell> for CellView {
+    fn from(cell: Cell) -> Self {
+        CellView {
+            lat: cell.lat,
+            lng: cell.lng,
+            token_mint: cell.token_mint.to_string(),
+            logo_uri: cell.logo_uri,
+            logo_hash: hex::encode(cell.logo_hash),
+            placed_by: cell.placed_by.to_string(),
+            placed_at: cell.placed_at,
+            placements: cell.placements,
+            slot: cell.slot,
+            signature: cell.signature,
+            hidden: cell.hidden,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlacementItems {
+    /// Individual cells, from `DETAIL_ZOOM`
+    Cells(Vec<CellView>),
+    /// Cells aggregated per tile, below `DETAIL_ZOOM`
+    Tiles(Vec<TileSummary>),
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlacementsPage {
+    pub zoom: u8,
+    #[serde(flatten)]
+    pub items: PlacementItems,
+    /// Pass as `cursor` to fetch the next page, `null` on the last one
+    pub next_cursor: Option<String>,
+}
+
+async fn placements(
+    State(state): State<AppState>,
+    Query(query): Query<PlacementsQuery>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    if query.zoom > MAX_ZOOM {
+        return Err(ApiError::InvalidQuery(format!(
+            "zoom must be at most {MAX_ZOOM}"
+        )));
+    }
+    let bbox = match &query.bbox {
+        Some(bbox) => bbox.parse().map_err(ApiError::InvalidQuery)?,
+        None => BoundingBox::WORLD,
+    };
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let cursor = query.cursor.as_deref();
+
+    let page = if query.zoom >= DETAIL_ZOOM {
+        let after = cursor.map(parse_cell_cursor).transpose()?;
+        // One extra row tells whether another page follows
+        let mut cells = state
+            .query(move |store| store.cells_page(&bbox, after, limit + 1))
+            .await?;
+        let next_cursor = (cells.len() > limit).then(|| {
+            cells.truncate(limit);
+            let last = &cells[limit - 1];
+            format!("{},{}", last.lat, last.lng)
+        });
+        PlacementsPage {
+            zoom: query.zoom,
+            items: PlacementItems::Cells(cells.into_iter().map(CellView::from).collect()),
+            next_cursor,
+        }
+    } else {
+        let after = cursor.map(parse_cursor).transpose()?;
+        let zoom = query.zoom;
+        let mut tiles: Vec<TileSummary> = state
+            .query(move |store| store.tiles_page(&bbox, zoom, after, limit + 1))
+            .await?
+            .into_iter()
+            .map(TileSummary::from)
+            .collect();
+        let next_cursor = (tiles.len() > limit).then(|| {
+            tiles.truncate(limit);
+            let last = &tiles[limit - 1].tile;
+            format!("{},{}", last.x, last.y)
+        });
+        PlacementsPage {
+            zoom: query.zoom,
+            items: PlacementItems::Tiles(tiles),
+            next_cursor,
+        }
+    };
+    Ok(json_with_etag(&page, &headers))
+}
+
+async fn cell(
+    State(state): State<AppState>,
+    Path((lat, lng)): Path<(i32, i32)>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let cell = state
+        .query(move |store| store.cell(lat, lng))
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    Ok(json_with_etag(&CellView::from(cell), &headers))
+}
+
+/// Cursors are the sort key of the last item served, `lat,lng` or `x,y`
+fn parse_cursor(cursor: &str) -> Result<(i64, i64), ApiError> {
+    let invalid = || ApiError::InvalidQuery(format!("invalid cursor {cursor}"));
+    let (first, second) = cursor.split_once(',').ok_or_else(invalid)?;
+    Ok((
+        first.trim().parse().map_err(|_| invalid())?,
+        second.trim().parse().map_err(|_| invalid())?,
+    ))
+}
+
+/// A `lat,lng` cursor, coordinates outside the map are rejected rather than wrapped into a cell
+fn parse_cell_cursor(cursor: &str) -> Result<(i32, i32), ApiError> {
+    let invalid = || ApiError::InvalidQuery(format!("invalid cursor {cursor}"));
+    let (lat, lng) = parse_cursor(cursor)?;
+    let lat = i32::try_from(lat).map_err(|_| invalid())?;
+    let lng = i32::try_from(lng).map_err(|_| invalid())?;
+    if !BoundingBox::WORLD.contains(lat, lng) {
+        return Err(invalid());
+    }
+    Ok((lat, lng))
+}
+
+/// Serialize `value` with a strong ETag of the body, `304` when the client already has it
+fn json_with_etag(value: &impl Serialize, headers: &HeaderMap) -> Response {
+    let body = serde_json::to_vec(value).expect("responses serialize to JSON");
+    let etag = format!("\"{}\"", &hash(&body).to_string()[..27]);
+    let etag_header = HeaderValue::from_str(&etag).expect("ETags are ASCII");
+
+    let matches = headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|candidate| {
+            let candidate = candidate.trim();
+            candidate == "*" || candidate.trim_start_matches("W/") == etag
+        });
+    if matches {
+        return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag_header)]).into_response();
+    }
+
+    (
+        [
+            (header::ETAG, etag_header),
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("application/json"),
+            ),
+            (header::CACHE_CONTROL, HeaderValue::from_static("no-cache")),
+        ],
+        body,
+    )
+        .into_response()
+}
@@ -0,0 +1,314 @@
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use serde::{Deserialize, Serialize};
+use solplace_client::{BoundingBox, Tile};
+use solplace_indexer::{
+    IndexerError, JournalChange, JournalEntry, JournalPosition, Rollback, Store,
+};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{Stream, StreamExt};
+
+use crate::*;
+
+/// Updates a feed buffers for subscribers that fall behind
+pub const DEFAULT_FEED_CAPACITY: usize = 1024;
+
+/// A journal entry as pushed to stream subscribers
+#[derive(Debug, Clone, Serialize)]
+pub struct StreamUpdate {
+    /// Sent as the event id, unique and growing with every update
+    #[serde(skip)]
+    pub id: JournalPosition,
+    pub slot: u64,
+    pub signature: String,
+    pub lat: i32,
+    pub lng: i32,
+    #[serde(flatten)]
+    pub change: UpdateChange,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum UpdateChange {
+    /// New occupant of the cell
+    Placement {
+        token_mint: String,
+        logo_uri: String,
+        logo_hash: String,
+        placed_by: String,
+        placed_at: i64,
+        /// Mint the placement displaced, unknown for imported snapshots
+        previous_token_mint: Option<String>,
+    },
+    /// The placement made at `placed_at` was hidden, unhidden or taken down
+    Status { placed_at: i64, status: &'static str },
+}
+
+/// Changes journaled above `slot` left the chain and were undone
+///
+/// Subscribers already received them, so each one is told which of `cells` it views.
+#[derive(Debug, Clone)]
+pub struct RollbackUpdate {
+    pub slot: u64,
+    /// Cells whose placements or statuses were undone, sorted by coordinates
+    pub cells: Vec<(i32, i32)>,
+}
+
+/// What a feed pushes to its subscribers
+#[derive(Debug, Clone)]
+pub enum FeedUpdate {
+    Journaled(StreamUpdate),
+    RolledBack(RollbackUpdate),
+}
+
+impl From<JournalEntry> for StreamUpdate {
+    fn from(entry: JournalEntry) -> Self {
+        let change = match entry.change {
+            JournalChange::Placed {
+                token_mint,
+                logo_uri,
+                logo_hash,
+                placed_by,
+                placed_at,
+                previous_token_mint,
+            } => UpdateChange::Placement {
+                token_mint: token_mint.to_string(),
+                logo_uri,
+                logo_hash: hex::encode(logo_hash),
+                placed_by: placed_by.to_string(),
+                placed_at,
+                previous_token_mint: previous_token_mint.map(|mint| mint.to_string()),
+            },
+            JournalChange::Status { placed_at, status } => UpdateChange::Status {
+                placed_at,
+                status: status.as_str(),
+            },
+        };
+        StreamUpdate {
+            id: entry.position,
+            slot: entry.slot,
+            signature: entry.signature,
+            lat: entry.lat,
+            lng: entry.lng,
+            change,
+        }
+    }
+}
+
+/// Fan-out of journaled placements, status changes and rollbacks to stream subscribers
+///
+/// Publishing never waits on subscribers: each one reads from a shared ring buffer
+/// of `capacity` updates, and one that falls further behind skips ahead and is told
+/// how many updates it missed so it can refetch its viewport.
+#[derive(Clone)]
+pub struct PlacementFeed {
+    sender: broadcast::Sender<Arc<FeedUpdate>>,
+    /// Journal position of the last update published, unset before the first `publish`
+    published: Arc<Mutex<Option<JournalPosition>>>,
+}
+
+impl PlacementFeed {
+    pub fn new(capacity: usize) -> Self {
+        PlacementFeed {
+            sender: broadcast::channel(capacity).0,
+            published: Arc::default(),
+        }
+    }
+
+    /// Push what `store` journaled since the last call
+    ///
+    /// The first call only marks where the journal ends, subscribers replay older entries.
+    /// Returns the number of updates published.
+    pub fn publish(&self, store: &Store) -> Result<usize, IndexerError> {
+        let mut published = self.published.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(after) = *published else {
+            *published = Some(store.journal_position()?);
+            return Ok(0);
+        };
+        let entries = store.journal_since(after, None)?;
+        if let Some(last) = entries.last() {
+            *published = Some(last.position);
+        }
+        let count = entries.len();
+        for entry in entries {
+            // Sending only fails when nobody is subscribed
+            let _ = self.sender.send(Arc::new(FeedUpdate::Journaled(entry.into())));
+        }
+        Ok(count)
+    }
+
+    /// Tell subscribers that `rollback` undid changes they may have been pushed
+    ///
+    /// Call it before publishing again: later entries never reuse the journal
+    /// positions of the removed ones, so only the cells need refetching.
+    pub fn roll_back(&self, rollback: &Rollback) {
+        if rollback.cells.is_empty() {
+            return;
+        }
+        let update = RollbackUpdate {
+            slot: rollback.slot,
+            cells: rollback.cells.clone(),
+        };
+        let _ = self.sender.send(Arc::new(FeedUpdate::RolledBack(update)));
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<FeedUpdate>> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for PlacementFeed {
+    fn default() -> Self {
+        PlacementFeed::new(DEFAULT_FEED_CAPACITY)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StreamQuery {
+    /// `min_lat,min_lng,max_lat,max_lng` in microdegrees, the whole world when absent
+    pub bbox: Option<String>,
+    /// Zoom of the tiles updates are keyed by
+    #[serde(default = "detail_zoom")]
+    pub zoom: u8,
+    /// Replay the changes journaled in slots above this one first, instead of those after
+    /// `Last-Event-ID`
+    pub from_slot: Option<u64>,
+}
+
+fn detail_zoom() -> u8 {
+    DETAIL_ZOOM
+}
+
+/// An update as sent to one subscriber, keyed by its tile at the subscribed zoom
+#[derive(Serialize)]
+struct TileUpdate<'a> {
+    tile: Tile,
+    #[serde(flatten)]
+    update: &'a StreamUpdate,
+}
+
+/// The cells of a rollback inside one subscriber's `bbox`, keyed by their tiles
+#[derive(Serialize)]
+struct TileRollback {
+    slot: u64,
+    cells: Vec<RolledBackCell>,
+}
+
+#[derive(Serialize)]
+struct RolledBackCell {
+    tile: Tile,
+    lat: i32,
+    lng: i32,
+}
+
+/// Server-sent events of the placements, status changes and rollbacks inside `bbox`
+///
+/// Every journaled change carries its journal position as its id, so a reconnecting
+/// `EventSource` resumes through `Last-Event-ID` without missing or repeating one.
+/// The replay holds every change journaled since, live updates it covers are skipped.
+/// Rollbacks carry no id and are never skipped, the replay may predate them.
+pub(crate) async fn stream(
+    State(state): State<AppState>,
+    Query(query): Query<StreamQuery>,
+    headers: HeaderMap,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    if query.zoom > MAX_ZOOM {
+        return Err(ApiError::InvalidQuery(format!(
+            "zoom must be at most {MAX_ZOOM}"
+        )));
+    }
+    let bbox: BoundingBox = match &query.bbox {
+        Some(bbox) => bbox.parse().map_err(ApiError::InvalidQuery)?,
+        None => BoundingBox::WORLD,
+    };
+    let last_event_id = headers
+        .get("last-event-id")
+        .map(|id| {
+            id.to_str()
+                .ok()
+                .and_then(|id| id.parse::<JournalPosition>().ok())
+                .ok_or_else(|| ApiError::InvalidQuery("invalid Last-Event-ID".to_string()))
+        })
+        .transpose()?;
+    let resume = match (query.from_slot, last_event_id) {
+        (Some(slot), _) => Some((JournalPosition::default(), Some(slot))),
+        (None, Some(after)) => Some((after, None)),
+        (None, None) => None,
+    };
+    let zoom = query.zoom;
+
+    // Subscribe before reading the replay so no update falls in between
+    let live = BroadcastStream::new(state.feed().subscribe());
+    let replay = match resume {
+        Some((after, above_slot)) => {
+            state
+                .query(move |store| store.journal_since(after, above_slot))
+                .await?
+        }
+        None => Vec::new(),
+    };
+    let replayed = replay.last().map(|entry| entry.position);
+
+    let replay = tokio_stream::iter(replay)
+        .filter(move |entry| bbox.contains(entry.lat, entry.lng))
+        .map(move |entry| Ok(update_event(&StreamUpdate::from(entry), zoom)));
+    let live = live.filter_map(move |update| match update {
+        Ok(update) => match &*update {
+            FeedUpdate::Journaled(update) => (bbox.contains(update.lat, update.lng)
+                && !replayed.is_some_and(|replayed| replayed.covers(&update.id)))
+            .then(|| Ok(update_event(update, zoom))),
+            FeedUpdate::RolledBack(rollback) => rollback_event(rollback, &bbox, zoom).map(Ok),
+        },
+        Err(BroadcastStreamRecvError::Lagged(skipped)) => Some(Ok(Event::default()
+            .event("lagged")
+            .data(serde_json::json!({ "skipped": skipped }).to_string()))),
+    });
+    Ok(Sse::new(replay.chain(live)).keep_alive(KeepAlive::default()))
+}
+
+fn update_event(update: &StreamUpdate, zoom: u8) -> Event {
+    let tile_update = TileUpdate {
+        tile: Tile::containing(update.lat, update.lng, zoom),
+        update,
+    };
+    let event = match update.change {
+        UpdateChange::Placement { .. } => "placement",
+        UpdateChange::Status { .. } => "status",
+    };
+    Event::default()
+        .id(update.id.to_string())
+        .event(event)
+        .data(serde_json::to_string(&tile_update).expect("updates serialize to JSON"))
+}
+
+/// `None` when none of the rolled back cells are inside `bbox`
+fn rollback_event(rollback: &RollbackUpdate, bbox: &BoundingBox, zoom: u8) -> Option<Event> {
+    let cells: Vec<_> = rollback
+        .cells
+        .iter()
+        .filter(|(lat, lng)| bbox.contains(*lat, *lng))
+        .map(|&(lat, lng)| RolledBackCell {
+            tile: Tile::containing(lat, lng, zoom),
+            lat,
+            lng,
+        })
+        .collect();
+    if cells.is_empty() {
+        return None;
+    }
+    let tile_rollback = TileRollback {
+        slot: rollback.slot,
+        cells,
+    };
+    Some(
+        Event::default()
+            .event("rollback")
+            .data(serde_json::to_string(&tile_rollback).expect("rollbacks serialize to JSON")),
+    )
+}
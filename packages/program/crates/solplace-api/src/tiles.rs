@@ -0,0 +1,36 @@
+use serde::Serialize;
+use solplace_client::Tile;
+use solplace_indexer::TileCells;
+
+/// Zoom from which cells are listed individually instead of per tile
+pub const DETAIL_ZOOM: u8 = 12;
+/// Deepest zoom accepted
+pub const MAX_ZOOM: u8 = 22;
+
+/// Cells of one tile rolled up for low zoom levels
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TileSummary {
+    #[serde(flatten)]
+    pub tile: Tile,
+    /// Occupied cells in the tile
+    pub cells: u32,
+    /// Placements on those cells, overwritten ones included
+    pub placements: u32,
+    /// Mint showing on the most cells, ties broken by the most recent placement
+    pub top_mint: String,
+    pub top_mint_cells: u32,
+    pub last_placed_at: i64,
+}
+
+impl From<TileCells> for TileSummary {
+    fn from(tile: TileCells) -> Self {
+        TileSummary {
+            tile: tile.tile,
+            cells: tile.cells,
+            placements: tile.placements,
+            top_mint: tile.top_mint.to_string(),
+            top_mint_cells: tile.top_mint_cells,
+            last_placed_at: tile.last_placed_at,
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! Serves an in-memory store seeded from `LogoPlacement` fixtures, no network needed
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::hash;
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use http_body_util::BodyExt;
+use program::state::LogoPlacement;
+use solplace_api::*;
+use solplace_indexer::Store;
+use tower::ServiceExt;
+
+/// Fixture accounts are sha256 of a name
+fn key(name: &str) -> Pubkey {
+    Pubkey::new_from_array(hash(name.as_bytes()).to_bytes())
+}
+
+fn placement(lat: i32, lng: i32, mint: &str, placed_at: i64) -> LogoPlacement {
+    let logo_uri = format!("https://example.com/{mint}.png");
+    LogoPlacement {
+        version: LogoPlacement::VERSION,
+        coordinates: [lat, lng],
+        token_mint: key(mint),
+        logo_hash: hash(logo_uri.as_bytes()).to_bytes(),
+        logo_uri,
+        placed_by: key("alice"),
+        placed_at,
+        overwrite_count: 0,
+        bump: 255,
+    }
+}
+
+/// Three cells around Manhattan, one in Paris and one in Tokyo
+fn fixtures() -> Vec<LogoPlacement> {
+    vec![
+        placement(40_712_800, -74_006_000, "bonk", 1_700_000_100),
+        placement(40_712_900, -74_006_000, "wif", 1_700_000_200),
+        placement(40_758_000, -73_985_500, "bonk", 1_700_000_300),
+        placement(48_858_400, 2_294_500, "bonk", 1_700_000_400),
+        placement(35_658_600, 139_745_400, "wif", 1_700_000_500),
+    ]
+}
+
+fn seeded_store() -> Store {
+    let mut store = Store::open_in_memory().unwrap();
+    store.import_placements(100, &fixtures()).unwrap();
+    store
+}
+
+async fn get(
+    state: &AppState,
+    uri: &str,
+    etag: Option<&str>,
+) -> (StatusCode, Option<String>, serde_json::Value) {
+    let mut request = Request::get(uri);
+    if let Some(etag) = etag {
+        request = request.header(header::IF_NONE_MATCH, etag);
+    }
+    let response = router(state.clone())
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let etag = response
+        .headers()
+        .get(header::ETAG)
+        .map(|etag| etag.to_str().unwrap().to_string());
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if body.is_empty() {
+        serde_json::Value::Null
+    } else {
+        serde_json::from_slice(&body).unwrap()
+    };
+    (status, etag, body)
+}
+
+#[tokio::test]
+async fn lists_cells_in_a_bbox_page_by_page() {
+    let state = AppState::new(seeded_store());
+    let new_york = "bbox=40000000,-75000000,41000000,-73000000";
+
+    let (status, _, first) = get(
+        &state,
+        &format!("/placements?{new_york}&zoom=15&limit=2"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(first["zoom"], 15);
+    let cells = first["cells"].as_array().unwrap();
+    assert_eq!(cells.len(), 2);
+    assert_eq!(
+        (&cells[0]["lat"], &cells[0]["lng"]),
+        (&40_712_800.into(), &(-74_006_000).into())
+    );
+    assert_eq!(cells[0]["token_mint"], key("bonk").to_string());
+    assert_eq!(first["next_cursor"], "40712900,-74006000");
+
+    let (_, _, second) = get(
+        &state,
+        &format!("/placements?{new_york}&zoom=15&limit=2&cursor=40712900,-74006000"),
+        None,
+    )
+    .await;
+    let cells = second["cells"].as_array().unwrap();
+    assert_eq!(cells.len(), 1);
+    assert_eq!(cells[0]["lat"], 40_758_000);
+    assert!(second["next_cursor"].is_null());
+}
+
+#[tokio::test]
+async fn aggregates_tiles_at_low_zoom() {
+    let state = AppState::new(seeded_store());
+
+    // One tile for all of Manhattan, Paris and Tokyo on their own
+    let (status, _, page) = get(&state, "/placements?zoom=8", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let tiles = page["tiles"].as_array().unwrap();
+    assert_eq!(tiles.len(), 3);
+    let manhattan = tiles
+        .iter()
+        .find(|tile| tile["cells"] == 3)
+        .expect("Manhattan tile");
+    assert_eq!(manhattan["z"], 8);
+    assert_eq!((&manhattan["x"], &manhattan["y"]), (&75.into(), &96.into()));
+    assert_eq!(manhattan["top_mint"], key("bonk").to_string());
+    assert_eq!(manhattan["top_mint_cells"], 2);
+    assert_eq!(manhattan["last_placed_at"], 1_700_000_300);
+
+    // Tiles are paginated by their x,y
+    let (_, _, first) = get(&state, "/placements?zoom=8&limit=2", None).await;
+    assert_eq!(first["tiles"].as_array().unwrap().len(), 2);
+    let cursor = first["next_cursor"].as_str().unwrap();
+    let (_, _, rest) = get(
+        &state,
+        &format!("/placements?zoom=8&limit=2&cursor={cursor}"),
+        None,
+    )
+    .await;
+    assert_eq!(rest["tiles"].as_array().unwrap().len(), 1);
+    assert!(rest["next_cursor"].is_null());
+
+    // Across the antimeridian only Tokyo is in view
+    let (_, _, pacific) = get(
+        &state,
+        "/placements?bbox=0,120000000,60000000,-120000000&zoom=3",
+        None,
+    )
+    .await;
+    assert_eq!(pacific["tiles"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn revalidates_with_etags() {
+    let state = AppState::new(seeded_store());
+    let uri = "/placements?zoom=4";
+
+    let (_, etag, _) = get(&state, uri, None).await;
+    let etag = etag.unwrap();
+    let (status, again, body) = get(&state, uri, Some(&etag)).await;
+    assert_eq!(status, StatusCode::NOT_MODIFIED);
+    assert_eq!(again.as_deref(), Some(etag.as_str()));
+    assert!(body.is_null());
+
+    // A new placement changes the viewport and its ETag
+    state
+        .store()
+        .import_placements(
+            101,
+            &[placement(51_507_400, -127_800, "wif", 1_700_000_600)],
+        )
+        .unwrap();
+    let (status, changed, _) = get(&state, uri, Some(&etag)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_ne!(changed.unwrap(), etag);
+}
+
+#[tokio::test]
+async fn serves_single_cells_and_rejects_bad_queries() {
+    let state = AppState::new(seeded_store());
+
+    let (status, etag, cell) = get(&state, "/cells/48858400/2294500", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(etag.is_some());
+    assert_eq!(cell["logo_uri"], "https://example.com/bonk.png");
+    assert_eq!(cell["placements"], 1);
+
+    let (status, _, _) = get(&state, "/cells/1/1", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _, error) = get(&state, "/placements?bbox=1,2,3&zoom=3", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(error["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("invalid query"));
+    let (status, _, _) = get(&state, "/placements?zoom=30", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _, _) = get(&state, "/placements?zoom=15&cursor=abc", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    // Cell cursors out of range are refused, not wrapped into another cell
+    for cursor in ["4294967296,0", "40712800,4294967295", "90000001,0", "0,-180000001"] {
+        let (status, _, _) =
+            get(&state, &format!("/placements?zoom=15&cursor={cursor}"), None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{cursor}");
+    }
+}
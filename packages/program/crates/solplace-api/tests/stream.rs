@@ -0,0 +1,324 @@
+//! Drives the placement stream with the indexer's recorded transactions
+
+use std::time::Duration;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::hash;
+use axum::body::{Body, BodyDataStream};
+use axum::http::{Request, StatusCode};
+use solplace_api::*;
+use solplace_client::get_logo_placement_pda;
+use solplace_indexer::{CellStatus, CellStatusEvent, IndexedTransaction, Store};
+use tokio_stream::StreamExt;
+use tower::ServiceExt;
+
+const FIXTURES: [&str; 5] = [
+    "place_new",
+    "place_overwrite",
+    "place_second_cell",
+    "place_failed",
+    "spoofed_event",
+];
+
+/// Fixture accounts are sha256 of a name
+fn key(name: &str) -> Pubkey {
+    Pubkey::new_from_array(hash(name.as_bytes()).to_bytes())
+}
+
+fn transaction(name: &str) -> IndexedTransaction {
+    let path = format!(
+        "{}/../solplace-indexer/tests/fixtures/{name}.json",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let value: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    IndexedTransaction::from_rpc(&value).unwrap()
+}
+
+/// One server-sent event
+#[derive(Debug)]
+struct Sse {
+    event: String,
+    id: Option<String>,
+    data: serde_json::Value,
+}
+
+struct Subscription {
+    body: BodyDataStream,
+    buffer: String,
+}
+
+impl Subscription {
+    async fn open(state: &AppState, uri: &str, last_event_id: Option<&str>) -> Self {
+        let mut request = Request::get(uri);
+        if let Some(id) = last_event_id {
+            request = request.header("last-event-id", id);
+        }
+        let response = router(state.clone())
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        Subscription {
+            body: response.into_body().into_data_stream(),
+            buffer: String::new(),
+        }
+    }
+
+    /// Next event, `None` when nothing arrives for a moment
+    async fn next(&mut self) -> Option<Sse> {
+        loop {
+            if let Some(end) = self.buffer.find("\n\n") {
+                let frame: String = self.buffer.drain(..end + 2).collect();
+                let mut sse = Sse {
+                    event: "message".to_string(),
+                    id: None,
+                    data: serde_json::Value::Null,
+                };
+                for line in frame.lines() {
+                    if let Some(event) = line.strip_prefix("event: ") {
+                        sse.event = event.to_string();
+                    } else if let Some(id) = line.strip_prefix("id: ") {
+                        sse.id = Some(id.to_string());
+                    } else if let Some(data) = line.strip_prefix("data: ") {
+                        sse.data = serde_json::from_str(data).unwrap();
+                    }
+                }
+                return Some(sse);
+            }
+            let chunk = tokio::time::timeout(Duration::from_millis(200), self.body.next())
+                .await
+                .ok()??
+                .unwrap();
+            self.buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+    }
+}
+
+/// Journal the fixtures `names` in the state's store and publish them
+fn index(state: &AppState, names: &[&str]) {
+    let mut store = state.store();
+    for name in names {
+        store.apply(&transaction(name)).unwrap();
+    }
+    state.feed().publish(&store).unwrap();
+}
+
+/// A transaction hiding the placement made at `placed_at` on New York
+fn hide(placed_at: i64) -> IndexedTransaction {
+    IndexedTransaction {
+        slot: 103,
+        signature: "hide".to_string(),
+        block_time: None,
+        events: Vec::new(),
+        statuses: vec![CellStatusEvent {
+            cell: get_logo_placement_pda(40_712_800, -74_006_000, &program::ID).0,
+            coordinates: Some((40_712_800, -74_006_000)),
+            placed_at,
+            status: CellStatus::Hidden,
+            timestamp: 1_700_000_500,
+        }],
+        logs_truncated: false,
+    }
+}
+
+#[tokio::test]
+async fn pushes_placements_inside_the_bbox() {
+    let state = AppState::new(Store::open_in_memory().unwrap());
+    index(&state, &[]);
+    let mut new_york = Subscription::open(
+        &state,
+        "/stream?bbox=40000000,-75000000,41000000,-73000000&zoom=8",
+        None,
+    )
+    .await;
+
+    index(&state, &FIXTURES);
+
+    let placed = new_york.next().await.unwrap();
+    assert_eq!(placed.event, "placement");
+    assert_eq!(placed.id.as_deref(), Some("1.0"));
+    assert_eq!(placed.data["slot"], 100);
+    assert_eq!(
+        placed.data["tile"],
+        serde_json::json!({ "z": 8, "x": 75, "y": 96 })
+    );
+    assert_eq!(placed.data["token_mint"], key("bonk").to_string());
+    assert!(placed.data["previous_token_mint"].is_null());
+
+    let overwrite = new_york.next().await.unwrap();
+    assert_eq!(overwrite.id.as_deref(), Some("2.0"));
+    assert_eq!(overwrite.data["placed_by"], key("bob").to_string());
+    assert_eq!(
+        overwrite.data["previous_token_mint"],
+        key("bonk").to_string()
+    );
+
+    // Paris is out of view, the failed and spoofed transactions carry no placements
+    assert!(new_york.next().await.is_none());
+
+    // Status changes are pushed too
+    let placed_at = overwrite.data["placed_at"].as_i64().unwrap();
+    state.store().apply(&hide(placed_at)).unwrap();
+    state.feed().publish(&state.store()).unwrap();
+    let hidden = new_york.next().await.unwrap();
+    assert_eq!(hidden.event, "status");
+    // Paris, out of view, took position 3.0
+    assert_eq!(hidden.id.as_deref(), Some("3.1"));
+    assert_eq!(hidden.data["status"], "hidden");
+    assert_eq!(hidden.data["placed_at"], placed_at);
+}
+
+#[tokio::test]
+async fn resumes_from_a_slot_or_the_last_event() {
+    let state = AppState::new(Store::open_in_memory().unwrap());
+    index(&state, &FIXTURES);
+
+    let mut resumed = Subscription::open(&state, "/stream?from_slot=100", None).await;
+    let replayed: Vec<_> = [resumed.next().await.unwrap(), resumed.next().await.unwrap()]
+        .into_iter()
+        .map(|sse| (sse.id.unwrap(), sse.data["lat"].as_i64().unwrap()))
+        .collect();
+    assert_eq!(
+        replayed,
+        [
+            ("2.0".to_string(), 40_712_800),
+            ("3.0".to_string(), 48_858_400)
+        ]
+    );
+    assert!(resumed.next().await.is_none());
+
+    // A reconnecting EventSource resumes after the last id it saw
+    let mut reconnected = Subscription::open(&state, "/stream", Some("2.0")).await;
+    assert_eq!(reconnected.next().await.unwrap().id.as_deref(), Some("3.0"));
+
+    // Live updates follow the replay
+    let placed_at = state
+        .store()
+        .cell(40_712_800, -74_006_000)
+        .unwrap()
+        .unwrap()
+        .placed_at;
+    state.store().apply(&hide(placed_at)).unwrap();
+    state.feed().publish(&state.store()).unwrap();
+    let hidden = reconnected.next().await.unwrap();
+    assert_eq!(
+        (hidden.event.as_str(), hidden.id.as_deref()),
+        ("status", Some("3.1"))
+    );
+}
+
+#[tokio::test]
+async fn skips_live_updates_the_replay_covered() {
+    let state = AppState::new(Store::open_in_memory().unwrap());
+    index(&state, &[]);
+    // Indexed but not yet published when the subscriber replays
+    state.store().apply(&transaction("place_new")).unwrap();
+
+    let mut resumed = Subscription::open(&state, "/stream", Some("0.0")).await;
+    state.feed().publish(&state.store()).unwrap();
+    assert_eq!(resumed.next().await.unwrap().id.as_deref(), Some("1.0"));
+    assert!(resumed.next().await.is_none());
+}
+
+#[tokio::test]
+async fn tells_lagging_subscribers_what_they_missed() {
+    let state = AppState::with_feed(Store::open_in_memory().unwrap(), PlacementFeed::new(2));
+    index(&state, &[]);
+    let mut slow = Subscription::open(&state, "/stream", None).await;
+
+    // Publishing never waits, the oldest update is dropped for the slow subscriber
+    index(&state, &["place_new", "place_overwrite", "place_second_cell"]);
+
+    let lagged = slow.next().await.unwrap();
+    assert_eq!(lagged.event, "lagged");
+    assert_eq!(lagged.data["skipped"], 1);
+    assert_eq!(slow.next().await.unwrap().id.as_deref(), Some("2.0"));
+    assert_eq!(slow.next().await.unwrap().id.as_deref(), Some("3.0"));
+}
+
+#[tokio::test]
+async fn tells_subscribers_in_view_about_rollbacks() {
+    let state = AppState::new(Store::open_in_memory().unwrap());
+    index(&state, &[]);
+    let mut world = Subscription::open(&state, "/stream?zoom=8", None).await;
+    let mut new_york = Subscription::open(
+        &state,
+        "/stream?bbox=40000000,-75000000,41000000,-73000000&zoom=8",
+        None,
+    )
+    .await;
+    let mut tokyo = Subscription::open(
+        &state,
+        "/stream?bbox=35000000,139000000,36000000,140000000",
+        None,
+    )
+    .await;
+    index(&state, &FIXTURES);
+    for _ in 0..3 {
+        world.next().await.unwrap();
+    }
+    for _ in 0..2 {
+        new_york.next().await.unwrap();
+    }
+
+    // The overwrite and the Paris placement leave the chain
+    let rollback = state.store().rollback(100).unwrap();
+    state.feed().roll_back(&rollback);
+
+    let undone = world.next().await.unwrap();
+    assert_eq!(undone.event, "rollback");
+    assert_eq!(undone.id, None);
+    assert_eq!(undone.data["slot"], 100);
+    let cells: Vec<_> = undone.data["cells"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|cell| (cell["lat"].as_i64().unwrap(), cell["lng"].as_i64().unwrap()))
+        .collect();
+    assert_eq!(
+        cells,
+        [(40_712_800, -74_006_000), (48_858_400, 2_294_500)]
+    );
+
+    let undone = new_york.next().await.unwrap();
+    assert_eq!(undone.event, "rollback");
+    assert_eq!(
+        undone.data["cells"],
+        serde_json::json!([{
+            "tile": { "z": 8, "x": 75, "y": 96 },
+            "lat": 40_712_800,
+            "lng": -74_006_000,
+        }])
+    );
+    assert!(tokyo.next().await.is_none());
+
+    // Landing again, the overwrite takes a new journal position
+    index(&state, &["place_overwrite"]);
+    let replaced = new_york.next().await.unwrap();
+    assert_eq!(
+        (replaced.event.as_str(), replaced.id.as_deref()),
+        ("placement", Some("4.0"))
+    );
+}
+
+#[tokio::test]
+async fn rejects_invalid_subscriptions() {
+    let state = AppState::new(Store::open_in_memory().unwrap());
+    for (uri, last_event_id) in [
+        ("/stream?bbox=1,2", None),
+        ("/stream?zoom=40", None),
+        ("/stream", Some("not-a-position")),
+        ("/stream", Some("101")),
+    ] {
+        let mut request = Request::get(uri);
+        if let Some(id) = last_event_id {
+            request = request.header("last-event-id", id);
+        }
+        let response = router(state.clone())
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{uri}");
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, Discriminator};
+use program::state::{
+    LogoBitmap, LogoPlacement, LogoPlacementV0, MintProfile, ProgramConfig, ReportTally,
+    RestrictedZone, UserCooldown,
+};
+
+use crate::{ClientError, RpcAccount};
+
+/// Decode a `LogoPlacement`, upgrading v0 accounts in memory
+pub fn decode_logo_placement(data: &[u8]) -> Result<LogoPlacement, ClientError> {
+    LogoPlacement::try_deserialize_versioned(data).map_err(invalid("LogoPlacement"))
+}
+
+/// Decode the `LogoPlacement`s among program accounts, skipping other account types
+pub fn logo_placements(
+    accounts: impl IntoIterator<Item = (Pubkey, RpcAccount)>,
+) -> Result<Vec<(Pubkey, LogoPlacement)>, ClientError> {
+    accounts
+        .into_iter()
+        .filter(|(_, account)| {
+            account.data.starts_with(LogoPlacement::DISCRIMINATOR)
+                || account.data.starts_with(LogoPlacementV0::DISCRIMINATOR)
+        })
+        .map(|(address, account)| Ok((address, decode_logo_placement(&account.data)?)))
+        .collect()
+}
+
+/// `LogoPlacement`s among program accounts that the map shows
+///
+/// Leaves out placements a moderator blanked and those whose `ReportTally` among the same
+/// accounts hides them.
+pub fn visible_logo_placements(
+    accounts: impl IntoIterator<Item = (Pubkey, RpcAccount)>,
+) -> Result<Vec<(Pubkey, LogoPlacement)>, ClientError> {
+    let accounts: Vec<_> = accounts.into_iter().collect();
+    let hidden = hidden_placements(accounts.iter().cloned())?;
+    Ok(logo_placements(accounts)?
+        .into_iter()
+        .filter(|(address, placement)| {
+            !placement.is_blank() && !hidden.contains(&(*address, placement.placed_at))
+        })
+        .collect())
+}
+
+/// Placements hidden by reports, as their `LogoPlacement` address and `placed_at`
+///
+/// A tally counts towards the placement it was last synced to, so one left over from an
+/// overwritten placement hides nothing.
+pub fn hidden_placements(
+    accounts: impl IntoIterator<Item = (Pubkey, RpcAccount)>,
+) -> Result<HashSet<(Pubkey, i64)>, ClientError> {
+    let mut hidden = HashSet::new();
+    for (_, account) in accounts {
+        if !account.data.starts_with(ReportTally::DISCRIMINATOR) {
+            continue;
+        }
+        let tally = decode_report_tally(&account.data)?;
+        if tally.hidden {
+            hidden.insert((tally.logo_placement, tally.placed_at));
+        }
+    }
+    Ok(hidden)
+}
+
+pub fn decode_report_tally(data: &[u8]) -> Result<ReportTally, ClientError> {
+    ReportTally::try_deserialize(&mut &data[..]).map_err(invalid("ReportTally"))
+}
+
+/// Decode a `UserCooldown`, upgrading v0 accounts in memory
+pub fn decode_user_cooldown(data: &[u8]) -> Result<UserCooldown, ClientError> {
+    UserCooldown::try_deserialize_versioned(data).map_err(invalid("UserCooldown"))
+}
+
+pub fn decode_mint_profile(data: &[u8]) -> Result<MintProfile, ClientError> {
+    MintProfile::try_deserialize(&mut &data[..]).map_err(invalid("MintProfile"))
+}
+
+pub fn decode_program_config(data: &[u8]) -> Result<ProgramConfig, ClientError> {
+    ProgramConfig::try_deserialize(&mut &data[..]).map_err(invalid("ProgramConfig"))
+}
+
+pub fn decode_logo_bitmap_account(data: &[u8]) -> Result<LogoBitmap, ClientError> {
+    LogoBitmap::try_deserialize(&mut &data[..]).map_err(invalid("LogoBitmap"))
+}
+
+pub fn decode_restricted_zone(data: &[u8]) -> Result<RestrictedZone, ClientError> {
+    RestrictedZone::try_deserialize(&mut &data[..]).map_err(invalid("RestrictedZone"))
+}
+
+fn invalid(account: &'static str) -> impl Fn(anchor_lang::error::Error) -> ClientError {
+    move |err| ClientError::InvalidAccount {
+        account,
+        message: err.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::AccountSerialize;
+
+    use super::*;
+
+    fn account(value: &impl AccountSerialize) -> RpcAccount {
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).unwrap();
+        RpcAccount {
+            lamports: 1,
+            owner: program::ID,
+            data,
+        }
+    }
+
+    fn placement(lat: i32, placed_at: i64) -> LogoPlacement {
+        LogoPlacement {
+            version: LogoPlacement::VERSION,
+            coordinates: [lat, 0],
+            token_mint: Pubkey::new_unique(),
+            placed_at,
+            ..LogoPlacement::default()
+        }
+    }
+
+    fn tally(logo_placement: Pubkey, placed_at: i64, hidden: bool) -> ReportTally {
+        ReportTally {
+            logo_placement,
+            placed_at,
+            report_count: 3,
+            hidden,
+            upheld_count: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn leaves_out_blank_and_report_hidden_placements() {
+        let (shown, hidden, overwritten, blank) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut blanked = placement(4, 400);
+        blanked.blank();
+        let accounts = vec![
+            (shown, account(&placement(1, 100))),
+            (Pubkey::new_unique(), account(&tally(shown, 100, false))),
+            (hidden, account(&placement(2, 200))),
+            (Pubkey::new_unique(), account(&tally(hidden, 200, true))),
+            // The tally still counts the placement this one replaced
+            (overwritten, account(&placement(3, 300))),
+            (
+                Pubkey::new_unique(),
+                account(&tally(overwritten, 250, true)),
+            ),
+            (blank, account(&blanked)),
+        ];
+
+        let visible: Vec<_> = visible_logo_placements(accounts)
+            .unwrap()
+            .into_iter()
+            .map(|(address, _)| address)
+            .collect();
+        assert_eq!(visible, [shown, overwritten]);
+    }
+}
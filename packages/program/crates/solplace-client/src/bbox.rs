@@ -0,0 +1,90 @@
+use std::str::FromStr;
+
+use program::constants::{MAX_LATITUDE, MAX_LONGITUDE, MIN_LATITUDE, MIN_LONGITUDE};
+
+/// Inclusive coordinate box in microdegrees
+/// A box with `min_lng > max_lng` crosses the antimeridian
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundingBox {
+    pub min_lat: i32,
+    pub min_lng: i32,
+    pub max_lat: i32,
+    pub max_lng: i32,
+}
+
+impl BoundingBox {
+    /// The whole map
+    pub const WORLD: BoundingBox = BoundingBox {
+        min_lat: MIN_LATITUDE,
+        min_lng: MIN_LONGITUDE,
+        max_lat: MAX_LATITUDE,
+        max_lng: MAX_LONGITUDE,
+    };
+
+    pub fn contains(&self, lat: i32, lng: i32) -> bool {
+        let lng_inside = if self.min_lng <= self.max_lng {
+            (self.min_lng..=self.max_lng).contains(&lng)
+        } else {
+            lng >= self.min_lng || lng <= self.max_lng
+        };
+        (self.min_lat..=self.max_lat).contains(&lat) && lng_inside
+    }
+}
+
+/// Parse `min_lat,min_lng,max_lat,max_lng` in microdegrees
+impl FromStr for BoundingBox {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values = s
+            .split(',')
+            .map(|value| value.trim().parse::<i32>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| format!("invalid bounding box: {err}"))?;
+        let [min_lat, min_lng, max_lat, max_lng] = values[..] else {
+            return Err("expected min_lat,min_lng,max_lat,max_lng".to_string());
+        };
+
+        if min_lat > max_lat {
+            return Err("min_lat is above max_lat".to_string());
+        }
+        if min_lat < MIN_LATITUDE || max_lat > MAX_LATITUDE {
+            return Err("latitude out of range".to_string());
+        }
+        if [min_lng, max_lng]
+            .iter()
+            .any(|lng| !(MIN_LONGITUDE..=MAX_LONGITUDE).contains(lng))
+        {
+            return Err("longitude out of range".to_string());
+        }
+
+        Ok(BoundingBox {
+            min_lat,
+            min_lng,
+            max_lat,
+            max_lng,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_contains() {
+        let bbox: BoundingBox = "40000000,-75000000,41000000,-73000000".parse().unwrap();
+        assert!(bbox.contains(40_712_800, -74_006_000));
+        assert!(!bbox.contains(40_712_800, -72_000_000));
+        assert!("41000000,0,40000000,1".parse::<BoundingBox>().is_err());
+        assert!("1,2,3".parse::<BoundingBox>().is_err());
+    }
+
+    #[test]
+    fn wraps_across_the_antimeridian() {
+        let bbox: BoundingBox = "-20000000,179000000,-10000000,-179000000".parse().unwrap();
+        assert!(bbox.contains(-15_000_000, 179_500_000));
+        assert!(bbox.contains(-15_000_000, -179_500_000));
+        assert!(!bbox.contains(-15_000_000, 0));
+    }
+}
@@ -0,0 +1,466 @@
+use anchor_lang::prelude::{borsh, AnchorDeserialize, Pubkey};
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::{AccountDeserialize, Discriminator};
+use base64::Engine;
+use program::state::{LogoPlacement, LogoPlacementV0, UserCooldown, UserCooldownV0};
+
+const DISCRIMINATOR_LEN: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DecodeError {
+    #[error("invalid {encoding}: {message}")]
+    InvalidEncoding {
+        encoding: &'static str,
+        message: String,
+    },
+
+    #[error("account data is {0} bytes, too short for a discriminator")]
+    MissingDiscriminator(usize),
+
+    #[error("unknown discriminator {}", hex::encode(.0))]
+    UnknownDiscriminator([u8; DISCRIMINATOR_LEN]),
+
+    #[error("invalid {account}: {message}")]
+    InvalidAccount {
+        account: &'static str,
+        message: String,
+    },
+
+    #[error("{account} version {actual} does not match the discriminator (v{expected})")]
+    VersionMismatch {
+        account: &'static str,
+        expected: u8,
+        actual: u8,
+    },
+
+    #[error("{account} account is {actual} bytes, expected {expected}")]
+    SizeMismatch {
+        account: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+/// Legacy cluster account written by early deployments, before each cell got its own
+/// `LogoPlacement`. The program no longer builds this type, so its layout lives here.
+#[derive(AnchorDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CellCluster {
+    pub cluster_id: u64,
+    /// `[min_lat, max_lat, min_lng, max_lng]` in microdegrees
+    pub bounds: [i32; 4],
+    pub cell_count: u32,
+    pub cells: Vec<CellData>,
+    pub last_updated: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CellData {
+    pub coordinates: [i32; 2],
+    pub token_mint: Pubkey,
+    pub logo_uri: String,
+    pub logo_hash: [u8; 32],
+    pub placed_by: Pubkey,
+    pub placed_at: i64,
+    pub overwrite_count: u16,
+}
+
+impl CellCluster {
+    pub const MAX_CELLS: usize = 10;
+    pub const BASE_SIZE: usize = 8 + 8 + 16 + 4 + 4 + 8 + 1;
+    pub const CELL_DATA_SIZE: usize = 8 + 32 + 4 + 200 + 32 + 32 + 8 + 2;
+    pub const MAX_SIZE: usize = Self::BASE_SIZE + Self::CELL_DATA_SIZE * Self::MAX_CELLS;
+
+    /// Anchor discriminator, sha256("account:CellCluster")[..8]
+    pub fn discriminator() -> [u8; DISCRIMINATOR_LEN] {
+        hash(b"account:CellCluster").to_bytes()[..DISCRIMINATOR_LEN]
+            .try_into()
+            .unwrap()
+    }
+}
+
+/// An account recognized by its discriminator
+/// `layout_version` is the on-chain layout, v0 accounts are upgraded in memory
+#[derive(Clone)]
+pub enum DecodedAccount {
+    LogoPlacement {
+        layout_version: u8,
+        account: LogoPlacement,
+    },
+    UserCooldown {
+        layout_version: u8,
+        account: UserCooldown,
+    },
+    CellCluster(CellCluster),
+}
+
+impl DecodedAccount {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            DecodedAccount::LogoPlacement { .. } => "LogoPlacement",
+            DecodedAccount::UserCooldown { .. } => "UserCooldown",
+            DecodedAccount::CellCluster(_) => "CellCluster",
+        }
+    }
+
+    /// JSON with the account type, layout version and every field
+    /// Pubkeys are base58, hashes hex
+    pub fn to_json(&self) -> serde_json::Value {
+        let (layout_version, mut json) = match self {
+            DecodedAccount::LogoPlacement {
+                layout_version,
+                account,
+            } => (*layout_version, logo_placement_json(account)),
+            DecodedAccount::UserCooldown {
+                layout_version,
+                account,
+            } => (*layout_version, user_cooldown_json(account)),
+            DecodedAccount::CellCluster(cluster) => (0, cell_cluster_json(cluster)),
+        };
+        json["type"] = self.type_name().into();
+        json["layout_version"] = layout_version.into();
+        json
+    }
+}
+
+impl std::fmt::Debug for DecodedAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_json())
+    }
+}
+
+/// Decode base64 account data, as shown by explorers and `getAccountInfo`
+pub fn parse_base64(input: &str) -> Result<Vec<u8>, DecodeError> {
+    let input: String = input.split_whitespace().collect();
+    base64::engine::general_purpose::STANDARD
+        .decode(input)
+        .map_err(|err| DecodeError::InvalidEncoding {
+            encoding: "base64",
+            message: err.to_string(),
+        })
+}
+
+/// Decode hex account data, with or without a `0x` prefix
+pub fn parse_hex(input: &str) -> Result<Vec<u8>, DecodeError> {
+    let input: String = input.split_whitespace().collect();
+    hex::decode(input.strip_prefix("0x").unwrap_or(&input)).map_err(|err| {
+        DecodeError::InvalidEncoding {
+            encoding: "hex",
+            message: err.to_string(),
+        }
+    })
+}
+
+/// Detect the account type by discriminator and decode it
+pub fn decode_account(data: &[u8]) -> Result<DecodedAccount, DecodeError> {
+    let discriminator: [u8; DISCRIMINATOR_LEN] = data
+        .get(..DISCRIMINATOR_LEN)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(DecodeError::MissingDiscriminator(data.len()))?;
+
+    if discriminator == LogoPlacement::DISCRIMINATOR {
+        read_logo_placement(data, 1)
+    } else if discriminator == LogoPlacementV0::DISCRIMINATOR {
+        read_logo_placement(data, 0)
+    } else if discriminator == UserCooldown::DISCRIMINATOR {
+        read_user_cooldown(data, 1)
+    } else if discriminator == UserCooldownV0::DISCRIMINATOR {
+        read_user_cooldown(data, 0)
+    } else if discriminator == CellCluster::discriminator() {
+        read_cell_cluster(data)
+    } else {
+        Err(DecodeError::UnknownDiscriminator(discriminator))
+    }
+}
+
+pub fn logo_placement_json(placement: &LogoPlacement) -> serde_json::Value {
+    serde_json::json!({
+        "coordinates": placement.coordinates,
+        "token_mint": placement.token_mint.to_string(),
+        "logo_uri": placement.logo_uri,
+        "logo_hash": hex::encode(placement.logo_hash),
+        "placed_by": placement.placed_by.to_string(),
+        "placed_at": placement.placed_at,
+        "overwrite_count": placement.overwrite_count,
+        "bump": placement.bump,
+    })
+}
+
+pub fn user_cooldown_json(cooldown: &UserCooldown) -> serde_json::Value {
+    serde_json::json!({
+        "user": cooldown.user.to_string(),
+        "last_placement": cooldown.last_placement,
+        "placement_count": cooldown.placement_count,
+        "bump": cooldown.bump,
+    })
+}
+
+pub fn cell_cluster_json(cluster: &CellCluster) -> serde_json::Value {
+    let cells: Vec<_> = cluster
+        .cells
+        .iter()
+        .map(|cell| {
+            serde_json::json!({
+                "coordinates": cell.coordinates,
+                "token_mint": cell.token_mint.to_string(),
+                "logo_uri": cell.logo_uri,
+                "logo_hash": hex::encode(cell.logo_hash),
+                "placed_by": cell.placed_by.to_string(),
+                "placed_at": cell.placed_at,
+                "overwrite_count": cell.overwrite_count,
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "cluster_id": cluster.cluster_id,
+        "bounds": cluster.bounds,
+        "cell_count": cluster.cell_count,
+        "cells": cells,
+        "last_updated": cluster.last_updated,
+        "bump": cluster.bump,
+    })
+}
+
+fn read_logo_placement(data: &[u8], layout_version: u8) -> Result<DecodedAccount, DecodeError> {
+    let account = match layout_version {
+        0 => {
+            expect_size("LogoPlacement", data, LogoPlacementV0::SIZE)?;
+            deserialize::<LogoPlacementV0>("LogoPlacement", data)?.into()
+        }
+        _ => {
+            expect_size("LogoPlacement", data, LogoPlacement::SIZE)?;
+            let account: LogoPlacement = deserialize("LogoPlacement", data)?;
+            expect_version("LogoPlacement", account.version, LogoPlacement::VERSION)?;
+            account
+        }
+    };
+    Ok(DecodedAccount::LogoPlacement {
+        layout_version,
+        account,
+    })
+}
+
+fn read_user_cooldown(data: &[u8], layout_version: u8) -> Result<DecodedAccount, DecodeError> {
+    let account = match layout_version {
+        0 => {
+            expect_size("UserCooldown", data, UserCooldownV0::SIZE)?;
+            deserialize::<UserCooldownV0>("UserCooldown", data)?.into()
+        }
+        _ => {
+            expect_size("UserCooldown", data, UserCooldown::SIZE)?;
+            let account: UserCooldown = deserialize("UserCooldown", data)?;
+            expect_version("UserCooldown", account.version, UserCooldown::VERSION)?;
+            account
+        }
+    };
+    Ok(DecodedAccount::UserCooldown {
+        layout_version,
+        account,
+    })
+}
+
+fn read_cell_cluster(data: &[u8]) -> Result<DecodedAccount, DecodeError> {
+    expect_size("CellCluster", data, CellCluster::MAX_SIZE)?;
+    let cluster = CellCluster::deserialize(&mut &data[DISCRIMINATOR_LEN..]).map_err(|err| {
+        DecodeError::InvalidAccount {
+            account: "CellCluster",
+            message: err.to_string(),
+        }
+    })?;
+    if cluster.cells.len() > CellCluster::MAX_CELLS {
+        return Err(DecodeError::InvalidAccount {
+            account: "CellCluster",
+            message: format!(
+                "{} cells, at most {}",
+                cluster.cells.len(),
+                CellCluster::MAX_CELLS
+            ),
+        });
+    }
+    Ok(DecodedAccount::CellCluster(cluster))
+}
+
+/// Decode a program account through its Anchor implementation, which also checks the
+/// discriminator
+fn deserialize<T: AccountDeserialize>(
+    account: &'static str,
+    data: &[u8],
+) -> Result<T, DecodeError> {
+    T::try_deserialize(&mut &data[..]).map_err(|err| DecodeError::InvalidAccount {
+        account,
+        message: err.to_string(),
+    })
+}
+
+/// Versioned layouts repeat their version after the discriminator
+fn expect_version(account: &'static str, actual: u8, expected: u8) -> Result<(), DecodeError> {
+    if actual != expected {
+        return Err(DecodeError::VersionMismatch {
+            account,
+            expected,
+            actual,
+        });
+    }
+    Ok(())
+}
+
+/// Anchor allocates exactly `expected` bytes, the rest is zero padding
+fn expect_size(account: &'static str, data: &[u8], expected: usize) -> Result<(), DecodeError> {
+    if data.len() != expected {
+        return Err(DecodeError::SizeMismatch {
+            account,
+            expected,
+            actual: data.len(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::AccountSerialize;
+
+    use super::*;
+
+    fn placement() -> LogoPlacement {
+        LogoPlacement {
+            version: LogoPlacement::VERSION,
+            coordinates: [40_712_800, -74_006_000],
+            token_mint: Pubkey::new_unique(),
+            logo_uri: "https://example.com/logo.png".to_string(),
+            logo_hash: [7; 32],
+            placed_by: Pubkey::new_unique(),
+            placed_at: 1_700_000_000,
+            overwrite_count: 2,
+            bump: 254,
+        }
+    }
+
+    fn serialize(account: &impl AccountSerialize, size: usize) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data.resize(size, 0);
+        data
+    }
+
+    #[test]
+    fn decodes_current_and_v0_layouts() {
+        let placement = placement();
+        let data = serialize(&placement, LogoPlacement::SIZE);
+        let expected = DecodedAccount::LogoPlacement {
+            layout_version: 1,
+            account: placement.clone(),
+        };
+        assert_eq!(decode_account(&data).unwrap().to_json(), expected.to_json());
+
+        let v0 = LogoPlacementV0 {
+            coordinates: placement.coordinates,
+            token_mint: placement.token_mint,
+            logo_uri: placement.logo_uri.clone(),
+            logo_hash: placement.logo_hash,
+            placed_by: placement.placed_by,
+            placed_at: placement.placed_at,
+            overwrite_count: placement.overwrite_count,
+            bump: placement.bump,
+        };
+        let decoded = decode_account(&serialize(&v0, LogoPlacementV0::SIZE)).unwrap();
+        let json = decoded.to_json();
+        assert_eq!(json["type"], "LogoPlacement");
+        assert_eq!(json["layout_version"], 0);
+        assert_eq!(json["token_mint"], placement.token_mint.to_string());
+        assert_eq!(json["logo_hash"], hex::encode([7; 32]));
+
+        let cooldown = UserCooldown {
+            version: UserCooldown::VERSION,
+            user: Pubkey::new_unique(),
+            last_placement: 1_700_000_000,
+            placement_count: 3,
+            bump: 255,
+        };
+        let decoded = decode_account(&serialize(&cooldown, UserCooldown::SIZE)).unwrap();
+        assert_eq!(decoded.to_json()["placement_count"], 3);
+    }
+
+    #[test]
+    fn decodes_cell_clusters() {
+        let mut data = CellCluster::discriminator().to_vec();
+        data.extend(7u64.to_le_bytes());
+        for bound in [0i32, 99_999, 0, 99_999] {
+            data.extend(bound.to_le_bytes());
+        }
+        data.extend(1u32.to_le_bytes());
+        data.extend(1u32.to_le_bytes());
+        data.extend(10i32.to_le_bytes());
+        data.extend(20i32.to_le_bytes());
+        data.extend([1; 32]);
+        data.extend(3u32.to_le_bytes());
+        data.extend(b"uri");
+        data.extend([2; 32]);
+        data.extend([3; 32]);
+        data.extend(5i64.to_le_bytes());
+        data.extend(0u16.to_le_bytes());
+        data.extend(6i64.to_le_bytes());
+        data.push(250);
+        data.resize(CellCluster::MAX_SIZE, 0);
+
+        let DecodedAccount::CellCluster(cluster) = decode_account(&data).unwrap() else {
+            panic!("expected a cell cluster");
+        };
+        assert_eq!(cluster.cluster_id, 7);
+        assert_eq!(cluster.cells[0].coordinates, [10, 20]);
+        assert_eq!(cluster.cells[0].logo_uri, "uri");
+        assert_eq!(cluster.last_updated, 6);
+    }
+
+    #[test]
+    fn reports_invalid_and_mismatched_data() {
+        let data = serialize(&placement(), LogoPlacement::SIZE);
+
+        assert_eq!(
+            decode_account(&data[..LogoPlacement::SIZE - 1]).unwrap_err(),
+            DecodeError::SizeMismatch {
+                account: "LogoPlacement",
+                expected: LogoPlacement::SIZE,
+                actual: LogoPlacement::SIZE - 1,
+            }
+        );
+
+        let mut wrong_version = data.clone();
+        wrong_version[8] = 0;
+        assert_eq!(
+            decode_account(&wrong_version).unwrap_err(),
+            DecodeError::VersionMismatch {
+                account: "LogoPlacement",
+                expected: 1,
+                actual: 0,
+            }
+        );
+
+        // A URI length running past the end of the account
+        let mut long_uri = data.clone();
+        long_uri[49..53].copy_from_slice(&500u32.to_le_bytes());
+        assert!(matches!(
+            decode_account(&long_uri),
+            Err(DecodeError::InvalidAccount {
+                account: "LogoPlacement",
+                ..
+            })
+        ));
+
+        assert_eq!(
+            decode_account(&[1, 2, 3]).unwrap_err(),
+            DecodeError::MissingDiscriminator(3)
+        );
+        assert!(matches!(
+            decode_account(&[0; 16]),
+            Err(DecodeError::UnknownDiscriminator(_))
+        ));
+    }
+
+    #[test]
+    fn parses_base64_and_hex() {
+        assert_eq!(parse_base64("AQID").unwrap(), vec![1, 2, 3]);
+        assert_eq!(parse_hex("0x010203").unwrap(), vec![1, 2, 3]);
+        assert!(parse_hex("0x0102g3").is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use program::error::SolplaceError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    #[error("RPC request failed: {0}")]
+    Rpc(String),
+
+    #[error("invalid {account} account: {message}")]
+    InvalidAccount {
+        account: &'static str,
+        message: String,
+    },
+
+    #[error("invalid event: {0}")]
+    InvalidEvent(String),
+
+    #[error("{name} ({code}): {0}", name = .0.name(), code = u32::from(*.0))]
+    Program(SolplaceError),
+}
+
+/// Every `SolplaceError` in declaration order, so its index is the code minus 6000
+/// A test checks it against the errors of the published IDL.
+pub const SOLPLACE_ERRORS: &[SolplaceError] = &[
+    SolplaceError::InvalidLatitude,
+    SolplaceError::InvalidLongitude,
+    SolplaceError::InvalidCoordinates,
+    SolplaceError::InvalidTokenMint,
+    SolplaceError::UninitializedMint,
+    SolplaceError::UserOnCooldown,
+    SolplaceError::LogoUriTooLong,
+    SolplaceError::InsufficientFunds,
+    SolplaceError::InvalidTreasury,
+    SolplaceError::InvalidLogoPlacement,
+    SolplaceError::InvalidCooldown,
+    SolplaceError::InvalidAccount,
+    SolplaceError::InvalidHolderTokenAccount,
+    SolplaceError::EmptyLogoUri,
+    SolplaceError::CanonicalLogoLocked,
+    SolplaceError::InvalidMintProfile,
+    SolplaceError::MissingPreviousMintProfile,
+    SolplaceError::MissingLogoHash,
+    SolplaceError::NotLogoPlacer,
+    SolplaceError::InvalidLogoBitmap,
+    SolplaceError::LogoBitmapTooLarge,
+    SolplaceError::InvalidLogoBitmapChunk,
+    SolplaceError::Unauthorized,
+    SolplaceError::UnsupportedUriScheme,
+    SolplaceError::InvalidUriCharacters,
+    SolplaceError::InvalidUriHost,
+    SolplaceError::UriDomainNotAllowed,
+    SolplaceError::InvalidDomain,
+    SolplaceError::TooManyDomains,
+    SolplaceError::InvalidPlacer,
+    SolplaceError::PlacementAlreadyBlank,
+    SolplaceError::CannotReportOwnPlacement,
+    SolplaceError::InvalidReporter,
+    SolplaceError::MintDenied,
+    SolplaceError::UserDenied,
+    SolplaceError::PlacementRestricted,
+    SolplaceError::InvalidRestrictedZone,
+    SolplaceError::InvalidZoneTile,
+    SolplaceError::InvalidZoneShape,
+    SolplaceError::ZoneOutsideTile,
+    SolplaceError::TooManyZoneShapes,
+    SolplaceError::ProgramPaused,
+    SolplaceError::AccountAlreadyMigrated,
+    SolplaceError::InvalidRentPayer,
+    SolplaceError::CanonicalClaimWithoutBalance,
+    SolplaceError::CanonicalLogoMismatch,
+    SolplaceError::NonCanonicalBump,
+];
+
+/// Map a custom program error code (6000 and up) to its `SolplaceError`
+pub fn solplace_error(code: u32) -> Option<SolplaceError> {
+    let index = code.checked_sub(anchor_lang::error::ERROR_CODE_OFFSET)?;
+    SOLPLACE_ERRORS.get(index as usize).copied()
+}
+
+/// Extract the `SolplaceError` from a JSON-RPC transaction error
+/// such as `{"InstructionError": [0, {"Custom": 6005}]}`
+pub fn parse_transaction_error(err: &serde_json::Value) -> Option<SolplaceError> {
+    let code = err
+        .get("InstructionError")?
+        .get(1)?
+        .get("Custom")?
+        .as_u64()?;
+    solplace_error(u32::try_from(code).ok()?)
+}
+
+/// Extract the `SolplaceError` from the logs of a failed transaction
+/// Anchor logs `AnchorError ... Error Number: 6005. Error Message: ...`
+pub fn parse_error_logs<'a>(logs: impl IntoIterator<Item = &'a str>) -> Option<SolplaceError> {
+    logs.into_iter().find_map(|line| {
+        let (_, rest) = line.split_once("Error Number: ")?;
+        let code = rest.split('.').next()?.parse().ok()?;
+        solplace_error(code)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_custom_instruction_errors() {
+        let err = serde_json::json!({ "InstructionError": [0, { "Custom": 6005 }] });
+        assert_eq!(
+            parse_transaction_error(&err).map(|error| error.name()),
+            Some(SolplaceError::UserOnCooldown.name())
+        );
+        assert!(solplace_error(5999).is_none());
+    }
+
+    #[test]
+    fn error_table_matches_the_idl() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../../shared/src/idl/solplace_program.json"
+        );
+        let idl: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        let errors: Vec<_> = idl["errors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|error| {
+                let name = error["name"].as_str().unwrap().to_string();
+                (error["code"].as_u64().unwrap() as u32, name)
+            })
+            .collect();
+        let table: Vec<_> = SOLPLACE_ERRORS
+            .iter()
+            .map(|error| (u32::from(*error), error.name()))
+            .collect();
+        assert_eq!(table, errors);
+    }
+}
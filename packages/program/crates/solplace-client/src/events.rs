@@ -0,0 +1,182 @@
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+
+use crate::{get_event_authority_pda, ClientError};
+
+/// Decode an Anchor event payload, the event discriminator followed by its fields
+/// Returns `None` when the payload holds another event type
+pub fn decode_event<T: AnchorDeserialize + Discriminator>(
+    data: &[u8],
+) -> Result<Option<T>, ClientError> {
+    let Some(mut fields) = data.strip_prefix(T::DISCRIMINATOR) else {
+        return Ok(None);
+    };
+    T::deserialize(&mut fields)
+        .map(Some)
+        .map_err(|err| ClientError::InvalidEvent(err.to_string()))
+}
+
+/// Decode the data of an `emit_cpi!` self-CPI instruction
+/// Returns `None` for other instructions and other event types
+pub fn decode_cpi_event<T: AnchorDeserialize + Discriminator>(
+    data: &[u8],
+) -> Result<Option<T>, ClientError> {
+    match data.strip_prefix(EVENT_IX_TAG_LE) {
+        Some(event) => decode_event(event),
+        None => Ok(None),
+    }
+}
+
+/// Events of type `T` emitted through self-CPI in a `getTransaction` result (`json` encoding)
+///
+/// Only inner instructions that invoke the SolPlace program and are signed by its event
+/// authority count. The program rejects that instruction unless it invoked itself, so
+/// unlike log lines these events cannot be forged by other programs. Failed transactions
+/// yield no events.
+pub fn transaction_cpi_events<T: AnchorDeserialize + Discriminator>(
+    transaction: &serde_json::Value,
+) -> Result<Vec<T>, ClientError> {
+    let mut events = Vec::new();
+    for data in transaction_cpi_event_data(transaction)? {
+        events.extend(decode_event(&data)?);
+    }
+    Ok(events)
+}
+
+/// Payloads of all events emitted through self-CPI in a `getTransaction` result, in emission order
+///
+/// Each payload is the event discriminator followed by its fields, see [`decode_event`].
+/// Inner instructions are filtered like in [`transaction_cpi_events`].
+pub fn transaction_cpi_event_data(
+    transaction: &serde_json::Value,
+) -> Result<Vec<Vec<u8>>, ClientError> {
+    let meta = &transaction["meta"];
+    if !meta["err"].is_null() {
+        return Ok(Vec::new());
+    }
+
+    let account_keys = transaction_account_keys(transaction)?;
+    let event_authority = get_event_authority_pda(&program::ID).0;
+    let key_at = |index: &serde_json::Value| {
+        index
+            .as_u64()
+            .and_then(|index| account_keys.get(index as usize))
+            .copied()
+    };
+
+    let mut events = Vec::new();
+    for group in meta["innerInstructions"].as_array().into_iter().flatten() {
+        for instruction in group["instructions"].as_array().into_iter().flatten() {
+            if key_at(&instruction["programIdIndex"]) != Some(program::ID) {
+                continue;
+            }
+            if key_at(&instruction["accounts"][0]) != Some(event_authority) {
+                continue;
+            }
+            let data = instruction["data"]
+                .as_str()
+                .and_then(|data| bs58::decode(data).into_vec().ok())
+                .ok_or_else(|| ClientError::InvalidEvent("invalid instruction data".to_string()))?;
+            if let Some(event) = data.strip_prefix(EVENT_IX_TAG_LE) {
+                events.push(event.to_vec());
+            }
+        }
+    }
+    Ok(events)
+}
+
+/// Static account keys followed by those loaded from address lookup tables
+fn transaction_account_keys(transaction: &serde_json::Value) -> Result<Vec<Pubkey>, ClientError> {
+    let loaded = &transaction["meta"]["loadedAddresses"];
+    transaction["transaction"]["message"]["accountKeys"]
+        .as_array()
+        .ok_or_else(|| ClientError::InvalidEvent("missing account keys".to_string()))?
+        .iter()
+        .chain(loaded["writable"].as_array().into_iter().flatten())
+        .chain(loaded["readonly"].as_array().into_iter().flatten())
+        .map(|key| {
+            key.as_str()
+                .and_then(|key| key.parse().ok())
+                .ok_or_else(|| ClientError::InvalidEvent(format!("invalid account key {key}")))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::Event;
+    use program::instructions::LogoPlacedEvent;
+
+    use super::*;
+
+    fn placed_event() -> LogoPlacedEvent {
+        LogoPlacedEvent {
+            user: Pubkey::new_unique(),
+            cell: Pubkey::new_unique(),
+            lat: 40_712_800,
+            lng: -74_006_000,
+            token_mint: Pubkey::new_unique(),
+            logo_uri: "https://example.com/logo.png".to_string(),
+            logo_hash: [7; 32],
+            previous_token_mint: None,
+            previous_placed_by: None,
+            previous_placed_at: None,
+            overwrite_count: 0,
+            base_fee: 1_000_000,
+            holder_discount: 0,
+            holder_discount_bps: 0,
+            fee_paid: 1_000_000,
+            rent_paid: 2_498_640,
+            is_overwrite: false,
+            timestamp: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn decodes_self_cpi_event_data() {
+        let event = placed_event();
+        let data = [EVENT_IX_TAG_LE, &event.data()].concat();
+        let decoded: LogoPlacedEvent = decode_cpi_event(&data).unwrap().unwrap();
+        assert_eq!(decoded.user, event.user);
+        assert_eq!(decoded.rent_paid, 2_498_640);
+
+        // Untagged data is a regular instruction, not an event
+        assert!(decode_cpi_event::<LogoPlacedEvent>(&event.data())
+            .unwrap()
+            .is_none());
+        assert!(matches!(
+            decode_cpi_event::<LogoPlacedEvent>(&data[..data.len() - 4]),
+            Err(ClientError::InvalidEvent(_))
+        ));
+    }
+
+    #[test]
+    fn only_reads_events_signed_by_the_event_authority() {
+        let event = placed_event();
+        let data = bs58::encode([EVENT_IX_TAG_LE, &event.data()].concat()).into_string();
+        let transaction = |signer: Pubkey| {
+            serde_json::json!({
+                "transaction": { "message": { "accountKeys": [
+                    event.user.to_string(),
+                    signer.to_string(),
+                    program::ID.to_string(),
+                ] } },
+                "meta": {
+                    "err": null,
+                    "innerInstructions": [{
+                        "index": 0,
+                        "instructions": [{ "programIdIndex": 2, "accounts": [1], "data": data }],
+                    }],
+                },
+            })
+        };
+
+        let events: Vec<LogoPlacedEvent> =
+            transaction_cpi_events(&transaction(get_event_authority_pda(&program::ID).0)).unwrap();
+        assert_eq!(events.len(), 1);
+        let forged: Vec<LogoPlacedEvent> =
+            transaction_cpi_events(&transaction(Pubkey::new_unique())).unwrap();
+        assert!(forged.is_empty());
+    }
+}
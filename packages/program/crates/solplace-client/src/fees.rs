@@ -0,0 +1,57 @@
+use anchor_lang::prelude::Rent;
+use program::fees::{calculate_placement_fee, holder_discount_bps};
+use program::state::{LogoPlacement, MintProfile, UserCooldown};
+
+/// Which of the accounts `place_logo` creates on demand already exist
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExistingAccounts {
+    pub logo_placement: bool,
+    pub user_cooldown: bool,
+    pub mint_profile: bool,
+}
+
+/// Lamports a placement costs, computed with the same rules as `place_logo`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeQuote {
+    pub is_overwrite: bool,
+    pub holder_discount_bps: u16,
+    /// Fee transferred to the treasury
+    pub placement_fee: u64,
+    /// Rent deposited into the accounts the placement creates
+    pub rent: u64,
+}
+
+impl FeeQuote {
+    pub fn total(&self) -> u64 {
+        self.placement_fee + self.rent
+    }
+}
+
+/// Quote a placement
+/// `is_overwrite` is whether the cell shows a logo (blank cells are priced as empty),
+/// `holder_balance` and `supply` are the user's balance and the supply of the placed token
+pub fn quote_placement_fee(
+    is_overwrite: bool,
+    holder_balance: u64,
+    supply: u64,
+    existing: ExistingAccounts,
+) -> FeeQuote {
+    let holder_discount_bps = holder_discount_bps(holder_balance, supply);
+    let rent = Rent::default();
+    let rent_if_missing = |exists: bool, size: usize| {
+        if exists {
+            0
+        } else {
+            rent.minimum_balance(size)
+        }
+    };
+
+    FeeQuote {
+        is_overwrite,
+        holder_discount_bps,
+        placement_fee: calculate_placement_fee(is_overwrite, holder_discount_bps),
+        rent: rent_if_missing(existing.logo_placement, LogoPlacement::SIZE)
+            + rent_if_missing(existing.user_cooldown, UserCooldown::SIZE)
+            + rent_if_missing(existing.mint_profile, MintProfile::SIZE),
+    }
+}
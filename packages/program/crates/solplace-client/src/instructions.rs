@@ -0,0 +1,87 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use program::constants::TREASURY_ADDRESS;
+use program::state::get_program_config_pda;
+
+use crate::{get_event_authority_pda, PlacementPdas};
+
+/// Arguments of a `place_logo` call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaceLogoArgs {
+    /// Latitude in microdegrees
+    pub lat: i32,
+    /// Longitude in microdegrees
+    pub lng: i32,
+    pub token_mint: Pubkey,
+    pub logo_uri: String,
+    /// SHA-256 of the logo image bytes
+    pub logo_hash: [u8; 32],
+    /// Wallet that signs and pays for the placement
+    pub user: Pubkey,
+    /// User token account for `token_mint`, unlocks holder fee discounts
+    pub user_token_account: Option<Pubkey>,
+    /// Token shown by the cell being overwritten, when it differs from `token_mint`
+    pub previous_token_mint: Option<Pubkey>,
+}
+
+/// Build a `place_logo` instruction
+pub fn place_logo(args: &PlaceLogoArgs) -> Instruction {
+    let program_id = program::ID;
+    let pdas = PlacementPdas::derive(args.lat, args.lng, &args.token_mint, &args.user);
+
+    let accounts = program::accounts::PlaceLogo {
+        config: get_program_config_pda(&program_id).0,
+        logo_placement: pdas.logo_placement.0,
+        user_cooldown: pdas.user_cooldown.0,
+        token_mint: args.token_mint,
+        user_token_account: args.user_token_account,
+        mint_profile: pdas.mint_profile.0,
+        previous_mint_profile: args
+            .previous_token_mint
+            .filter(|previous| *previous != args.token_mint)
+            .map(|previous| program::state::get_mint_profile_pda(&previous, &program_id).0),
+        denied_mint: pdas.denied_mint.0,
+        denied_user: pdas.denied_user.0,
+        restricted_zone: pdas.restricted_zone.0,
+        treasury: TREASURY_ADDRESS,
+        user: args.user,
+        system_program: anchor_lang::system_program::ID,
+        event_authority: get_event_authority_pda(&program_id).0,
+        program: program_id,
+    };
+    let data = program::instruction::PlaceLogo {
+        lat: args.lat,
+        lng: args.lng,
+        bumps: pdas.bumps(),
+        token_mint: args.token_mint,
+        logo_uri: args.logo_uri.clone(),
+        logo_hash: args.logo_hash,
+    };
+
+    Instruction {
+        program_id,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Build a `migrate_account` instruction upgrading a v0 placement or cooldown account
+/// `payer` signs and covers the extra rent
+pub fn migrate_account(account: &Pubkey, payer: &Pubkey) -> Instruction {
+    let program_id = program::ID;
+    let accounts = program::accounts::MigrateAccount {
+        config: get_program_config_pda(&program_id).0,
+        account: *account,
+        payer: *payer,
+        system_program: anchor_lang::system_program::ID,
+        event_authority: get_event_authority_pda(&program_id).0,
+        program: program_id,
+    };
+
+    Instruction {
+        program_id,
+        accounts: accounts.to_account_metas(None),
+        data: program::instruction::MigrateAccount {}.data(),
+    }
+}
@@ -0,0 +1,32 @@
+//! Rust client SDK for SolPlace
+//!
+//! Reuses the account types of the `program` crate and adds what off-chain services need
+//! around them: PDA helpers, `place_logo` instruction builders, account fetching and
+//! decoding over JSON-RPC, transaction submission, fee quotes that mirror the on-chain
+//! rules, event decoding, Web Mercator tiles and error mapping.
+
+pub mod accounts;
+pub mod bbox;
+pub mod decode;
+pub mod error;
+pub mod events;
+pub mod fees;
+pub mod instructions;
+pub mod pda;
+pub mod rpc;
+pub mod tile;
+
+pub use accounts::*;
+pub use bbox::*;
+pub use decode::*;
+pub use error::*;
+pub use events::*;
+pub use fees::*;
+pub use instructions::*;
+pub use pda::*;
+pub use rpc::*;
+pub use tile::*;
+
+pub use program::error::SolplaceError;
+pub use program::state;
+pub use program::ID as PROGRAM_ID;
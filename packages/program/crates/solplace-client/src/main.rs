@@ -0,0 +1,378 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address;
+use clap::{ArgGroup, Parser, Subcommand};
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_signer::Signer;
+use solplace_client::state::LogoPlacement;
+use solplace_client::*;
+
+const DEFAULT_RPC_URL: &str = "https://api.devnet.solana.com";
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+#[derive(Parser)]
+#[command(name = "solplace", about = "Place and inspect SolPlace logos")]
+struct Cli {
+    /// Solana JSON-RPC endpoint
+    #[arg(long, global = true, default_value = DEFAULT_RPC_URL)]
+    rpc: String,
+    /// Keypair file of the paying wallet, defaults to ~/.config/solana/id.json
+    #[arg(long, global = true)]
+    keypair: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Place a token logo on a cell
+    Place {
+        /// Latitude in microdegrees
+        #[arg(long, allow_hyphen_values = true)]
+        lat: i32,
+        /// Longitude in microdegrees
+        #[arg(long, allow_hyphen_values = true)]
+        lng: i32,
+        /// Token mint to place
+        #[arg(long)]
+        mint: Pubkey,
+        /// Logo URI (https, ipfs://, ar://)
+        #[arg(long)]
+        uri: String,
+        /// SHA-256 of the logo in hex, computed by fetching `--uri` when omitted
+        #[arg(long)]
+        hash: Option<String>,
+        /// Token account for the holder discount, defaults to the wallet's associated account
+        #[arg(long)]
+        token_account: Option<Pubkey>,
+    },
+    /// Show the placement of a cell
+    Show {
+        /// Latitude in microdegrees
+        #[arg(allow_hyphen_values = true)]
+        lat: i32,
+        /// Longitude in microdegrees
+        #[arg(allow_hyphen_values = true)]
+        lng: i32,
+    },
+    /// Show the placement cooldown of a wallet
+    Cooldown {
+        /// Wallet address
+        wallet: Pubkey,
+    },
+    /// List placements inside a bounding box
+    ///
+    /// Scans every placement account of the program: the whole map downloads them all, a
+    /// smaller box first downloads only their coordinates.
+    List {
+        /// `min_lat,min_lng,max_lat,max_lng` in microdegrees, defaults to the whole map
+        #[arg(long, allow_hyphen_values = true)]
+        bbox: Option<BoundingBox>,
+    },
+    /// Quote the lamports a placement costs
+    FeeQuote {
+        /// Latitude in microdegrees
+        #[arg(long, allow_hyphen_values = true)]
+        lat: i32,
+        /// Longitude in microdegrees
+        #[arg(long, allow_hyphen_values = true)]
+        lng: i32,
+        /// Token mint to place
+        #[arg(long)]
+        mint: Pubkey,
+        /// Placing wallet, defaults to the `--keypair` wallet
+        #[arg(long)]
+        wallet: Option<Pubkey>,
+        /// Token account for the holder discount, defaults to the wallet's associated account
+        #[arg(long)]
+        token_account: Option<Pubkey>,
+    },
+    /// Check the logo of a placement against its on-chain hash
+    Verify {
+        /// Latitude in microdegrees
+        #[arg(long, allow_hyphen_values = true)]
+        lat: i32,
+        /// Longitude in microdegrees
+        #[arg(long, allow_hyphen_values = true)]
+        lng: i32,
+        /// Logo URL or local file path to check, defaults to the on-chain `logo_uri`
+        source: Option<String>,
+    },
+    /// Render the on-chain bitmap of a placement as PNG
+    Bitmap {
+        /// Latitude in microdegrees
+        #[arg(long, allow_hyphen_values = true)]
+        lat: i32,
+        /// Longitude in microdegrees
+        #[arg(long, allow_hyphen_values = true)]
+        lng: i32,
+        /// Output PNG file
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+    /// Decode raw account data offline and print it as JSON
+    #[command(group(ArgGroup::new("input").required(true).args(["base64", "hex", "file"])))]
+    Decode {
+        /// Account data in base64, as shown by explorers
+        #[arg(long)]
+        base64: Option<String>,
+        /// Account data in hex
+        #[arg(long)]
+        hex: Option<String>,
+        /// File with the raw account bytes
+        #[arg(long)]
+        file: Option<PathBuf>,
+    },
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum CliError {
+    #[error(transparent)]
+    Client(#[from] ClientError),
+
+    #[error(transparent)]
+    Logo(#[from] solplace_logo::VerifyError),
+
+    #[error(transparent)]
+    Bitmap(#[from] solplace_logo::BitmapError),
+
+    #[error("no {account} at ({lat}, {lng})")]
+    NotFound {
+        account: &'static str,
+        lat: i32,
+        lng: i32,
+    },
+
+    #[error(transparent)]
+    Decode(#[from] DecodeError),
+
+    #[error("cannot read keypair {path}: {message}")]
+    Keypair { path: PathBuf, message: String },
+
+    #[error("{path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+fn run(cli: Cli) -> Result<ExitCode, CliError> {
+    let client = RpcClient::new(&cli.rpc);
+    match cli.command {
+        Command::Place {
+            lat,
+            lng,
+            mint,
+            uri,
+            hash,
+            token_account,
+        } => {
+            let payer = read_keypair(cli.keypair)?;
+            let logo_hash = match hash {
+                Some(hash) => solplace_logo::parse_logo_hash(&hash)?,
+                None => solplace_logo::logo_hash(&solplace_logo::LogoSource::parse(&uri).fetch()?),
+            };
+            let user_token_account =
+                holder_account(&client, &payer.pubkey(), &mint, token_account)?;
+
+            let instruction = client.place_logo_instruction(PlaceLogoArgs {
+                lat,
+                lng,
+                token_mint: mint,
+                logo_uri: uri,
+                logo_hash,
+                user: payer.pubkey(),
+                user_token_account,
+                previous_token_mint: None,
+            })?;
+            let signature = client.send_and_confirm(&[instruction], &payer)?;
+            println!("{signature}");
+        }
+        Command::Show { lat, lng } => {
+            let (address, _) = get_logo_placement_pda(lat, lng, &PROGRAM_ID);
+            let Some(placement) = client.fetch_logo_placement(lat, lng)? else {
+                println!("no placement at ({lat}, {lng})");
+                return Ok(ExitCode::FAILURE);
+            };
+            print_placement(&address, &placement);
+        }
+        Command::Cooldown { wallet } => {
+            let Some(cooldown) = client.fetch_user_cooldown(&wallet)? else {
+                println!("no placements by {wallet}");
+                return Ok(ExitCode::SUCCESS);
+            };
+            println!("placement_count     {}", cooldown.placement_count);
+            println!("last_placement      {}", cooldown.last_placement);
+            println!(
+                "remaining_cooldown  {}s",
+                cooldown.remaining_cooldown(unix_timestamp())
+            );
+        }
+        Command::List { bbox } => {
+            let placements = client.fetch_logo_placements(&bbox.unwrap_or(BoundingBox::WORLD))?;
+            for (_, placement) in &placements {
+                let [lat, lng] = placement.coordinates;
+                println!(
+                    "{lat}\t{lng}\t{}\t{}\t{}",
+                    token_label(placement),
+                    placement.placed_by,
+                    placement.overwrite_count
+                );
+            }
+            eprintln!("{} placements", placements.len());
+        }
+        Command::FeeQuote {
+            lat,
+            lng,
+            mint,
+            wallet,
+            token_account,
+        } => {
+            let wallet = match wallet {
+                Some(wallet) => wallet,
+                None => read_keypair(cli.keypair)?.pubkey(),
+            };
+            let user_token_account = holder_account(&client, &wallet, &mint, token_account)?;
+            let quote =
+                client.quote_placement(lat, lng, &mint, &wallet, user_token_account.as_ref())?;
+
+            println!("overwrite        {}", quote.is_overwrite);
+            println!(
+                "holder_discount  {}.{:02}%",
+                quote.holder_discount_bps / 100,
+                quote.holder_discount_bps % 100
+            );
+            println!("placement_fee    {}", lamports(quote.placement_fee));
+            println!("rent             {}", lamports(quote.rent));
+            println!("total            {}", lamports(quote.total()));
+        }
+        Command::Verify { lat, lng, source } => {
+            let placement = client
+                .fetch_logo_placement(lat, lng)?
+                .ok_or(CliError::NotFound {
+                    account: "logo placement",
+                    lat,
+                    lng,
+                })?;
+            let source =
+                solplace_logo::LogoSource::parse(source.as_deref().unwrap_or(&placement.logo_uri));
+            let verification = solplace_logo::verify_logo(&source, placement.logo_hash)?;
+            println!("{verification}");
+            if !verification.matches() {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Bitmap { lat, lng, output } => {
+            let account = client
+                .fetch_logo_bitmap(lat, lng)?
+                .ok_or(CliError::NotFound {
+                    account: "logo bitmap",
+                    lat,
+                    lng,
+                })?;
+            let bitmap = solplace_logo::decode_logo_bitmap(&account)?;
+            if !client
+                .fetch_logo_placement(lat, lng)?
+                .is_some_and(|placement| account.is_current(&placement))
+            {
+                eprintln!("warning: bitmap predates the current placement");
+            }
+            let size = bitmap.size as u32;
+            let png = solplace_logo::write_png(size, size, &bitmap.to_rgba())?;
+            std::fs::write(&output, png).map_err(|source| CliError::Io {
+                path: output,
+                source,
+            })?;
+        }
+        Command::Decode { base64, hex, file } => {
+            let data = match (base64, hex, file) {
+                (Some(base64), _, _) => parse_base64(&base64)?,
+                (_, Some(hex), _) => parse_hex(&hex)?,
+                (_, _, Some(path)) => {
+                    std::fs::read(&path).map_err(|source| CliError::Io { path, source })?
+                }
+                _ => unreachable!("clap requires one input"),
+            };
+            let account = decode_account(&data)?;
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&account.to_json()).expect("JSON values serialize")
+            );
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn read_keypair(path: Option<PathBuf>) -> Result<Keypair, CliError> {
+    let path = path.unwrap_or_else(|| {
+        let home = std::env::var_os("HOME").unwrap_or_default();
+        PathBuf::from(home).join(".config/solana/id.json")
+    });
+    read_keypair_file(&path).map_err(|err| CliError::Keypair {
+        path,
+        message: err.to_string(),
+    })
+}
+
+/// The explicit token account, or the wallet's associated token account when it exists
+fn holder_account(
+    client: &RpcClient,
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    explicit: Option<Pubkey>,
+) -> Result<Option<Pubkey>, CliError> {
+    if explicit.is_some() {
+        return Ok(explicit);
+    }
+    let address = get_associated_token_address(wallet, mint);
+    let exists = client
+        .get_account(&address)?
+        .is_some_and(|account| account.owner == anchor_spl::token::ID);
+    Ok(exists.then_some(address))
+}
+
+fn print_placement(address: &Pubkey, placement: &LogoPlacement) {
+    let [lat, lng] = placement.coordinates;
+    println!("address          {address}");
+    println!("coordinates      {lat}, {lng}");
+    println!("token_mint       {}", token_label(placement));
+    println!("logo_uri         {}", placement.logo_uri);
+    println!("logo_hash        {}", hex::encode(placement.logo_hash));
+    println!("placed_by        {}", placement.placed_by);
+    println!("placed_at        {}", placement.placed_at);
+    println!("overwrite_count  {}", placement.overwrite_count);
+}
+
+fn token_label(placement: &LogoPlacement) -> String {
+    if placement.is_blank() {
+        "(blank)".to_string()
+    } else {
+        placement.token_mint.to_string()
+    }
+}
+
+fn lamports(amount: u64) -> String {
+    format!(
+        "{amount} lamports ({} SOL)",
+        amount as f64 / LAMPORTS_PER_SOL
+    )
+}
+
+fn unix_timestamp() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
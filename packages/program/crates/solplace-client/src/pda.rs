@@ -0,0 +1,51 @@
+use anchor_lang::prelude::Pubkey;
+use program::constants::EVENT_AUTHORITY_SEED;
+use program::geo::tile_of;
+use program::instructions::PlacementBumps;
+
+pub use program::state::{
+    get_denied_mint_pda, get_denied_user_pda, get_logo_bitmap_pda, get_logo_placement_pda,
+    get_mint_profile_pda, get_program_config_pda, get_restricted_zone_pda, get_user_cooldown_pda,
+};
+
+/// Signer of the self-CPI instructions that carry `emit_cpi!` events
+pub fn get_event_authority_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], program_id)
+}
+
+/// Every PDA touched by a `place_logo` call, with its canonical bump
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlacementPdas {
+    pub logo_placement: (Pubkey, u8),
+    pub user_cooldown: (Pubkey, u8),
+    pub mint_profile: (Pubkey, u8),
+    pub denied_mint: (Pubkey, u8),
+    pub denied_user: (Pubkey, u8),
+    pub restricted_zone: (Pubkey, u8),
+}
+
+impl PlacementPdas {
+    /// Derive the PDAs of a placement at `(lat, lng)` of `token_mint` by `user`
+    pub fn derive(lat: i32, lng: i32, token_mint: &Pubkey, user: &Pubkey) -> Self {
+        let program_id = &program::ID;
+        PlacementPdas {
+            logo_placement: get_logo_placement_pda(lat, lng, program_id),
+            user_cooldown: get_user_cooldown_pda(user, program_id),
+            mint_profile: get_mint_profile_pda(token_mint, program_id),
+            denied_mint: get_denied_mint_pda(token_mint, program_id),
+            denied_user: get_denied_user_pda(user, program_id),
+            restricted_zone: get_restricted_zone_pda(tile_of(lat, lng), program_id),
+        }
+    }
+
+    /// Bumps passed to `place_logo`, so the program does not have to search for them
+    pub fn bumps(&self) -> PlacementBumps {
+        PlacementBumps {
+            logo_placement: self.logo_placement.1,
+            user_cooldown: self.user_cooldown.1,
+            denied_mint: self.denied_mint.1,
+            denied_user: self.denied_user.1,
+            restricted_zone: self.restricted_zone.1,
+        }
+    }
+}
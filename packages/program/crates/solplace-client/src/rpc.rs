@@ -0,0 +1,541 @@
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::Hash;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::Discriminator;
+use base64::Engine;
+use program::state::{
+    get_logo_bitmap_pda, LogoBitmap, LogoPlacement, LogoPlacementV0, MintProfile, ProgramConfig,
+    ReportTally, UserCooldown, UserCooldownV0,
+};
+use solana_keypair::Keypair;
+use solana_signature::Signature;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+use crate::*;
+
+/// Accounts `getMultipleAccounts` returns per request
+const MULTIPLE_ACCOUNTS_LIMIT: usize = 100;
+
+/// Account as returned by `getAccountInfo`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcAccount {
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub data: Vec<u8>,
+}
+
+impl RpcAccount {
+    /// Whether the account was created by the SolPlace program
+    pub fn is_program_owned(&self) -> bool {
+        self.owner == program::ID
+    }
+
+    /// Whether the account is a placement or cooldown still in the v0 layout
+    /// `place_logo` upgrades the accounts it touches, `migrate_account` upgrades any of them
+    pub fn needs_migration(&self) -> bool {
+        self.is_program_owned()
+            && (self.data.starts_with(LogoPlacementV0::DISCRIMINATOR)
+                || self.data.starts_with(UserCooldownV0::DISCRIMINATOR))
+    }
+}
+
+/// Minimal blocking JSON-RPC client for SolPlace state and transactions
+#[derive(Debug, Clone)]
+pub struct RpcClient {
+    url: String,
+    commitment: String,
+}
+
+impl RpcClient {
+    /// Client reading and confirming at `confirmed` commitment
+    pub fn new(url: impl Into<String>) -> Self {
+        RpcClient {
+            url: url.into(),
+            commitment: "confirmed".to_string(),
+        }
+    }
+
+    /// Use another commitment level (`processed`, `confirmed` or `finalized`)
+    pub fn with_commitment(mut self, commitment: impl Into<String>) -> Self {
+        self.commitment = commitment.into();
+        self
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Send a JSON-RPC request and return its `result`
+    /// Failed simulations that hit a `SolplaceError` are reported as `ClientError::Program`
+    pub fn request(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value, ClientError> {
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+        let mut response: serde_json::Value = ureq::post(&self.url)
+            .send_json(request)
+            .map_err(|err| ClientError::Rpc(err.to_string()))?
+            .into_json()
+            .map_err(|err| ClientError::Rpc(err.to_string()))?;
+
+        if let Some(error) = response.get("error") {
+            let data = &error["data"];
+            let program_error = parse_transaction_error(&data["err"])
+                .or_else(|| parse_error_logs(log_lines(&data["logs"])));
+            return Err(match program_error {
+                Some(program_error) => ClientError::Program(program_error),
+                None => ClientError::Rpc(error.to_string()),
+            });
+        }
+        Ok(response["result"].take())
+    }
+
+    pub fn get_account(&self, address: &Pubkey) -> Result<Option<RpcAccount>, ClientError> {
+        let result = self.request(
+            "getAccountInfo",
+            serde_json::json!([
+                address.to_string(),
+                { "encoding": "base64", "commitment": self.commitment },
+            ]),
+        )?;
+        parse_account(&result["value"])
+    }
+
+    pub fn get_multiple_accounts(
+        &self,
+        addresses: &[Pubkey],
+    ) -> Result<Vec<Option<RpcAccount>>, ClientError> {
+        let addresses: Vec<String> = addresses.iter().map(Pubkey::to_string).collect();
+        let result = self.request(
+            "getMultipleAccounts",
+            serde_json::json!([
+                addresses,
+                { "encoding": "base64", "commitment": self.commitment },
+            ]),
+        )?;
+        result["value"]
+            .as_array()
+            .ok_or_else(|| ClientError::Rpc("missing accounts".to_string()))?
+            .iter()
+            .map(parse_account)
+            .collect()
+    }
+
+    /// Accounts of the SolPlace program matching `getProgramAccounts` `filters`
+    pub fn get_program_accounts(
+        &self,
+        filters: serde_json::Value,
+    ) -> Result<Vec<(Pubkey, RpcAccount)>, ClientError> {
+        let result = self.request(
+            "getProgramAccounts",
+            serde_json::json!([
+                program::ID.to_string(),
+                { "encoding": "base64", "commitment": self.commitment, "filters": filters },
+            ]),
+        )?;
+        parse_program_accounts(&result)
+    }
+
+    /// Like `get_program_accounts`, returning only `length` bytes of data from `offset`
+    pub fn get_program_account_slices(
+        &self,
+        filters: serde_json::Value,
+        offset: usize,
+        length: usize,
+    ) -> Result<Vec<(Pubkey, RpcAccount)>, ClientError> {
+        let result = self.request(
+            "getProgramAccounts",
+            serde_json::json!([
+                program::ID.to_string(),
+                {
+                    "encoding": "base64",
+                    "commitment": self.commitment,
+                    "filters": filters,
+                    "dataSlice": { "offset": offset, "length": length },
+                },
+            ]),
+        )?;
+        parse_program_accounts(&result)
+    }
+
+    /// Every placement inside `bbox`, blanked cells included, sorted by coordinates
+    ///
+    /// Accounts are selected by discriminator and size. Unless `bbox` is the whole map, a first
+    /// pass reads only their coordinates and the placements inside are then fetched in batches.
+    pub fn fetch_logo_placements(
+        &self,
+        bbox: &BoundingBox,
+    ) -> Result<Vec<(Pubkey, LogoPlacement)>, ClientError> {
+        let mut placements = Vec::new();
+        for (discriminator, size, coordinates_offset) in [
+            (LogoPlacement::DISCRIMINATOR, LogoPlacement::SIZE, 8 + 1),
+            (LogoPlacementV0::DISCRIMINATOR, LogoPlacementV0::SIZE, 8),
+        ] {
+            let filters = serde_json::json!([
+                { "dataSize": size },
+                {
+                    "memcmp": {
+                        "offset": 0,
+                        "bytes": base64::engine::general_purpose::STANDARD.encode(discriminator),
+                        "encoding": "base64",
+                    },
+                },
+            ]);
+            let accounts = if *bbox == BoundingBox::WORLD {
+                self.get_program_accounts(filters)?
+            } else {
+                let inside: Vec<Pubkey> = self
+                    .get_program_account_slices(filters, coordinates_offset, 8)?
+                    .into_iter()
+                    .filter(|(_, slice)| {
+                        let coordinate = |at: usize| {
+                            slice.data.get(at..at + 4).map(|bytes| {
+                                i32::from_le_bytes(bytes.try_into().expect("4 bytes"))
+                            })
+                        };
+                        matches!(
+                            (coordinate(0), coordinate(4)),
+                            (Some(lat), Some(lng)) if bbox.contains(lat, lng)
+                        )
+                    })
+                    .map(|(address, _)| address)
+                    .collect();
+                let mut accounts = Vec::with_capacity(inside.len());
+                for batch in inside.chunks(MULTIPLE_ACCOUNTS_LIMIT) {
+                    // Accounts closed since the first pass come back empty
+                    let fetched = self.get_multiple_accounts(batch)?;
+                    accounts.extend(
+                        batch
+                            .iter()
+                            .zip(fetched)
+                            .filter_map(|(address, account)| Some((*address, account?))),
+                    );
+                }
+                accounts
+            };
+            for (address, placement) in logo_placements(accounts)? {
+                let [lat, lng] = placement.coordinates;
+                if bbox.contains(lat, lng) {
+                    placements.push((address, placement));
+                }
+            }
+        }
+        placements.sort_by_key(|(_, placement)| placement.coordinates);
+        Ok(placements)
+    }
+
+    /// Placements hidden by reports, see `hidden_placements`
+    pub fn fetch_hidden_placements(&self) -> Result<HashSet<(Pubkey, i64)>, ClientError> {
+        let filters = serde_json::json!([{
+            "memcmp": {
+                "offset": 0,
+                "bytes": base64::engine::general_purpose::STANDARD.encode(ReportTally::DISCRIMINATOR),
+                "encoding": "base64",
+            },
+        }]);
+        hidden_placements(self.get_program_accounts(filters)?)
+    }
+
+    /// Raw token amount held by an SPL token account
+    pub fn get_token_account_balance(&self, address: &Pubkey) -> Result<u64, ClientError> {
+        let result = self.request(
+            "getTokenAccountBalance",
+            serde_json::json!([address.to_string(), { "commitment": self.commitment }]),
+        )?;
+        parse_amount(&result)
+    }
+
+    /// Raw supply of an SPL token mint
+    pub fn get_token_supply(&self, token_mint: &Pubkey) -> Result<u64, ClientError> {
+        let result = self.request(
+            "getTokenSupply",
+            serde_json::json!([token_mint.to_string(), { "commitment": self.commitment }]),
+        )?;
+        parse_amount(&result)
+    }
+
+    pub fn get_latest_blockhash(&self) -> Result<Hash, ClientError> {
+        let result = self.request(
+            "getLatestBlockhash",
+            serde_json::json!([{ "commitment": self.commitment }]),
+        )?;
+        result["value"]["blockhash"]
+            .as_str()
+            .and_then(|blockhash| blockhash.parse().ok())
+            .ok_or_else(|| ClientError::Rpc("invalid blockhash".to_string()))
+    }
+
+    /// Submit a signed transaction after preflight simulation
+    pub fn send_transaction(&self, transaction: &Transaction) -> Result<Signature, ClientError> {
+        let wire = bincode::serialize(transaction)
+            .map_err(|err| ClientError::Rpc(format!("cannot serialize transaction: {err}")))?;
+        let result = self.request(
+            "sendTransaction",
+            serde_json::json!([
+                base64::engine::general_purpose::STANDARD.encode(wire),
+                { "encoding": "base64", "preflightCommitment": self.commitment },
+            ]),
+        )?;
+        result
+            .as_str()
+            .and_then(|signature| signature.parse().ok())
+            .ok_or_else(|| ClientError::Rpc("invalid signature".to_string()))
+    }
+
+    /// Wait until `signature` reaches the client commitment or `timeout` elapses
+    pub fn confirm_transaction(
+        &self,
+        signature: &Signature,
+        timeout: Duration,
+    ) -> Result<(), ClientError> {
+        let started = Instant::now();
+        loop {
+            let result = self.request(
+                "getSignatureStatuses",
+                serde_json::json!([[signature.to_string()]]),
+            )?;
+            let status = &result["value"][0];
+            if !status.is_null() {
+                let err = &status["err"];
+                if !err.is_null() {
+                    return Err(match parse_transaction_error(err) {
+                        Some(program_error) => ClientError::Program(program_error),
+                        None => ClientError::Rpc(format!("transaction failed: {err}")),
+                    });
+                }
+                if reaches_commitment(status, &self.commitment) {
+                    return Ok(());
+                }
+            }
+            if started.elapsed() > timeout {
+                return Err(ClientError::Rpc(format!(
+                    "transaction {signature} not confirmed after {}s",
+                    timeout.as_secs()
+                )));
+            }
+            std::thread::sleep(Duration::from_millis(500));
+        }
+    }
+
+    /// Sign `instructions` with `payer`, send them and wait for confirmation
+    pub fn send_and_confirm(
+        &self,
+        instructions: &[Instruction],
+        payer: &Keypair,
+    ) -> Result<Signature, ClientError> {
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &[payer],
+            self.get_latest_blockhash()?,
+        );
+        let signature = self.send_transaction(&transaction)?;
+        self.confirm_transaction(&signature, Duration::from_secs(60))?;
+        Ok(signature)
+    }
+
+    pub fn fetch_logo_placement(
+        &self,
+        lat: i32,
+        lng: i32,
+    ) -> Result<Option<LogoPlacement>, ClientError> {
+        let (address, _) = get_logo_placement_pda(lat, lng, &program::ID);
+        self.fetch(&address, decode_logo_placement)
+    }
+
+    pub fn fetch_logo_bitmap(&self, lat: i32, lng: i32) -> Result<Option<LogoBitmap>, ClientError> {
+        let (address, _) = get_logo_bitmap_pda(lat, lng, &program::ID);
+        self.fetch(&address, decode_logo_bitmap_account)
+    }
+
+    pub fn fetch_user_cooldown(&self, user: &Pubkey) -> Result<Option<UserCooldown>, ClientError> {
+        let (address, _) = get_user_cooldown_pda(user, &program::ID);
+        self.fetch(&address, decode_user_cooldown)
+    }
+
+    pub fn fetch_mint_profile(
+        &self,
+        token_mint: &Pubkey,
+    ) -> Result<Option<MintProfile>, ClientError> {
+        let (address, _) = get_mint_profile_pda(token_mint, &program::ID);
+        self.fetch(&address, decode_mint_profile)
+    }
+
+    pub fn fetch_program_config(&self) -> Result<Option<ProgramConfig>, ClientError> {
+        let (address, _) = get_program_config_pda(&program::ID);
+        self.fetch(&address, decode_program_config)
+    }
+
+    /// Look up which placement accounts exist and what the cell currently shows
+    /// Returns the existing accounts and the cell's placement, if any
+    pub fn placement_state(
+        &self,
+        lat: i32,
+        lng: i32,
+        token_mint: &Pubkey,
+        user: &Pubkey,
+    ) -> Result<(ExistingAccounts, Option<LogoPlacement>), ClientError> {
+        let pdas = PlacementPdas::derive(lat, lng, token_mint, user);
+        let accounts = self.get_multiple_accounts(&[
+            pdas.logo_placement.0,
+            pdas.user_cooldown.0,
+            pdas.mint_profile.0,
+        ])?;
+        let exists = |index: usize| {
+            accounts[index]
+                .as_ref()
+                .is_some_and(RpcAccount::is_program_owned)
+        };
+
+        let placement = match &accounts[0] {
+            Some(account) if account.is_program_owned() => {
+                Some(decode_logo_placement(&account.data)?)
+            }
+            _ => None,
+        };
+        let existing = ExistingAccounts {
+            logo_placement: exists(0),
+            user_cooldown: exists(1),
+            mint_profile: exists(2),
+        };
+        Ok((existing, placement))
+    }
+
+    /// Build a `place_logo` instruction for the current on-chain state of the cell
+    /// Placement and cooldown accounts still in the v0 layout are upgraded by the program
+    pub fn place_logo_instruction(
+        &self,
+        mut args: PlaceLogoArgs,
+    ) -> Result<Instruction, ClientError> {
+        let (_, placement) =
+            self.placement_state(args.lat, args.lng, &args.token_mint, &args.user)?;
+        if args.previous_token_mint.is_none() {
+            args.previous_token_mint = placement
+                .filter(|placement| !placement.is_blank())
+                .map(|placement| placement.token_mint);
+        }
+        Ok(place_logo(&args))
+    }
+
+    /// Quote a placement of `token_mint` at a cell by `user`
+    /// `user_token_account` is the holder account passed to `place_logo`, if any
+    pub fn quote_placement(
+        &self,
+        lat: i32,
+        lng: i32,
+        token_mint: &Pubkey,
+        user: &Pubkey,
+        user_token_account: Option<&Pubkey>,
+    ) -> Result<FeeQuote, ClientError> {
+        let (existing, placement) = self.placement_state(lat, lng, token_mint, user)?;
+        let is_overwrite = placement.is_some_and(|placement| !placement.is_blank());
+        let (holder_balance, supply) = match user_token_account {
+            Some(address) => (
+                self.get_token_account_balance(address)?,
+                self.get_token_supply(token_mint)?,
+            ),
+            None => (0, 0),
+        };
+        Ok(quote_placement_fee(
+            is_overwrite,
+            holder_balance,
+            supply,
+            existing,
+        ))
+    }
+
+    fn fetch<T>(
+        &self,
+        address: &Pubkey,
+        decode: fn(&[u8]) -> Result<T, ClientError>,
+    ) -> Result<Option<T>, ClientError> {
+        match self.get_account(address)? {
+            Some(account) if account.is_program_owned() => decode(&account.data).map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+fn log_lines(logs: &serde_json::Value) -> impl Iterator<Item = &str> {
+    logs.as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(serde_json::Value::as_str)
+}
+
+fn reaches_commitment(status: &serde_json::Value, commitment: &str) -> bool {
+    let rank = |level: &str| match level {
+        "processed" => 0,
+        "confirmed" => 1,
+        _ => 2,
+    };
+    status["confirmationStatus"]
+        .as_str()
+        .is_some_and(|reached| rank(reached) >= rank(commitment))
+}
+
+/// Parse the `amount` string of a `UiTokenAmount`
+fn parse_amount(result: &serde_json::Value) -> Result<u64, ClientError> {
+    result["value"]["amount"]
+        .as_str()
+        .and_then(|amount| amount.parse().ok())
+        .ok_or_else(|| ClientError::Rpc("invalid token amount".to_string()))
+}
+
+/// Accounts of a `getProgramAccounts` result with `base64` encoding
+/// Also takes a whole saved response, reading the accounts under `result`
+pub fn parse_program_accounts(
+    value: &serde_json::Value,
+) -> Result<Vec<(Pubkey, RpcAccount)>, ClientError> {
+    let invalid = || ClientError::Rpc("invalid program accounts".to_string());
+    value
+        .get("result")
+        .unwrap_or(value)
+        .as_array()
+        .ok_or_else(invalid)?
+        .iter()
+        .map(|entry| {
+            let address = entry["pubkey"]
+                .as_str()
+                .and_then(|address| address.parse().ok())
+                .ok_or_else(invalid)?;
+            let account = parse_account(&entry["account"])?.ok_or_else(invalid)?;
+            Ok((address, account))
+        })
+        .collect()
+}
+
+fn parse_account(value: &serde_json::Value) -> Result<Option<RpcAccount>, ClientError> {
+    if value.is_null() {
+        return Ok(None);
+    }
+
+    let invalid = |field: &str| ClientError::Rpc(format!("invalid account {field}"));
+    let lamports = value["lamports"]
+        .as_u64()
+        .ok_or_else(|| invalid("lamports"))?;
+    let owner = value["owner"]
+        .as_str()
+        .and_then(|owner| owner.parse().ok())
+        .ok_or_else(|| invalid("owner"))?;
+    let data = value["data"][0]
+        .as_str()
+        .and_then(|data| base64::engine::general_purpose::STANDARD.decode(data).ok())
+        .ok_or_else(|| invalid("data"))?;
+
+    Ok(Some(RpcAccount {
+        lamports,
+        owner,
+        data,
+    }))
+}
@@ -0,0 +1,142 @@
+use std::f64::consts::PI;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::BoundingBox;
+
+/// Edge of an XYZ tile in pixels
+pub const TILE_SIZE: u32 = 256;
+/// Web Mercator cuts off the poles at ±85.0511°
+pub const MAX_MERCATOR_LAT: f64 = 85.051_128_78;
+
+/// Position of `(lat, lng)` microdegrees on the Web Mercator world map at `zoom`, in pixels
+/// from its top left corner; the map is `TILE_SIZE << zoom` pixels wide
+pub fn project(lat: i32, lng: i32, zoom: u8) -> (f64, f64) {
+    let size = f64::from(TILE_SIZE) * f64::from(1u32 << zoom);
+    let lat = (f64::from(lat) / 1_000_000.0)
+        .clamp(-MAX_MERCATOR_LAT, MAX_MERCATOR_LAT)
+        .to_radians();
+    let lng = f64::from(lng) / 1_000_000.0;
+
+    let x = (lng + 180.0) / 360.0 * size;
+    let y = (1.0 - lat.tan().asinh() / PI) / 2.0 * size;
+    (x, y)
+}
+
+/// Inverse of `project`, in microdegrees
+pub fn unproject(x: f64, y: f64, zoom: u8) -> (i32, i32) {
+    let size = f64::from(TILE_SIZE) * f64::from(1u32 << zoom);
+    let lng = x / size * 360.0 - 180.0;
+    let lat = (PI * (1.0 - 2.0 * y / size)).sinh().atan().to_degrees();
+    (
+        (lat * 1_000_000.0).round() as i32,
+        (lng * 1_000_000.0).round() as i32,
+    )
+}
+
+/// XYZ (slippy map) tile in the Web Mercator projection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct Tile {
+    pub z: u8,
+    pub x: u32,
+    pub y: u32,
+}
+
+impl Tile {
+    /// Tile containing the cell at `(lat, lng)` microdegrees
+    pub fn containing(lat: i32, lng: i32, z: u8) -> Self {
+        let (x, y) = project(lat, lng, z);
+        let max = f64::from((1u32 << z) - 1);
+        let tile = f64::from(TILE_SIZE);
+        Tile {
+            z,
+            x: (x / tile).floor().clamp(0.0, max) as u32,
+            y: (y / tile).floor().clamp(0.0, max) as u32,
+        }
+    }
+
+    /// Coordinates covered by the tile, edges included
+    pub fn bounds(&self) -> BoundingBox {
+        let tile = f64::from(TILE_SIZE);
+        let (max_lat, min_lng) =
+            unproject(f64::from(self.x) * tile, f64::from(self.y) * tile, self.z);
+        let (min_lat, max_lng) = unproject(
+            f64::from(self.x + 1) * tile,
+            f64::from(self.y + 1) * tile,
+            self.z,
+        );
+        BoundingBox {
+            min_lat,
+            min_lng,
+            max_lat,
+            max_lng,
+        }
+    }
+}
+
+/// Parse `z/x/y`
+impl FromStr for Tile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid tile {s:?}: expected z/x/y");
+        let mut parts = s.split('/');
+        let (Some(z), Some(x), Some(y), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        let z: u8 = z.parse().map_err(|_| invalid())?;
+        let x: u32 = x.parse().map_err(|_| invalid())?;
+        let y: u32 = y.parse().map_err(|_| invalid())?;
+        if z > 31 || u64::from(x) >= 1 << z || u64::from(y) >= 1 << z {
+            return Err(format!("tile {s} is outside zoom {z}"));
+        }
+        Ok(Tile { z, x, y })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_coordinates_to_xyz_tiles() {
+        assert_eq!(Tile::containing(0, 0, 0), Tile { z: 0, x: 0, y: 0 });
+        assert_eq!(Tile::containing(1, 1, 1), Tile { z: 1, x: 1, y: 0 });
+        assert_eq!(
+            Tile::containing(40_712_800, -74_006_000, 10),
+            Tile {
+                z: 10,
+                x: 301,
+                y: 385
+            }
+        );
+        // Poles and the antimeridian stay on the grid
+        assert_eq!(
+            Tile::containing(90_000_000, 180_000_000, 2),
+            Tile { z: 2, x: 3, y: 0 }
+        );
+        assert_eq!(
+            Tile::containing(-90_000_000, -180_000_000, 2),
+            Tile { z: 2, x: 0, y: 3 }
+        );
+    }
+
+    #[test]
+    fn bounds_round_trip_through_the_projection() {
+        let tile: Tile = "10/301/385".parse().unwrap();
+        let bounds = tile.bounds();
+        assert!(bounds.contains(40_712_800, -74_006_000));
+        assert_eq!(bounds.min_lng, -74_179_688);
+        assert_eq!(bounds.max_lng, -73_828_125);
+        assert_eq!(
+            Tile::containing(bounds.max_lat - 1, bounds.min_lng + 1, 10),
+            tile
+        );
+
+        assert!("3/8/0".parse::<Tile>().is_err());
+        assert!("3/1".parse::<Tile>().is_err());
+    }
+}
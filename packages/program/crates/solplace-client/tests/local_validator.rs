@@ -0,0 +1,214 @@
+//! End-to-end tests against a local validator
+//!
+//! Start `solana-test-validator`, deploy the program and initialize its config
+//! (`anchor deploy && pnpm test` does both), then run
+//! `cargo test -p solplace-client --test local_validator -- --ignored`.
+//! `SOLPLACE_RPC_URL` points the tests at another cluster.
+
+use std::process::Command;
+use std::time::Duration;
+
+use anchor_lang::prelude::{Pubkey, Rent};
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_spl::token::spl_token;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use solana_system_interface::instruction as system_instruction;
+use solana_transaction::Transaction;
+use solplace_client::*;
+
+fn client() -> RpcClient {
+    RpcClient::new(
+        std::env::var("SOLPLACE_RPC_URL").unwrap_or_else(|_| "http://127.0.0.1:8899".to_string()),
+    )
+}
+
+fn funded_wallet(client: &RpcClient) -> Keypair {
+    let wallet = Keypair::new();
+    let signature = client
+        .request(
+            "requestAirdrop",
+            serde_json::json!([wallet.pubkey().to_string(), 2_000_000_000u64]),
+        )
+        .unwrap();
+    let signature = signature.as_str().unwrap().parse().unwrap();
+    client
+        .confirm_transaction(&signature, Duration::from_secs(30))
+        .unwrap();
+    wallet
+}
+
+fn create_mint(client: &RpcClient, payer: &Keypair) -> Pubkey {
+    let mint = Keypair::new();
+    let instructions = [
+        system_instruction::create_account(
+            &payer.pubkey(),
+            &mint.pubkey(),
+            Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            spl_token::state::Mint::LEN as u64,
+            &spl_token::ID,
+        ),
+        spl_token::instruction::initialize_mint2(
+            &spl_token::ID,
+            &mint.pubkey(),
+            &payer.pubkey(),
+            None,
+            6,
+        )
+        .unwrap(),
+    ];
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&payer.pubkey()),
+        &[payer, &mint],
+        client.get_latest_blockhash().unwrap(),
+    );
+    let signature = client.send_transaction(&transaction).unwrap();
+    client
+        .confirm_transaction(&signature, Duration::from_secs(30))
+        .unwrap();
+    mint.pubkey()
+}
+
+/// A cell unlikely to be used by other runs
+fn fresh_cell(wallet: &Pubkey) -> (i32, i32) {
+    let bytes = wallet.to_bytes();
+    let offset =
+        |i: usize| i32::from_le_bytes(bytes[i..i + 4].try_into().unwrap()).rem_euclid(1_000_000);
+    (-60_000_000 + offset(0), 150_000_000 + offset(4))
+}
+
+#[test]
+#[ignore = "needs a local validator with the program deployed"]
+fn places_and_reads_back_a_logo() {
+    let client = client();
+    let wallet = funded_wallet(&client);
+    let mint = create_mint(&client, &wallet);
+    let (lat, lng) = fresh_cell(&wallet.pubkey());
+
+    let quote = client
+        .quote_placement(lat, lng, &mint, &wallet.pubkey(), None)
+        .unwrap();
+    assert!(!quote.is_overwrite);
+    assert!(quote.rent > 0);
+
+    let instruction = client
+        .place_logo_instruction(PlaceLogoArgs {
+            lat,
+            lng,
+            token_mint: mint,
+            logo_uri: "https://example.com/logo.png".to_string(),
+            logo_hash: [7; 32],
+            user: wallet.pubkey(),
+            user_token_account: None,
+            previous_token_mint: None,
+        })
+        .unwrap();
+    client.send_and_confirm(&[instruction], &wallet).unwrap();
+
+    let placement = client.fetch_logo_placement(lat, lng).unwrap().unwrap();
+    assert_eq!(placement.token_mint, mint);
+    assert_eq!(placement.placed_by, wallet.pubkey());
+
+    let cooldown = client
+        .fetch_user_cooldown(&wallet.pubkey())
+        .unwrap()
+        .unwrap();
+    assert_eq!(cooldown.placement_count, 1);
+    assert!(cooldown.remaining_cooldown(placement.placed_at) > 0);
+
+    let bbox = BoundingBox {
+        min_lat: lat,
+        min_lng: lng,
+        max_lat: lat,
+        max_lng: lng,
+    };
+    let placements = client.fetch_logo_placements(&bbox).unwrap();
+    assert_eq!(placements.len(), 1);
+
+    // The wallet is now on cooldown, which surfaces as a program error
+    let instruction = client
+        .place_logo_instruction(PlaceLogoArgs {
+            lat: lat + 1,
+            lng,
+            token_mint: mint,
+            logo_uri: "https://example.com/logo.png".to_string(),
+            logo_hash: [7; 32],
+            user: wallet.pubkey(),
+            user_token_account: None,
+            previous_token_mint: None,
+        })
+        .unwrap();
+    match client.send_and_confirm(&[instruction], &wallet) {
+        Err(ClientError::Program(error)) => {
+            assert_eq!(error.name(), SolplaceError::UserOnCooldown.name())
+        }
+        other => panic!("expected UserOnCooldown, got {other:?}"),
+    }
+}
+
+#[test]
+#[ignore = "needs a local validator with the program deployed"]
+fn cli_places_and_shows_a_logo() {
+    let client = client();
+    let wallet = funded_wallet(&client);
+    let mint = create_mint(&client, &wallet).to_string();
+    let (lat, lng) = fresh_cell(&wallet.pubkey());
+    let (lat, lng) = (lat.to_string(), lng.to_string());
+
+    let keypair = std::env::temp_dir().join(format!("solplace-{}.json", wallet.pubkey()));
+    std::fs::write(
+        &keypair,
+        serde_json::to_string(&wallet.to_bytes().to_vec()).unwrap(),
+    )
+    .unwrap();
+
+    let solplace = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_solplace"))
+            .args([
+                "--rpc",
+                client.url(),
+                "--keypair",
+                keypair.to_str().unwrap(),
+            ])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{args:?}: {output:?}");
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let quote = solplace(&["fee-quote", "--lat", &lat, "--lng", &lng, "--mint", &mint]);
+    assert!(quote.contains("overwrite        false"), "{quote}");
+
+    let hash = "11".repeat(32);
+    solplace(&[
+        "place",
+        "--lat",
+        &lat,
+        "--lng",
+        &lng,
+        "--mint",
+        &mint,
+        "--uri",
+        "https://example.com/logo.png",
+        "--hash",
+        &hash,
+    ]);
+
+    let shown = solplace(&["show", &lat, &lng]);
+    assert!(shown.contains(&mint), "{shown}");
+    assert!(shown.contains(&hash), "{shown}");
+
+    let cooldown = solplace(&["cooldown", &wallet.pubkey().to_string()]);
+    assert!(cooldown.contains("placement_count     1"), "{cooldown}");
+
+    let bbox = format!("{lat},{lng},{lat},{lng}");
+    let listed = solplace(&["list", "--bbox", &bbox]);
+    assert_eq!(listed.lines().count(), 1, "{listed}");
+
+    let quote = solplace(&["fee-quote", "--lat", &lat, "--lng", &lng, "--mint", &mint]);
+    assert!(quote.contains("overwrite        true"), "{quote}");
+
+    std::fs::remove_file(keypair).unwrap();
+}
@@ -0,0 +1,49 @@
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::*;
+
+/// Output file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    GeoJson,
+    Csv,
+    Parquet,
+}
+
+impl Format {
+    /// Format named by a file extension: `.geojson` or `.json`, `.csv`, `.parquet`
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension()?.to_str()?.parse().ok()
+    }
+
+    /// Write `records` in this format; `geometry` only applies to GeoJSON
+    pub fn write(
+        self,
+        records: &[PlacementRecord],
+        geometry: Geometry,
+        writer: impl Write + Send,
+    ) -> Result<(), ExportError> {
+        match self {
+            Format::GeoJson => write_geojson(records, geometry, writer),
+            Format::Csv => write_csv(records, writer),
+            Format::Parquet => write_parquet(records, writer),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "geojson" | "json" => Ok(Format::GeoJson),
+            "csv" => Ok(Format::Csv),
+            "parquet" => Ok(Format::Parquet),
+            _ => Err(format!(
+                "invalid format {s:?}: expected geojson, csv or parquet"
+            )),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::io::Write;
+use std::str::FromStr;
+
+use serde_json::json;
+
+use crate::*;
+
+/// How a placement is drawn in GeoJSON
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Geometry {
+    /// `Point` at the placement's exact coordinates
+    #[default]
+    Point,
+    /// `Polygon` of the `GRID_CELL_SIZE` grid cell holding the placement
+    Cell,
+}
+
+impl FromStr for Geometry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "point" => Ok(Geometry::Point),
+            "cell" => Ok(Geometry::Cell),
+            _ => Err(format!("invalid geometry {s:?}: expected point or cell")),
+        }
+    }
+}
+
+/// RFC 7946 FeatureCollection of `records`, with every record field as a property
+/// Features are identified by their cell address
+pub fn feature_collection(records: &[PlacementRecord], geometry: Geometry) -> serde_json::Value {
+    let features: Vec<serde_json::Value> = records
+        .iter()
+        .map(|record| {
+            json!({
+                "type": "Feature",
+                "id": record.cell,
+                "geometry": match geometry {
+                    Geometry::Point => json!({
+                        "type": "Point",
+                        "coordinates": [record.longitude(), record.latitude()],
+                    }),
+                    Geometry::Cell => cell_polygon(record),
+                },
+                "properties": record,
+            })
+        })
+        .collect();
+    json!({ "type": "FeatureCollection", "features": features })
+}
+
+pub fn write_geojson(
+    records: &[PlacementRecord],
+    geometry: Geometry,
+    mut writer: impl Write,
+) -> Result<(), ExportError> {
+    serde_json::to_writer(&mut writer, &feature_collection(records, geometry))?;
+    writer.write_all(b"\n")?;
+    Ok(())
+}
+
+/// Counterclockwise ring of the grid cell, as RFC 7946 wants exterior rings
+fn cell_polygon(record: &PlacementRecord) -> serde_json::Value {
+    let [lat, lng] = record.grid_cell();
+    let degrees = |microdegrees: i32| f64::from(microdegrees) / 1_000_000.0;
+    let (south, west) = (degrees(lat), degrees(lng));
+    let (north, east) = (degrees(lat + GRID_CELL_SIZE), degrees(lng + GRID_CELL_SIZE));
+    json!({
+        "type": "Polygon",
+        "coordinates": [[
+            [west, south],
+            [east, south],
+            [east, north],
+            [west, north],
+            [west, south],
+        ]],
+    })
+}
@@ -0,0 +1,18 @@
+//! Map state exporter for SolPlace
+//!
+//! Flattens `LogoPlacement` accounts into records and writes them as GeoJSON
+//! FeatureCollections (points or grid cell polygons), CSV or Parquet, for GIS tools
+//! and notebooks. The CLI reads placements from an indexer store, a saved
+//! `getProgramAccounts` response or JSON-RPC.
+
+pub mod error;
+pub mod format;
+pub mod geojson;
+pub mod record;
+pub mod tabular;
+
+pub use error::*;
+pub use format::*;
+pub use geojson::*;
+pub use record::*;
+pub use tabular::*;
@@ -0,0 +1,143 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, Parser};
+use program::state::LogoPlacement;
+use solplace_client::{
+    parse_program_accounts, visible_logo_placements, BoundingBox, ClientError, RpcClient,
+};
+use solplace_export::*;
+use solplace_indexer::{IndexerError, Store};
+
+#[derive(Parser)]
+#[command(
+    name = "solplace-export",
+    about = "Export SolPlace map state to GeoJSON, CSV or Parquet"
+)]
+struct Cli {
+    #[command(flatten)]
+    source: Source,
+    /// Only export placements inside `min_lat,min_lng,max_lat,max_lng` microdegrees
+    #[arg(long)]
+    bbox: Option<BoundingBox>,
+    /// `geojson`, `csv` or `parquet`, defaults to the extension of `--out`
+    #[arg(long)]
+    format: Option<Format>,
+    /// GeoJSON geometry: `point` at the placement or `cell` polygon of its grid cell
+    #[arg(long, default_value = "point")]
+    geometry: Geometry,
+    /// File to write, standard output when absent
+    #[arg(long)]
+    out: Option<PathBuf>,
+}
+
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+struct Source {
+    /// SQLite database written by solplace-indexer
+    #[arg(long)]
+    db: Option<PathBuf>,
+    /// `getProgramAccounts` response saved with base64 encoding
+    #[arg(long)]
+    accounts: Option<PathBuf>,
+    /// Fetch the placements from this JSON-RPC endpoint
+    #[arg(long)]
+    rpc: Option<String>,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum CliError {
+    #[error(transparent)]
+    Export(#[from] ExportError),
+
+    #[error(transparent)]
+    Indexer(#[from] IndexerError),
+
+    #[error(transparent)]
+    Client(#[from] ClientError),
+
+    #[error("{path}: {message}")]
+    File { path: PathBuf, message: String },
+
+    #[error("--format is required when writing to standard output or an unknown extension")]
+    UnknownFormat,
+}
+
+fn run(cli: Cli) -> Result<(), CliError> {
+    let format = cli
+        .format
+        .or_else(|| cli.out.as_deref().and_then(Format::from_path))
+        .ok_or(CliError::UnknownFormat)?;
+    let bbox = cli.bbox.unwrap_or(BoundingBox::WORLD);
+    let records = records(&load(&cli.source, &bbox)?);
+
+    match &cli.out {
+        Some(path) => {
+            let file = File::create(path).map_err(|err| CliError::File {
+                path: path.clone(),
+                message: err.to_string(),
+            })?;
+            let mut writer = BufWriter::new(file);
+            format.write(&records, cli.geometry, &mut writer)?;
+            writer.flush().map_err(ExportError::from)?;
+            eprintln!(
+                "exported {} placements to {}",
+                records.len(),
+                path.display()
+            );
+        }
+        None => {
+            let mut writer = BufWriter::new(std::io::stdout());
+            format.write(&records, cli.geometry, &mut writer)?;
+            writer.flush().map_err(ExportError::from)?;
+        }
+    }
+    Ok(())
+}
+
+fn load(source: &Source, bbox: &BoundingBox) -> Result<Vec<LogoPlacement>, CliError> {
+    if let Some(db) = &source.db {
+        let store = Store::open(db)?;
+        return Ok(store
+            .cells_in(bbox)?
+            .into_iter()
+            .map(LogoPlacement::from)
+            .collect());
+    }
+    if let Some(rpc) = &source.rpc {
+        let client = RpcClient::new(rpc.as_str());
+        let hidden = client.fetch_hidden_placements()?;
+        return Ok(client
+            .fetch_logo_placements(bbox)?
+            .into_iter()
+            .filter(|(address, placement)| !hidden.contains(&(*address, placement.placed_at)))
+            .map(|(_, placement)| placement)
+            .collect());
+    }
+
+    let path = source.accounts.clone().expect("clap requires a source");
+    let dump = std::fs::read_to_string(&path)
+        .map_err(|err| err.to_string())
+        .and_then(|json| serde_json::from_str(&json).map_err(|err| err.to_string()))
+        .map_err(|message| CliError::File {
+            path: path.clone(),
+            message,
+        })?;
+    Ok(visible_logo_placements(parse_program_accounts(&dump)?)?
+        .into_iter()
+        .map(|(_, placement)| placement)
+        .filter(|placement| bbox.contains(placement.coordinates[0], placement.coordinates[1]))
+        .collect())
+}
@@ -0,0 +1,113 @@
+use program::state::{get_logo_placement_pda, LogoPlacement};
+use serde::Serialize;
+
+/// Edge of a map cell in microdegrees, the grid the web client snaps placements to
+pub const GRID_CELL_SIZE: i32 = 1_000;
+
+/// A placement flattened for export, pubkeys in base58 and the logo hash in hex
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlacementRecord {
+    /// `LogoPlacement` account address
+    pub cell: String,
+    /// Coordinates in microdegrees
+    pub lat: i32,
+    pub lng: i32,
+    pub token_mint: String,
+    pub logo_uri: String,
+    pub logo_hash: String,
+    pub placed_by: String,
+    /// Unix timestamp
+    pub placed_at: i64,
+    /// `placed_at` as RFC 3339 in UTC
+    pub placed_at_utc: String,
+    pub overwrite_count: u16,
+}
+
+impl PlacementRecord {
+    pub fn latitude(&self) -> f64 {
+        f64::from(self.lat) / 1_000_000.0
+    }
+
+    pub fn longitude(&self) -> f64 {
+        f64::from(self.lng) / 1_000_000.0
+    }
+
+    /// South-west corner of the grid cell holding the placement, in microdegrees
+    pub fn grid_cell(&self) -> [i32; 2] {
+        [
+            self.lat.div_euclid(GRID_CELL_SIZE) * GRID_CELL_SIZE,
+            self.lng.div_euclid(GRID_CELL_SIZE) * GRID_CELL_SIZE,
+        ]
+    }
+}
+
+impl From<&LogoPlacement> for PlacementRecord {
+    fn from(placement: &LogoPlacement) -> Self {
+        let [lat, lng] = placement.coordinates;
+        PlacementRecord {
+            cell: get_logo_placement_pda(lat, lng, &program::ID).0.to_string(),
+            lat,
+            lng,
+            token_mint: placement.token_mint.to_string(),
+            logo_uri: placement.logo_uri.clone(),
+            logo_hash: hex::encode(placement.logo_hash),
+            placed_by: placement.placed_by.to_string(),
+            placed_at: placement.placed_at,
+            placed_at_utc: utc(placement.placed_at),
+            overwrite_count: placement.overwrite_count,
+        }
+    }
+}
+
+/// Records of the placements showing a logo, sorted by coordinates
+pub fn records(placements: &[LogoPlacement]) -> Vec<PlacementRecord> {
+    let mut records: Vec<PlacementRecord> = placements
+        .iter()
+        .filter(|placement| !placement.is_blank())
+        .map(PlacementRecord::from)
+        .collect();
+    records.sort_by_key(|record| (record.lat, record.lng));
+    records
+}
+
+/// Format a unix timestamp as `YYYY-MM-DDTHH:MM:SSZ`
+fn utc(timestamp: i64) -> String {
+    let days = timestamp.div_euclid(86_400);
+    let seconds = timestamp.rem_euclid(86_400);
+
+    // Civil date from days since 1970-01-01, in 400 year eras starting on March 1st
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        seconds / 3_600,
+        seconds % 3_600 / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_timestamps_in_utc() {
+        assert_eq!(utc(0), "1970-01-01T00:00:00Z");
+        assert_eq!(utc(1_700_000_100), "2023-11-14T22:15:00Z");
+        assert_eq!(utc(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(utc(-1), "1969-12-31T23:59:59Z");
+    }
+}
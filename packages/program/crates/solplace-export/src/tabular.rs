@@ -0,0 +1,144 @@
+use std::io::Write;
+use std::sync::Arc;
+
+use parquet::basic::Compression;
+use parquet::data_type::{ByteArray, ByteArrayType, DoubleType, Int32Type, Int64Type};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::parser::parse_message_type;
+
+use crate::*;
+
+/// CSV header; `latitude` and `longitude` are in degrees for GIS tools
+pub const CSV_COLUMNS: [&str; 12] = [
+    "cell",
+    "lat",
+    "lng",
+    "latitude",
+    "longitude",
+    "token_mint",
+    "logo_uri",
+    "logo_hash",
+    "placed_by",
+    "placed_at",
+    "placed_at_utc",
+    "overwrite_count",
+];
+
+/// Parquet columns, `placed_at` being a UTC timestamp
+pub const PARQUET_SCHEMA: &str = "
+message placement {
+    REQUIRED BYTE_ARRAY cell (STRING);
+    REQUIRED INT32 lat;
+    REQUIRED INT32 lng;
+    REQUIRED DOUBLE latitude;
+    REQUIRED DOUBLE longitude;
+    REQUIRED BYTE_ARRAY token_mint (STRING);
+    REQUIRED BYTE_ARRAY logo_uri (STRING);
+    REQUIRED BYTE_ARRAY logo_hash (STRING);
+    REQUIRED BYTE_ARRAY placed_by (STRING);
+    REQUIRED INT64 placed_at (TIMESTAMP(MILLIS, true));
+    REQUIRED INT32 overwrite_count (INTEGER(16, false));
+}
+";
+
+/// RFC 4180 CSV with a header row
+pub fn write_csv(records: &[PlacementRecord], mut writer: impl Write) -> Result<(), ExportError> {
+    writeln!(writer, "{}", CSV_COLUMNS.join(","))?;
+    for record in records {
+        let fields = [
+            record.cell.clone(),
+            record.lat.to_string(),
+            record.lng.to_string(),
+            record.latitude().to_string(),
+            record.longitude().to_string(),
+            record.token_mint.clone(),
+            csv_field(&record.logo_uri),
+            record.logo_hash.clone(),
+            record.placed_by.clone(),
+            record.placed_at.to_string(),
+            record.placed_at_utc.clone(),
+            record.overwrite_count.to_string(),
+        ];
+        writeln!(writer, "{}", fields.join(","))?;
+    }
+    Ok(())
+}
+
+/// Quote a field holding separators, quotes or line breaks
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Values of one Parquet column
+enum Column {
+    Text(Vec<ByteArray>),
+    Int32(Vec<i32>),
+    Int64(Vec<i64>),
+    Double(Vec<f64>),
+}
+
+/// Snappy-compressed Parquet file of `PARQUET_SCHEMA`, in a single row group
+pub fn write_parquet(
+    records: &[PlacementRecord],
+    writer: impl Write + Send,
+) -> Result<(), ExportError> {
+    let schema = Arc::new(parse_message_type(PARQUET_SCHEMA)?);
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut file = SerializedFileWriter::new(writer, schema, Arc::new(properties))?;
+
+    if !records.is_empty() {
+        let text = |field: fn(&PlacementRecord) -> &str| {
+            Column::Text(records.iter().map(|r| ByteArray::from(field(r))).collect())
+        };
+        let columns = [
+            text(|r| &r.cell),
+            Column::Int32(records.iter().map(|r| r.lat).collect()),
+            Column::Int32(records.iter().map(|r| r.lng).collect()),
+            Column::Double(records.iter().map(|r| r.latitude()).collect()),
+            Column::Double(records.iter().map(|r| r.longitude()).collect()),
+            text(|r| &r.token_mint),
+            text(|r| &r.logo_uri),
+            text(|r| &r.logo_hash),
+            text(|r| &r.placed_by),
+            Column::Int64(records.iter().map(|r| r.placed_at * 1_000).collect()),
+            Column::Int32(
+                records
+                    .iter()
+                    .map(|r| i32::from(r.overwrite_count))
+                    .collect(),
+            ),
+        ];
+
+        let mut row_group = file.next_row_group()?;
+        for column in columns {
+            let mut writer = row_group
+                .next_column()?
+                .expect("the schema has a column per value list");
+            match column {
+                Column::Text(values) => writer
+                    .typed::<ByteArrayType>()
+                    .write_batch(&values, None, None)?,
+                Column::Int32(values) => writer
+                    .typed::<Int32Type>()
+                    .write_batch(&values, None, None)?,
+                Column::Int64(values) => writer
+                    .typed::<Int64Type>()
+                    .write_batch(&values, None, None)?,
+                Column::Double(values) => writer
+                    .typed::<DoubleType>()
+                    .write_batch(&values, None, None)?,
+            };
+            writer.close()?;
+        }
+        row_group.close()?;
+    }
+    file.close()?;
+    Ok(())
+}
@@ -0,0 +1,235 @@
+//! Exports a handful of placements in every format and reads them back
+
+use std::path::Path;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::AccountSerialize;
+use base64::Engine;
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use parquet::record::RowAccessor;
+use program::state::{get_logo_placement_pda, LogoPlacement, UserCooldown};
+use solplace_client::{logo_placements, parse_program_accounts};
+use solplace_export::*;
+
+/// Fixture accounts are sha256 of a name
+fn key(name: &str) -> Pubkey {
+    Pubkey::new_from_array(hash(name.as_bytes()).to_bytes())
+}
+
+fn placement(
+    lat: i32,
+    lng: i32,
+    mint: &str,
+    placed_at: i64,
+    overwrite_count: u16,
+) -> LogoPlacement {
+    LogoPlacement {
+        version: LogoPlacement::VERSION,
+        coordinates: [lat, lng],
+        token_mint: key(mint),
+        logo_uri: format!("https://example.com/{mint}.png"),
+        logo_hash: [7; 32],
+        placed_by: key("alice"),
+        placed_at,
+        overwrite_count,
+        bump: get_logo_placement_pda(lat, lng, &program::ID).1,
+    }
+}
+
+fn serialize(account: &impl AccountSerialize, size: usize) -> Vec<u8> {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    data.resize(size, 0);
+    data
+}
+
+fn placements() -> Vec<LogoPlacement> {
+    let mut blanked = placement(35_676_200, 139_650_300, "tokyo", 1_700_000_400, 1);
+    blanked.blank();
+    let mut quoted = placement(-33_868_800, 151_209_300, "vegemite", 1_700_000_300, 0);
+    quoted.logo_uri = "https://example.com/logo.png?size=32,\"big\"".to_string();
+    vec![
+        placement(48_858_400, 2_294_500, "pepe", 1_700_000_200, 0),
+        placement(40_712_800, -74_006_000, "bonk", 1_700_000_100, 3),
+        blanked,
+        quoted,
+    ]
+}
+
+#[test]
+fn flattens_placements_into_records() {
+    let records = records(&placements());
+    // The blanked cell is left out, the rest sorted by coordinates
+    let coordinates: Vec<_> = records.iter().map(|r| (r.lat, r.lng)).collect();
+    assert_eq!(
+        coordinates,
+        [
+            (-33_868_800, 151_209_300),
+            (40_712_800, -74_006_000),
+            (48_858_400, 2_294_500)
+        ]
+    );
+
+    let new_york = &records[1];
+    assert_eq!(
+        new_york.cell,
+        get_logo_placement_pda(40_712_800, -74_006_000, &program::ID)
+            .0
+            .to_string()
+    );
+    assert_eq!(new_york.token_mint, key("bonk").to_string());
+    assert_eq!(new_york.placed_by, key("alice").to_string());
+    assert_eq!(new_york.logo_hash, hex::encode([7; 32]));
+    assert_eq!(new_york.placed_at_utc, "2023-11-14T22:15:00Z");
+    assert_eq!(new_york.overwrite_count, 3);
+    assert_eq!(
+        (new_york.latitude(), new_york.longitude()),
+        (40.7128, -74.006)
+    );
+    // Grid cells are floored, west and south of negative coordinates
+    assert_eq!(new_york.grid_cell(), [40_712_000, -74_006_000]);
+    assert_eq!(records[0].grid_cell(), [-33_869_000, 151_209_000]);
+}
+
+#[test]
+fn writes_geojson_points_and_cells() {
+    let records = records(&placements());
+
+    let points = feature_collection(&records, Geometry::Point);
+    assert_eq!(points["type"], "FeatureCollection");
+    let feature = &points["features"][1];
+    assert_eq!(feature["id"], records[1].cell);
+    assert_eq!(
+        feature["geometry"],
+        serde_json::json!({ "type": "Point", "coordinates": [-74.006, 40.7128] })
+    );
+    assert_eq!(feature["properties"]["token_mint"], key("bonk").to_string());
+    assert_eq!(feature["properties"]["overwrite_count"], 3);
+    assert_eq!(feature["properties"]["placed_at"], 1_700_000_100);
+
+    let mut written = Vec::new();
+    write_geojson(&records, Geometry::Cell, &mut written).unwrap();
+    let cells: serde_json::Value = serde_json::from_slice(&written).unwrap();
+    assert_eq!(cells["features"].as_array().unwrap().len(), 3);
+    assert_eq!(
+        cells["features"][0]["geometry"],
+        serde_json::json!({
+            "type": "Polygon",
+            "coordinates": [[
+                [151.209, -33.869],
+                [151.21, -33.869],
+                [151.21, -33.868],
+                [151.209, -33.868],
+                [151.209, -33.869]
+            ]]
+        })
+    );
+    assert_eq!("cell".parse(), Ok(Geometry::Cell));
+    assert!("hexagon".parse::<Geometry>().is_err());
+}
+
+#[test]
+fn writes_csv() {
+    let records = records(&placements());
+    let mut written = Vec::new();
+    write_csv(&records, &mut written).unwrap();
+    let csv = String::from_utf8(written).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], CSV_COLUMNS.join(","));
+    // Fields with separators or quotes are quoted
+    assert!(lines[1].contains(",\"https://example.com/logo.png?size=32,\"\"big\"\"\","));
+    assert_eq!(
+        lines[2],
+        format!(
+            "{},40712800,-74006000,40.7128,-74.006,{},https://example.com/bonk.png,{},{},\
+             1700000100,2023-11-14T22:15:00Z,3",
+            records[1].cell,
+            key("bonk"),
+            hex::encode([7; 32]),
+            key("alice")
+        )
+    );
+    assert_eq!(Format::from_path(Path::new("map.csv")), Some(Format::Csv));
+    assert_eq!(Format::from_path(Path::new("map.txt")), None);
+}
+
+#[test]
+fn writes_parquet() {
+    let path = std::env::temp_dir().join(format!("solplace-export-{}.parquet", std::process::id()));
+    let records = records(&placements());
+    Format::from_path(&path)
+        .unwrap()
+        .write(
+            &records,
+            Geometry::default(),
+            std::fs::File::create(&path).unwrap(),
+        )
+        .unwrap();
+
+    let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+    assert_eq!(reader.metadata().file_metadata().num_rows(), 3);
+    let rows: Vec<_> = reader
+        .get_row_iter(None)
+        .unwrap()
+        .map(|row| row.unwrap())
+        .collect();
+
+    let new_york = &rows[1];
+    assert_eq!(new_york.get_string(0).unwrap(), &records[1].cell);
+    assert_eq!(new_york.get_int(1).unwrap(), 40_712_800);
+    assert_eq!(new_york.get_double(4).unwrap(), -74.006);
+    assert_eq!(new_york.get_string(5).unwrap(), &key("bonk").to_string());
+    assert_eq!(new_york.get_timestamp_millis(9).unwrap(), 1_700_000_100_000);
+    assert_eq!(new_york.get_ushort(10).unwrap(), 3);
+    assert_eq!(
+        rows[0].get_string(6).unwrap(),
+        "https://example.com/logo.png?size=32,\"big\""
+    );
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn reads_placements_from_a_program_accounts_dump() {
+    let account = |data: Vec<u8>| {
+        serde_json::json!({
+            "data": [base64::engine::general_purpose::STANDARD.encode(data), "base64"],
+            "executable": false,
+            "lamports": 3_173_760,
+            "owner": program::ID.to_string(),
+            "rentEpoch": 18_446_744_073_709_551_615u64,
+        })
+    };
+    let new_york = placement(40_712_800, -74_006_000, "bonk", 1_700_000_100, 3);
+    let cooldown = UserCooldown {
+        version: UserCooldown::VERSION,
+        user: key("alice"),
+        last_placement: 1_700_000_100,
+        placement_count: 1,
+        bump: 255,
+    };
+    let dump = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "result": [
+            {
+                "pubkey": key("cooldown").to_string(),
+                "account": account(serialize(&cooldown, UserCooldown::SIZE)),
+            },
+            {
+                "pubkey": key("cell").to_string(),
+                "account": account(serialize(&new_york, LogoPlacement::SIZE)),
+            },
+        ],
+    });
+
+    // Accounts of other types are skipped
+    let placements = logo_placements(parse_program_accounts(&dump).unwrap()).unwrap();
+    assert_eq!(placements.len(), 1);
+    assert_eq!(placements[0].0, key("cell"));
+    let records = records(&[placements[0].1.clone()]);
+    assert_eq!(records[0].overwrite_count, 3);
+    assert_eq!(records[0].token_mint, key("bonk").to_string());
+}
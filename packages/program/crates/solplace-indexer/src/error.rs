@@ -0,0 +1,16 @@
+use solplace_client::ClientError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum IndexerError {
+    #[error("database error: {0}")]
+    Database(#[from] rusqlite::Error),
+
+    #[error(transparent)]
+    Client(#[from] ClientError),
+
+    #[error("invalid transaction: {0}")]
+    InvalidTransaction(String),
+
+    #[error("invalid event in {signature}: {message}")]
+    InvalidEvent { signature: String, message: String },
+}
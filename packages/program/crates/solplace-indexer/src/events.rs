@@ -0,0 +1,202 @@
+use anchor_lang::prelude::{borsh, AnchorDeserialize, Pubkey};
+use anchor_lang::Discriminator;
+use base64::Engine;
+use program::fees::calculate_placement_fee;
+use program::instructions::{
+    LogoModeratedEvent, LogoPlacedEvent, PlacementHiddenEvent, PlacementUnhiddenEvent,
+};
+use solplace_client::{decode_event, get_logo_placement_pda, ClientError};
+
+const PROGRAM_DATA: &str = "Program data: ";
+const LOG_TRUNCATED: &str = "Log truncated";
+
+/// `LogoPlacedEvent` as the original program logged it with `emit!`, before events moved
+/// to self-CPI. Only found in the logs of historical transactions
+#[derive(AnchorDeserialize)]
+pub struct LegacyLogoPlacedEvent {
+    pub user: Pubkey,
+    pub lat: i32,
+    pub lng: i32,
+    pub token_mint: Pubkey,
+    pub logo_uri: String,
+    pub fee_paid: u64,
+    pub is_overwrite: bool,
+    pub timestamp: i64,
+}
+
+impl Discriminator for LegacyLogoPlacedEvent {
+    const DISCRIMINATOR: &'static [u8] = LogoPlacedEvent::DISCRIMINATOR;
+}
+
+/// The legacy event predates logo hashes, holder discounts and the previous occupant
+impl From<LegacyLogoPlacedEvent> for LogoPlacedEvent {
+    fn from(legacy: LegacyLogoPlacedEvent) -> Self {
+        let base_fee = calculate_placement_fee(legacy.is_overwrite, 0);
+        LogoPlacedEvent {
+            user: legacy.user,
+            cell: get_logo_placement_pda(legacy.lat, legacy.lng, &program::ID).0,
+            lat: legacy.lat,
+            lng: legacy.lng,
+            token_mint: legacy.token_mint,
+            logo_uri: legacy.logo_uri,
+            logo_hash: [0; 32],
+            previous_token_mint: None,
+            previous_placed_by: None,
+            previous_placed_at: None,
+            overwrite_count: 0,
+            base_fee,
+            holder_discount: base_fee.saturating_sub(legacy.fee_paid),
+            holder_discount_bps: 0,
+            fee_paid: legacy.fee_paid,
+            rent_paid: 0,
+            is_overwrite: legacy.is_overwrite,
+            timestamp: legacy.timestamp,
+        }
+    }
+}
+
+/// How the current placement of a cell is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellStatus {
+    /// Reported past the hide threshold, left out of the map
+    Hidden,
+    /// A rejected report took it back under the threshold
+    Unhidden,
+    /// A moderator blanked or closed it, the cell is empty until the next placement
+    TakenDown,
+}
+
+impl CellStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CellStatus::Hidden => "hidden",
+            CellStatus::Unhidden => "unhidden",
+            CellStatus::TakenDown => "taken_down",
+        }
+    }
+}
+
+/// Status change of the placement made at `placed_at` on `cell`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellStatusEvent {
+    pub cell: Pubkey,
+    /// `(lat, lng)` of `cell`, unhide events only carry its address
+    pub coordinates: Option<(i32, i32)>,
+    pub placed_at: i64,
+    pub status: CellStatus,
+    pub timestamp: i64,
+}
+
+impl From<PlacementHiddenEvent> for CellStatusEvent {
+    fn from(event: PlacementHiddenEvent) -> Self {
+        CellStatusEvent {
+            cell: get_logo_placement_pda(event.lat, event.lng, &program::ID).0,
+            coordinates: Some((event.lat, event.lng)),
+            placed_at: event.placed_at,
+            status: CellStatus::Hidden,
+            timestamp: event.timestamp,
+        }
+    }
+}
+
+impl From<PlacementUnhiddenEvent> for CellStatusEvent {
+    fn from(event: PlacementUnhiddenEvent) -> Self {
+        CellStatusEvent {
+            cell: event.logo_placement,
+            coordinates: None,
+            placed_at: event.placed_at,
+            status: CellStatus::Unhidden,
+            timestamp: event.timestamp,
+        }
+    }
+}
+
+impl From<LogoModeratedEvent> for CellStatusEvent {
+    fn from(event: LogoModeratedEvent) -> Self {
+        CellStatusEvent {
+            cell: get_logo_placement_pda(event.lat, event.lng, &program::ID).0,
+            coordinates: Some((event.lat, event.lng)),
+            placed_at: event.placed_at,
+            status: CellStatus::TakenDown,
+            timestamp: event.timestamp,
+        }
+    }
+}
+
+/// An event the SolPlace program emitted through self-CPI that the indexer tracks
+pub enum ProgramEvent {
+    Placed(Box<LogoPlacedEvent>),
+    Status(CellStatusEvent),
+}
+
+/// Decode one self-CPI event payload, `None` for events the indexer does not track
+pub fn decode_program_event(data: &[u8]) -> Result<Option<ProgramEvent>, ClientError> {
+    if let Some(event) = decode_event::<LogoPlacedEvent>(data)? {
+        return Ok(Some(ProgramEvent::Placed(Box::new(event))));
+    }
+    if let Some(event) = decode_event::<PlacementHiddenEvent>(data)? {
+        return Ok(Some(ProgramEvent::Status(event.into())));
+    }
+    if let Some(event) = decode_event::<PlacementUnhiddenEvent>(data)? {
+        return Ok(Some(ProgramEvent::Status(event.into())));
+    }
+    if let Some(event) = decode_event::<LogoModeratedEvent>(data)? {
+        return Ok(Some(ProgramEvent::Status(event.into())));
+    }
+    Ok(None)
+}
+
+/// Placements found in the logs of one transaction
+pub struct LogEvents {
+    pub events: Vec<LogoPlacedEvent>,
+    /// The node cut the logs short, later events may be missing
+    pub truncated: bool,
+}
+
+/// Collect the `LogoPlacedEvent`s the SolPlace program logged before self-CPI events
+///
+/// Logs are attributed by following the `invoke`/`success`/`failed` lines, so
+/// `Program data:` written by any other program, even one that copies the
+/// event layout, is ignored.
+pub fn parse_log_events<'a>(logs: impl IntoIterator<Item = &'a str>) -> Result<LogEvents, String> {
+    let program_id = program::ID.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+    let mut truncated = false;
+
+    for line in logs {
+        if let Some(data) = line.strip_prefix(PROGRAM_DATA) {
+            if stack.last() == Some(&program_id.as_str()) {
+                events.extend(decode_program_data(data)?);
+            }
+        } else if let Some(rest) = line.strip_prefix("Program ") {
+            let mut words = rest.split(' ');
+            let (Some(program), Some(action)) = (words.next(), words.next()) else {
+                continue;
+            };
+            match action {
+                "invoke" => stack.push(program),
+                "success" | "failed:" => {
+                    stack.pop();
+                }
+                _ => {}
+            }
+        } else if line.starts_with(LOG_TRUNCATED) {
+            truncated = true;
+        }
+    }
+
+    Ok(LogEvents { events, truncated })
+}
+
+/// Decode one `Program data:` payload, `None` for anything but a legacy `LogoPlacedEvent`
+pub fn decode_program_data(data: &str) -> Result<Option<LogoPlacedEvent>, String> {
+    let bytes = data
+        .split(' ')
+        .map(|chunk| base64::engine::general_purpose::STANDARD.decode(chunk))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("invalid program data: {err}"))?
+        .concat();
+    let event = decode_event::<LegacyLogoPlacedEvent>(&bytes).map_err(|err| err.to_string())?;
+    Ok(event.map(LogoPlacedEvent::from))
+}
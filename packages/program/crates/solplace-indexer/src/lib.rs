@@ -0,0 +1,18 @@
+//! Event indexer for SolPlace
+//!
+//! Rebuilds the map from `LogoPlacedEvent`s instead of scanning program accounts.
+//! Events are read from self-CPI inner instructions (or the logs of older
+//! transactions), journaled in SQLite and folded into cell, user and mint tables. Everything above a slot can be rolled back, so
+//! transactions indexed at `confirmed` that later drop out of the chain are undone.
+
+pub mod error;
+pub mod events;
+pub mod store;
+pub mod sync;
+pub mod transaction;
+
+pub use error::*;
+pub use events::*;
+pub use store::*;
+pub use sync::*;
+pub use transaction::*;
@@ -0,0 +1,123 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{Parser, Subcommand};
+use solplace_client::RpcClient;
+use solplace_indexer::*;
+
+const DEFAULT_RPC_URL: &str = "https://api.devnet.solana.com";
+
+#[derive(Parser)]
+#[command(
+    name = "solplace-indexer",
+    about = "Index SolPlace placements into SQLite"
+)]
+struct Cli {
+    /// SQLite database file
+    #[arg(long, global = true, default_value = "solplace.sqlite")]
+    db: PathBuf,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Follow the program and index new transactions
+    Sync {
+        /// Solana JSON-RPC endpoint
+        #[arg(long, default_value = DEFAULT_RPC_URL)]
+        rpc: String,
+        /// Seconds between polls
+        #[arg(long, default_value_t = 5)]
+        interval: u64,
+        /// Index what is available and exit
+        #[arg(long)]
+        once: bool,
+    },
+    /// Apply recorded `getTransaction` results
+    Ingest {
+        /// JSON files holding one result or an array of results
+        files: Vec<PathBuf>,
+    },
+    /// Undo every transaction above a slot
+    Rollback {
+        #[arg(long)]
+        slot: u64,
+    },
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum CliError {
+    #[error(transparent)]
+    Indexer(#[from] IndexerError),
+
+    #[error("{path}: {message}")]
+    Fixture { path: PathBuf, message: String },
+}
+
+fn run(cli: Cli) -> Result<(), CliError> {
+    let mut store = Store::open(&cli.db)?;
+    match cli.command {
+        Command::Sync {
+            rpc,
+            interval,
+            once,
+        } => {
+            let client = RpcClient::new(rpc);
+            loop {
+                if let Some(rollback) = check_reorgs(&mut store, &client)? {
+                    eprintln!("rolled back to slot {}", rollback.slot);
+                }
+                let applied = sync(&mut store, &client)?;
+                if applied > 0 {
+                    eprintln!(
+                        "indexed {applied} transactions up to slot {}",
+                        store.last_slot()?.unwrap_or_default()
+                    );
+                }
+                if once {
+                    break;
+                }
+                std::thread::sleep(Duration::from_secs(interval));
+            }
+        }
+        Command::Ingest { files } => {
+            for path in files {
+                let fixture = |message: String| CliError::Fixture {
+                    path: path.clone(),
+                    message,
+                };
+                let contents = std::fs::read(&path).map_err(|err| fixture(err.to_string()))?;
+                let value: serde_json::Value =
+                    serde_json::from_slice(&contents).map_err(|err| fixture(err.to_string()))?;
+                let results = match value {
+                    serde_json::Value::Array(results) => results,
+                    result => vec![result],
+                };
+                for result in &results {
+                    let transaction = IndexedTransaction::from_rpc(result)?;
+                    if transaction.logs_truncated {
+                        eprintln!("warning: logs of {} are truncated", transaction.signature);
+                    }
+                    store.apply(&transaction)?;
+                }
+            }
+        }
+        Command::Rollback { slot } => {
+            let rollback = store.rollback(slot)?;
+            eprintln!("removed {} events", rollback.removed);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,897 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use anchor_lang::prelude::Pubkey;
+use program::state::LogoPlacement;
+use rusqlite::functions::FunctionFlags;
+use rusqlite::{params, Connection, OptionalExtension, Row, Transaction};
+use solplace_client::{get_logo_placement_pda, BoundingBox, Tile};
+
+use crate::*;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS transactions (
+    signature TEXT PRIMARY KEY,
+    slot INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS transactions_slot ON transactions (slot);
+
+CREATE TABLE IF NOT EXISTS events (
+    seq INTEGER PRIMARY KEY AUTOINCREMENT,
+    slot INTEGER NOT NULL,
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    user TEXT NOT NULL,
+    cell TEXT NOT NULL,
+    lat INTEGER NOT NULL,
+    lng INTEGER NOT NULL,
+    token_mint TEXT NOT NULL,
+    logo_uri TEXT NOT NULL,
+    logo_hash TEXT NOT NULL,
+    previous_token_mint TEXT,
+    previous_placed_by TEXT,
+    previous_placed_at INTEGER,
+    overwrite_count INTEGER NOT NULL,
+    base_fee INTEGER NOT NULL,
+    holder_discount INTEGER NOT NULL,
+    holder_discount_bps INTEGER NOT NULL,
+    fee_paid INTEGER NOT NULL,
+    rent_paid INTEGER NOT NULL,
+    is_overwrite INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    UNIQUE (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS events_slot ON events (slot);
+CREATE INDEX IF NOT EXISTS events_cell ON events (lat, lng);
+CREATE INDEX IF NOT EXISTS events_address ON events (cell);
+CREATE INDEX IF NOT EXISTS events_user ON events (user);
+CREATE INDEX IF NOT EXISTS events_mint ON events (token_mint);
+
+CREATE TABLE IF NOT EXISTS statuses (
+    seq INTEGER PRIMARY KEY AUTOINCREMENT,
+    slot INTEGER NOT NULL,
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    cell TEXT NOT NULL,
+    placed_at INTEGER NOT NULL,
+    status TEXT NOT NULL,
+    timestamp INTEGER NOT NULL,
+    lat INTEGER,
+    lng INTEGER,
+    UNIQUE (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS statuses_slot ON statuses (slot);
+CREATE INDEX IF NOT EXISTS statuses_cell ON statuses (cell, placed_at);
+
+CREATE TABLE IF NOT EXISTS cells (
+    lat INTEGER NOT NULL,
+    lng INTEGER NOT NULL,
+    token_mint TEXT NOT NULL,
+    logo_uri TEXT NOT NULL,
+    logo_hash TEXT NOT NULL,
+    placed_by TEXT NOT NULL,
+    placed_at INTEGER NOT NULL,
+    placements INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    signature TEXT NOT NULL,
+    overwrite_count INTEGER NOT NULL,
+    hidden INTEGER NOT NULL,
+    PRIMARY KEY (lat, lng)
+);
+CREATE INDEX IF NOT EXISTS cells_mint ON cells (token_mint);
+
+CREATE TABLE IF NOT EXISTS users (
+    user TEXT PRIMARY KEY,
+    placements INTEGER NOT NULL,
+    fees_paid INTEGER NOT NULL,
+    last_placement INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS mints (
+    token_mint TEXT PRIMARY KEY,
+    placements INTEGER NOT NULL,
+    cells INTEGER NOT NULL
+);
+";
+
+/// Current occupant of a cell
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell {
+    pub lat: i32,
+    pub lng: i32,
+    pub token_mint: Pubkey,
+    pub logo_uri: String,
+    pub logo_hash: [u8; 32],
+    pub placed_by: Pubkey,
+    pub placed_at: i64,
+    /// Indexed placements on this cell, the first one included
+    pub placements: u32,
+    /// Slot and signature of the placement shown
+    pub slot: u64,
+    pub signature: String,
+    /// The cell's on-chain overwrite count, or the overwrites indexed when only
+    /// legacy events, which don't carry it, were seen
+    pub overwrite_count: u16,
+    /// Reported past the hide threshold, map queries leave it out
+    pub hidden: bool,
+}
+
+impl From<Cell> for LogoPlacement {
+    fn from(cell: Cell) -> Self {
+        LogoPlacement {
+            version: LogoPlacement::VERSION,
+            coordinates: [cell.lat, cell.lng],
+            token_mint: cell.token_mint,
+            logo_uri: cell.logo_uri,
+            logo_hash: cell.logo_hash,
+            placed_by: cell.placed_by,
+            placed_at: cell.placed_at,
+            overwrite_count: cell.overwrite_count,
+            bump: get_logo_placement_pda(cell.lat, cell.lng, &program::ID).1,
+        }
+    }
+}
+
+/// How far the journal has been read: the last `events` and `statuses` rows covered
+///
+/// Every entry moves one of the two forward and rows are never renumbered, so positions are
+/// unique and grow in journal order. Written as `events.statuses`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JournalPosition {
+    pub events: u64,
+    pub statuses: u64,
+}
+
+impl JournalPosition {
+    /// Whether an entry at `position` was already read by the time the journal got here
+    pub fn covers(&self, position: &JournalPosition) -> bool {
+        position.events <= self.events && position.statuses <= self.statuses
+    }
+}
+
+impl fmt::Display for JournalPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.events, self.statuses)
+    }
+}
+
+impl FromStr for JournalPosition {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid journal position {value}");
+        let (events, statuses) = value.split_once('.').ok_or_else(invalid)?;
+        Ok(JournalPosition {
+            events: events.parse().map_err(|_| invalid())?,
+            statuses: statuses.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+/// A placement or status change read back from the journal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalEntry {
+    /// Position right after this entry
+    pub position: JournalPosition,
+    pub slot: u64,
+    pub signature: String,
+    pub lat: i32,
+    pub lng: i32,
+    pub change: JournalChange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JournalChange {
+    Placed {
+        token_mint: Pubkey,
+        logo_uri: String,
+        logo_hash: [u8; 32],
+        placed_by: Pubkey,
+        placed_at: i64,
+        previous_token_mint: Option<Pubkey>,
+    },
+    Status {
+        /// Placement the status applies to
+        placed_at: i64,
+        status: CellStatus,
+    },
+}
+
+/// Visible cells of one tile rolled up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TileCells {
+    pub tile: Tile,
+    pub cells: u32,
+    /// Placements on those cells, overwritten ones included
+    pub placements: u32,
+    /// Mint showing on the most cells, ties broken by the most recent placement
+    pub top_mint: Pubkey,
+    pub top_mint_cells: u32,
+    pub last_placed_at: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserStats {
+    pub user: Pubkey,
+    pub placements: u32,
+    pub fees_paid: u64,
+    pub last_placement: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MintStats {
+    pub token_mint: Pubkey,
+    pub placements: u32,
+    /// Cells currently showing the mint
+    pub cells: u32,
+}
+
+/// What `Store::rollback` undid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rollback {
+    /// Slot the store was rolled back to, every transaction above it was undone
+    pub slot: u64,
+    /// Events and statuses removed
+    pub removed: usize,
+    /// Cells whose placements or statuses were removed, sorted by coordinates
+    pub cells: Vec<(i32, i32)>,
+}
+
+/// Keys whose derived rows must be recomputed from the event journal
+#[derive(Default)]
+struct Touched {
+    cells: BTreeSet<(i32, i32)>,
+    users: BTreeSet<String>,
+    mints: BTreeSet<String>,
+}
+
+/// SQLite store of indexed events and the map state derived from them
+///
+/// `events` and `statuses` are the journal; `cells`, `users` and `mints` are
+/// recomputed from it for every key an applied or rolled back event touches.
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, IndexerError> {
+        Self::init(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self, IndexerError> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self, IndexerError> {
+        conn.execute_batch(SCHEMA)?;
+        // `tile_x(lat, lng, z)` and `tile_y(lat, lng, z)` let queries group cells by map tile
+        let flags = FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC;
+        conn.create_scalar_function("tile_x", 3, flags, |ctx| {
+            Ok(Tile::containing(ctx.get(0)?, ctx.get(1)?, ctx.get(2)?).x)
+        })?;
+        conn.create_scalar_function("tile_y", 3, flags, |ctx| {
+            Ok(Tile::containing(ctx.get(0)?, ctx.get(1)?, ctx.get(2)?).y)
+        })?;
+        Ok(Store { conn })
+    }
+
+    /// Record a transaction and fold its events and statuses into the map
+    /// Returns the number of new entries, re-applying a transaction is a no-op
+    pub fn apply(&mut self, transaction: &IndexedTransaction) -> Result<usize, IndexerError> {
+        let tx = self.conn.transaction()?;
+        let inserted = tx.execute(
+            "INSERT OR IGNORE INTO transactions (signature, slot) VALUES (?1, ?2)",
+            params![transaction.signature, transaction.slot as i64],
+        )?;
+        if inserted == 0 {
+            return Ok(0);
+        }
+
+        let mut touched = Touched::default();
+        for (index, event) in transaction.events.iter().enumerate() {
+            let user = event.user.to_string();
+            let token_mint = event.token_mint.to_string();
+            tx.execute(
+                "INSERT INTO events (slot, signature, event_index, user, cell, lat, lng,
+                     token_mint, logo_uri, logo_hash, previous_token_mint, previous_placed_by,
+                     previous_placed_at, overwrite_count, base_fee, holder_discount,
+                     holder_discount_bps, fee_paid, rent_paid, is_overwrite, timestamp)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16,
+                     ?17, ?18, ?19, ?20, ?21)",
+                params![
+                    transaction.slot as i64,
+                    transaction.signature,
+                    index as i64,
+                    user,
+                    event.cell.to_string(),
+                    event.lat,
+                    event.lng,
+                    token_mint,
+                    event.logo_uri,
+                    hex::encode(event.logo_hash),
+                    event.previous_token_mint.map(|mint| mint.to_string()),
+                    event.previous_placed_by.map(|user| user.to_string()),
+                    event.previous_placed_at,
+                    event.overwrite_count,
+                    event.base_fee as i64,
+                    event.holder_discount as i64,
+                    event.holder_discount_bps,
+                    event.fee_paid as i64,
+                    event.rent_paid as i64,
+                    event.is_overwrite,
+                    event.timestamp,
+                ],
+            )?;
+            touched.cells.insert((event.lat, event.lng));
+            touched.users.insert(user);
+            touched.mints.insert(token_mint);
+        }
+
+        for (index, status) in transaction.statuses.iter().enumerate() {
+            let cell = status.cell.to_string();
+            let coordinates = match status.coordinates {
+                Some(coordinates) => Some(coordinates),
+                None => cell_coordinates(&tx, &cell)?,
+            };
+            tx.execute(
+                "INSERT INTO statuses (slot, signature, event_index, cell, placed_at, status,
+                     timestamp, lat, lng)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                params![
+                    transaction.slot as i64,
+                    transaction.signature,
+                    index as i64,
+                    cell,
+                    status.placed_at,
+                    status.status.as_str(),
+                    status.timestamp,
+                    coordinates.map(|(lat, _)| lat),
+                    coordinates.map(|(_, lng)| lng),
+                ],
+            )?;
+            touched.cells.extend(coordinates);
+        }
+
+        refresh(&tx, touched)?;
+        tx.commit()?;
+        Ok(transaction.events.len() + transaction.statuses.len())
+    }
+
+    /// Seed the map from `LogoPlacement` accounts read at `slot`
+    ///
+    /// Bootstraps a store without replaying history: every non-blank placement is
+    /// journaled as a fee-less event under a `snapshot:<slot>:<cell>` signature, so later
+    /// events and rollbacks fold over it like any other. Returns the number imported.
+    pub fn import_placements(
+        &mut self,
+        slot: u64,
+        placements: &[LogoPlacement],
+    ) -> Result<usize, IndexerError> {
+        let tx = self.conn.transaction()?;
+        let mut touched = Touched::default();
+        for placement in placements.iter().filter(|placement| !placement.is_blank()) {
+            let [lat, lng] = placement.coordinates;
+            let cell = get_logo_placement_pda(lat, lng, &program::ID).0;
+            let user = placement.placed_by.to_string();
+            let token_mint = placement.token_mint.to_string();
+            let inserted = tx.execute(
+                "INSERT OR IGNORE INTO events (slot, signature, event_index, user, cell, lat, lng,
+                     token_mint, logo_uri, logo_hash, overwrite_count, base_fee, holder_discount,
+                     holder_discount_bps, fee_paid, rent_paid, is_overwrite, timestamp)
+                 VALUES (?1, ?2, 0, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, 0, 0, 0, 0, 0, ?11, ?12)",
+                params![
+                    slot as i64,
+                    format!("snapshot:{slot}:{cell}"),
+                    user,
+                    cell.to_string(),
+                    lat,
+                    lng,
+                    token_mint,
+                    placement.logo_uri,
+                    hex::encode(placement.logo_hash),
+                    placement.overwrite_count,
+                    placement.overwrite_count > 0,
+                    placement.placed_at,
+                ],
+            )?;
+            if inserted > 0 {
+                touched.cells.insert((lat, lng));
+                touched.users.insert(user);
+                touched.mints.insert(token_mint);
+            }
+        }
+
+        let imported = touched.cells.len();
+        refresh(&tx, touched)?;
+        tx.commit()?;
+        Ok(imported)
+    }
+
+    /// Undo every transaction above `slot`
+    /// Returns how many events and statuses were removed and the cells they were on
+    pub fn rollback(&mut self, slot: u64) -> Result<Rollback, IndexerError> {
+        let tx = self.conn.transaction()?;
+        let mut touched = Touched::default();
+        {
+            let mut statement =
+                tx.prepare("SELECT lat, lng, user, token_mint FROM events WHERE slot > ?1")?;
+            let mut rows = statement.query([slot as i64])?;
+            while let Some(row) = rows.next()? {
+                touched.cells.insert((row.get(0)?, row.get(1)?));
+                touched.users.insert(row.get(2)?);
+                touched.mints.insert(row.get(3)?);
+            }
+
+            let mut statement = tx.prepare("SELECT cell FROM statuses WHERE slot > ?1")?;
+            let mut rows = statement.query([slot as i64])?;
+            while let Some(row) = rows.next()? {
+                touched.cells.extend(cell_coordinates(&tx, &row.get::<_, String>(0)?)?);
+            }
+        }
+
+        let removed = tx.execute("DELETE FROM events WHERE slot > ?1", [slot as i64])?
+            + tx.execute("DELETE FROM statuses WHERE slot > ?1", [slot as i64])?;
+        tx.execute("DELETE FROM transactions WHERE slot > ?1", [slot as i64])?;
+        let cells = touched.cells.iter().copied().collect();
+        refresh(&tx, touched)?;
+        tx.commit()?;
+        Ok(Rollback {
+            slot,
+            removed,
+            cells,
+        })
+    }
+
+    /// Highest indexed slot
+    pub fn last_slot(&self) -> Result<Option<u64>, IndexerError> {
+        let slot: Option<i64> =
+            self.conn
+                .query_row("SELECT MAX(slot) FROM transactions", [], |row| row.get(0))?;
+        Ok(slot.map(|slot| slot as u64))
+    }
+
+    /// Signature of the newest indexed transaction, where syncing resumes
+    pub fn last_signature(&self) -> Result<Option<String>, IndexerError> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT signature FROM transactions ORDER BY slot DESC, rowid DESC LIMIT 1",
+                [],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Indexed transactions above `slot`, oldest first
+    pub fn transactions_after(&self, slot: u64) -> Result<Vec<(u64, String)>, IndexerError> {
+        let mut statement = self.conn.prepare(
+            "SELECT slot, signature FROM transactions WHERE slot > ?1 ORDER BY slot, rowid",
+        )?;
+        let rows = statement.query_map([slot as i64], |row| {
+            Ok((row.get::<_, i64>(0)? as u64, row.get(1)?))
+        })?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    pub fn cell(&self, lat: i32, lng: i32) -> Result<Option<Cell>, IndexerError> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT * FROM cells WHERE lat = ?1 AND lng = ?2",
+                [lat, lng],
+                read_cell,
+            )
+            .optional()?)
+    }
+
+    /// Visible cells inside `bbox`, sorted by coordinates
+    pub fn cells_in(&self, bbox: &BoundingBox) -> Result<Vec<Cell>, IndexerError> {
+        let mut statement = self.conn.prepare(
+            "SELECT * FROM cells WHERE lat BETWEEN ?1 AND ?2
+                 AND (CASE WHEN ?3 <= ?4 THEN lng BETWEEN ?3 AND ?4 ELSE lng >= ?3 OR lng <= ?4 END)
+                 AND NOT hidden
+             ORDER BY lat, lng",
+        )?;
+        let rows = statement.query_map(
+            [bbox.min_lat, bbox.max_lat, bbox.min_lng, bbox.max_lng],
+            read_cell,
+        )?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    /// Position right after the last journaled entry
+    pub fn journal_position(&self) -> Result<JournalPosition, IndexerError> {
+        Ok(self.conn.query_row(
+            "SELECT (SELECT COALESCE(MAX(seq), 0) FROM events),
+                 (SELECT COALESCE(MAX(seq), 0) FROM statuses)",
+            [],
+            |row| {
+                Ok(JournalPosition {
+                    events: row.get::<_, i64>(0)? as u64,
+                    statuses: row.get::<_, i64>(1)? as u64,
+                })
+            },
+        )?)
+    }
+
+    /// Journal entries past `after`, only those of slots above `above_slot` when set,
+    /// in the order they were journaled
+    pub fn journal_since(
+        &self,
+        after: JournalPosition,
+        above_slot: Option<u64>,
+    ) -> Result<Vec<JournalEntry>, IndexerError> {
+        let above_slot = above_slot.map_or(-1, |slot| slot as i64);
+        // Each table is read in journal order and the two are interleaved by slot. Within a
+        // slot the transaction's row orders them, placements ahead of the statuses of the same
+        // transaction, and snapshot imports, which have no transaction, come first.
+        let mut statement = self.conn.prepare(
+            "SELECT events.seq, COALESCE(transactions.rowid, 0), events.slot, signature, lat,
+                 lng, token_mint, logo_uri, logo_hash, user, timestamp, previous_token_mint
+             FROM events LEFT JOIN transactions USING (signature)
+             WHERE events.seq > ?1 AND events.slot > ?2
+             ORDER BY events.seq",
+        )?;
+        let placed = statement
+            .query_map(params![after.events as i64, above_slot], |row| {
+                let logo_hash: String = row.get(8)?;
+                let previous_token_mint = row
+                    .get::<_, Option<String>>(11)?
+                    .map(|mint| mint.parse().map_err(|_| invalid_column(11, "pubkey")))
+                    .transpose()?;
+                let entry = JournalEntry {
+                    position: JournalPosition {
+                        events: row.get::<_, i64>(0)? as u64,
+                        statuses: 0,
+                    },
+                    slot: row.get::<_, i64>(2)? as u64,
+                    signature: row.get(3)?,
+                    lat: row.get(4)?,
+                    lng: row.get(5)?,
+                    change: JournalChange::Placed {
+                        token_mint: parse_pubkey(row, 6)?,
+                        logo_uri: row.get(7)?,
+                        logo_hash: hex::decode(&logo_hash)
+                            .ok()
+                            .and_then(|hash| hash.try_into().ok())
+                            .ok_or_else(|| invalid_column(8, "logo_hash"))?,
+                        placed_by: parse_pubkey(row, 9)?,
+                        placed_at: row.get(10)?,
+                        previous_token_mint,
+                    },
+                };
+                Ok(((entry.slot, row.get::<_, i64>(1)?), entry))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Statuses journaled before their cell was located borrow its coordinates from a
+        // later row, those of a cell never located can't be placed in any view
+        let mut statement = self.conn.prepare(
+            "SELECT statuses.seq, COALESCE(transactions.rowid, 0), statuses.slot, signature,
+                 COALESCE(statuses.lat, located.lat), COALESCE(statuses.lng, located.lng),
+                 placed_at, status
+             FROM statuses LEFT JOIN transactions USING (signature)
+                 LEFT JOIN (
+                     SELECT cell, lat, lng FROM events GROUP BY cell
+                     UNION SELECT cell, lat, lng FROM statuses
+                         WHERE lat IS NOT NULL
+                             AND cell NOT IN (SELECT cell FROM events)
+                         GROUP BY cell
+                 ) AS located USING (cell)
+             WHERE statuses.seq > ?1 AND statuses.slot > ?2
+                 AND COALESCE(statuses.lat, located.lat) IS NOT NULL
+             ORDER BY statuses.seq",
+        )?;
+        let statuses = statement
+            .query_map(params![after.statuses as i64, above_slot], |row| {
+                let status = match row.get::<_, String>(7)?.as_str() {
+                    "hidden" => CellStatus::Hidden,
+                    "unhidden" => CellStatus::Unhidden,
+                    "taken_down" => CellStatus::TakenDown,
+                    _ => return Err(invalid_column(7, "status")),
+                };
+                let entry = JournalEntry {
+                    position: JournalPosition {
+                        events: 0,
+                        statuses: row.get::<_, i64>(0)? as u64,
+                    },
+                    slot: row.get::<_, i64>(2)? as u64,
+                    signature: row.get(3)?,
+                    lat: row.get(4)?,
+                    lng: row.get(5)?,
+                    change: JournalChange::Status {
+                        placed_at: row.get(6)?,
+                        status,
+                    },
+                };
+                Ok(((entry.slot, row.get::<_, i64>(1)?), entry))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut entries = Vec::with_capacity(placed.len() + statuses.len());
+        let mut position = after;
+        let mut placed = placed.into_iter().peekable();
+        let mut statuses = statuses.into_iter().peekable();
+        loop {
+            let take_placed = match (placed.peek(), statuses.peek()) {
+                (Some((placed, _)), Some((status, _))) => placed <= status,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+            let mut entry = if take_placed {
+                let (_, entry) = placed.next().expect("peeked");
+                position.events = entry.position.events;
+                entry
+            } else {
+                let (_, entry) = statuses.next().expect("peeked");
+                position.statuses = entry.position.statuses;
+                entry
+            };
+            entry.position = position;
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+
+    /// Up to `limit` visible cells inside `bbox` sorted by coordinates, starting after `after`
+    pub fn cells_page(
+        &self,
+        bbox: &BoundingBox,
+        after: Option<(i32, i32)>,
+        limit: usize,
+    ) -> Result<Vec<Cell>, IndexerError> {
+        let (after_lat, after_lng) = after.unwrap_or((i32::MIN, i32::MIN));
+        let mut statement = self.conn.prepare(
+            "SELECT * FROM cells WHERE lat BETWEEN ?1 AND ?2
+                 AND (CASE WHEN ?3 <= ?4 THEN lng BETWEEN ?3 AND ?4 ELSE lng >= ?3 OR lng <= ?4 END)
+                 AND NOT hidden
+                 AND (lat, lng) > (?5, ?6)
+             ORDER BY lat, lng LIMIT ?7",
+        )?;
+        let rows = statement.query_map(
+            params![
+                bbox.min_lat,
+                bbox.max_lat,
+                bbox.min_lng,
+                bbox.max_lng,
+                after_lat,
+                after_lng,
+                limit as i64,
+            ],
+            read_cell,
+        )?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    /// Up to `limit` tiles at zoom `z` holding visible cells inside `bbox`, sorted by `(x, y)`
+    /// and starting after `after`
+    pub fn tiles_page(
+        &self,
+        bbox: &BoundingBox,
+        z: u8,
+        after: Option<(i64, i64)>,
+        limit: usize,
+    ) -> Result<Vec<TileCells>, IndexerError> {
+        let (after_x, after_y) = after.unwrap_or((-1, -1));
+        let mut statement = self.conn.prepare(
+            "WITH visible AS (
+                 SELECT * FROM (
+                     SELECT tile_x(lat, lng, ?5) AS x, tile_y(lat, lng, ?5) AS y,
+                         token_mint, placements, placed_at
+                     FROM cells WHERE lat BETWEEN ?1 AND ?2
+                         AND (CASE WHEN ?3 <= ?4 THEN lng BETWEEN ?3 AND ?4 ELSE lng >= ?3 OR lng <= ?4 END)
+                         AND NOT hidden
+                 ) WHERE (x, y) > (?6, ?7)
+             ),
+             tiles AS (
+                 SELECT x, y, COUNT(*) AS cells, SUM(placements) AS placements,
+                     MAX(placed_at) AS last_placed_at
+                 FROM visible GROUP BY x, y ORDER BY x, y LIMIT ?8
+             ),
+             mints AS (
+                 SELECT x, y, token_mint, ROW_NUMBER() OVER (
+                     PARTITION BY x, y ORDER BY COUNT(*) DESC, MAX(placed_at) DESC, token_mint DESC
+                 ) AS rank, COUNT(*) AS mint_cells
+                 FROM visible WHERE (x, y) IN (SELECT x, y FROM tiles)
+                 GROUP BY x, y, token_mint
+             )
+             SELECT tiles.x, tiles.y, cells, placements, token_mint, mint_cells, last_placed_at
+             FROM tiles JOIN mints USING (x, y) WHERE rank = 1
+             ORDER BY tiles.x, tiles.y",
+        )?;
+        let rows = statement.query_map(
+            params![
+                bbox.min_lat,
+                bbox.max_lat,
+                bbox.min_lng,
+                bbox.max_lng,
+                z,
+                after_x,
+                after_y,
+                limit as i64,
+            ],
+            |row| {
+                Ok(TileCells {
+                    tile: Tile {
+                        z,
+                        x: row.get(0)?,
+                        y: row.get(1)?,
+                    },
+                    cells: row.get(2)?,
+                    placements: row.get(3)?,
+                    top_mint: parse_pubkey(row, 4)?,
+                    top_mint_cells: row.get(5)?,
+                    last_placed_at: row.get(6)?,
+                })
+            },
+        )?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    pub fn user(&self, user: &Pubkey) -> Result<Option<UserStats>, IndexerError> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT * FROM users WHERE user = ?1",
+                [user.to_string()],
+                |row| {
+                    Ok(UserStats {
+                        user: parse_pubkey(row, 0)?,
+                        placements: row.get(1)?,
+                        fees_paid: row.get::<_, i64>(2)? as u64,
+                        last_placement: row.get(3)?,
+                    })
+                },
+            )
+            .optional()?)
+    }
+
+    pub fn mint(&self, token_mint: &Pubkey) -> Result<Option<MintStats>, IndexerError> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT * FROM mints WHERE token_mint = ?1",
+                [token_mint.to_string()],
+                |row| {
+                    Ok(MintStats {
+                        token_mint: parse_pubkey(row, 0)?,
+                        placements: row.get(1)?,
+                        cells: row.get(2)?,
+                    })
+                },
+            )
+            .optional()?)
+    }
+}
+
+/// Recompute the derived rows of `touched` keys from the event journal
+fn refresh(tx: &Transaction, mut touched: Touched) -> Result<(), IndexerError> {
+    for (lat, lng) in &touched.cells {
+        // The mint shown before the change loses a cell
+        let previous: Option<String> = tx
+            .query_row(
+                "SELECT token_mint FROM cells WHERE lat = ?1 AND lng = ?2",
+                [lat, lng],
+                |row| row.get(0),
+            )
+            .optional()?;
+        touched.mints.extend(previous);
+
+        tx.execute("DELETE FROM cells WHERE lat = ?1 AND lng = ?2", [lat, lng])?;
+        tx.execute(
+            "INSERT INTO cells (lat, lng, token_mint, logo_uri, logo_hash, placed_by, placed_at,
+                 placements, slot, signature, overwrite_count, hidden)
+             WITH history AS (SELECT COUNT(*) AS placements FROM events WHERE lat = ?1 AND lng = ?2)
+             SELECT lat, lng, token_mint, logo_uri, logo_hash, user, timestamp,
+                 history.placements, slot, signature,
+                 MAX(overwrite_count, history.placements - 1), 0
+             FROM events, history WHERE lat = ?1 AND lng = ?2
+             ORDER BY slot DESC, seq DESC LIMIT 1",
+            [lat, lng],
+        )?;
+
+        // The latest status of the placement shown decides whether it is hidden
+        let cell = get_logo_placement_pda(*lat, *lng, &program::ID).0;
+        tx.execute(
+            "UPDATE cells SET hidden = COALESCE((
+                 SELECT status = 'hidden' FROM statuses
+                 WHERE cell = ?3 AND placed_at = cells.placed_at
+                 ORDER BY slot DESC, seq DESC LIMIT 1
+             ), 0)
+             WHERE lat = ?1 AND lng = ?2",
+            params![lat, lng, cell.to_string()],
+        )?;
+
+        // A taken down placement leaves the cell empty
+        tx.execute(
+            "DELETE FROM cells WHERE lat = ?1 AND lng = ?2 AND EXISTS (
+                 SELECT 1 FROM statuses
+                 WHERE cell = ?3 AND placed_at = cells.placed_at AND status = 'taken_down'
+             )",
+            params![lat, lng, cell.to_string()],
+        )?;
+
+        // The mint shown after the change, which a status alone may bring back
+        let current: Option<String> = tx
+            .query_row(
+                "SELECT token_mint FROM cells WHERE lat = ?1 AND lng = ?2",
+                [lat, lng],
+                |row| row.get(0),
+            )
+            .optional()?;
+        touched.mints.extend(current);
+    }
+
+    for user in &touched.users {
+        tx.execute("DELETE FROM users WHERE user = ?1", [user])?;
+        tx.execute(
+            "INSERT INTO users (user, placements, fees_paid, last_placement)
+             SELECT user, COUNT(*), SUM(fee_paid), MAX(timestamp)
+             FROM events WHERE user = ?1 GROUP BY user",
+            [user],
+        )?;
+    }
+
+    for token_mint in &touched.mints {
+        tx.execute("DELETE FROM mints WHERE token_mint = ?1", [token_mint])?;
+        tx.execute(
+            "INSERT INTO mints (token_mint, placements, cells)
+             SELECT token_mint, COUNT(*),
+                 (SELECT COUNT(*) FROM cells WHERE token_mint = ?1)
+             FROM events WHERE token_mint = ?1 GROUP BY token_mint",
+            [token_mint],
+        )?;
+    }
+    Ok(())
+}
+
+/// Coordinates of the placement account `cell`, `None` until one of its placements or a
+/// status carrying them is indexed
+fn cell_coordinates(tx: &Transaction, cell: &str) -> Result<Option<(i32, i32)>, IndexerError> {
+    Ok(tx
+        .query_row(
+            "SELECT lat, lng FROM events WHERE cell = ?1
+             UNION ALL SELECT lat, lng FROM statuses WHERE cell = ?1 AND lat IS NOT NULL
+             LIMIT 1",
+            [cell],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?)
+}
+
+fn read_cell(row: &Row) -> rusqlite::Result<Cell> {
+    let logo_hash: String = row.get(4)?;
+    Ok(Cell {
+        lat: row.get(0)?,
+        lng: row.get(1)?,
+        token_mint: parse_pubkey(row, 2)?,
+        logo_uri: row.get(3)?,
+        logo_hash: hex::decode(&logo_hash)
+            .ok()
+            .and_then(|hash| hash.try_into().ok())
+            .ok_or_else(|| invalid_column(4, "logo_hash"))?,
+        placed_by: parse_pubkey(row, 5)?,
+        placed_at: row.get(6)?,
+        placements: row.get(7)?,
+        slot: row.get::<_, i64>(8)? as u64,
+        signature: row.get(9)?,
+        overwrite_count: row.get(10)?,
+        hidden: row.get(11)?,
+    })
+}
+
+fn parse_pubkey(row: &Row, index: usize) -> rusqlite::Result<Pubkey> {
+    let value: String = row.get(index)?;
+    value.parse().map_err(|_| invalid_column(index, "pubkey"))
+}
+
+fn invalid_column(index: usize, what: &str) -> rusqlite::Error {
+    rusqlite::Error::FromSqlConversionFailure(
+        index,
+        rusqlite::types::Type::Text,
+        format!("invalid {what}").into(),
+    )
+}
@@ -0,0 +1,109 @@
+use solplace_client::RpcClient;
+
+use crate::*;
+
+/// Signatures requested per `getSignaturesForAddress` page
+const SIGNATURE_PAGE: usize = 1000;
+/// Signatures checked per `getSignatureStatuses` call
+const STATUS_BATCH: usize = 256;
+
+/// Index every SolPlace transaction newer than the store's last one
+/// Returns the number of transactions applied
+pub fn sync(store: &mut Store, client: &RpcClient) -> Result<usize, IndexerError> {
+    let until = store.last_signature()?;
+    let mut signatures = Vec::new();
+    let mut before: Option<String> = None;
+
+    // Pages come newest first, walk back to the last indexed signature
+    loop {
+        let mut config = serde_json::json!({ "limit": SIGNATURE_PAGE, "commitment": "confirmed" });
+        if let Some(until) = &until {
+            config["until"] = until.clone().into();
+        }
+        if let Some(before) = &before {
+            config["before"] = before.clone().into();
+        }
+        let page = client.request(
+            "getSignaturesForAddress",
+            serde_json::json!([program::ID.to_string(), config]),
+        )?;
+        let page = page
+            .as_array()
+            .ok_or_else(|| IndexerError::InvalidTransaction("missing signatures".to_string()))?;
+        for entry in page {
+            if let Some(signature) = entry["signature"].as_str() {
+                signatures.push(signature.to_string());
+            }
+        }
+        if page.len() < SIGNATURE_PAGE {
+            break;
+        }
+        before = signatures.last().cloned();
+    }
+
+    let mut applied = 0;
+    for signature in signatures.iter().rev() {
+        let result = client.request(
+            "getTransaction",
+            serde_json::json!([
+                signature,
+                {
+                    "encoding": "json",
+                    "commitment": "confirmed",
+                    "maxSupportedTransactionVersion": 0,
+                },
+            ]),
+        )?;
+        // A node can list a signature before it serves the transaction. Stop before it so
+        // the next round resumes from the last indexed signature and asks again.
+        if result.is_null() {
+            break;
+        }
+        store.apply(&IndexedTransaction::from_rpc(&result)?)?;
+        applied += 1;
+    }
+    Ok(applied)
+}
+
+/// Roll back transactions indexed above the finalized slot that left the chain
+/// Returns what was rolled back, if anything
+pub fn check_reorgs(
+    store: &mut Store,
+    client: &RpcClient,
+) -> Result<Option<Rollback>, IndexerError> {
+    let finalized = client
+        .request(
+            "getSlot",
+            serde_json::json!([{ "commitment": "finalized" }]),
+        )?
+        .as_u64()
+        .ok_or_else(|| IndexerError::InvalidTransaction("missing finalized slot".to_string()))?;
+    let unfinalized = store.transactions_after(finalized)?;
+
+    let mut dropped_slot = None;
+    for batch in unfinalized.chunks(STATUS_BATCH) {
+        let signatures: Vec<&str> = batch
+            .iter()
+            .map(|(_, signature)| signature.as_str())
+            .collect();
+        let statuses = client.request("getSignatureStatuses", serde_json::json!([signatures]))?;
+        for ((slot, _), status) in batch
+            .iter()
+            .zip(statuses["value"].as_array().into_iter().flatten())
+        {
+            // A missing status, or one in another slot, means the block was replaced
+            if status["slot"].as_u64() != Some(*slot) {
+                dropped_slot = Some(*slot);
+                break;
+            }
+        }
+        if dropped_slot.is_some() {
+            break;
+        }
+    }
+
+    let Some(slot) = dropped_slot else {
+        return Ok(None);
+    };
+    Ok(Some(store.rollback(slot.saturating_sub(1))?))
+}
@@ -0,0 +1,71 @@
+use program::instructions::LogoPlacedEvent;
+use solplace_client::transaction_cpi_event_data;
+
+use crate::*;
+
+/// A confirmed transaction touching the SolPlace program
+pub struct IndexedTransaction {
+    pub slot: u64,
+    pub signature: String,
+    pub block_time: Option<i64>,
+    /// Placements in emission order, empty when the transaction failed
+    pub events: Vec<LogoPlacedEvent>,
+    /// Hide, un-hide and takedown changes in emission order
+    pub statuses: Vec<CellStatusEvent>,
+    /// Legacy placements logged by the program may be missing, self-CPI events are never truncated
+    pub logs_truncated: bool,
+}
+
+impl IndexedTransaction {
+    /// Parse a `getTransaction` result in `json` encoding
+    pub fn from_rpc(value: &serde_json::Value) -> Result<Self, IndexerError> {
+        let invalid = |field: &str| IndexerError::InvalidTransaction(format!("missing {field}"));
+        let slot = value["slot"].as_u64().ok_or_else(|| invalid("slot"))?;
+        let signature = value["transaction"]["signatures"][0]
+            .as_str()
+            .ok_or_else(|| invalid("signature"))?
+            .to_string();
+        let meta = &value["meta"];
+        if meta.is_null() {
+            return Err(invalid("meta"));
+        }
+
+        let mut transaction = IndexedTransaction {
+            slot,
+            signature,
+            block_time: value["blockTime"].as_i64(),
+            events: Vec::new(),
+            statuses: Vec::new(),
+            logs_truncated: false,
+        };
+        // Failed transactions log events that never took effect
+        if !meta["err"].is_null() {
+            return Ok(transaction);
+        }
+
+        let invalid_event = |message: String| IndexerError::InvalidEvent {
+            signature: transaction.signature.clone(),
+            message,
+        };
+        let events =
+            transaction_cpi_event_data(value).map_err(|err| invalid_event(err.to_string()))?;
+        for data in events {
+            match decode_program_event(&data).map_err(|err| invalid_event(err.to_string()))? {
+                Some(ProgramEvent::Placed(event)) => transaction.events.push(*event),
+                Some(ProgramEvent::Status(status)) => transaction.statuses.push(status),
+                None => {}
+            }
+        }
+
+        // Transactions from before self-CPI events only logged placements
+        let logs = meta["logMessages"]
+            .as_array()
+            .ok_or_else(|| invalid("logMessages"))?
+            .iter()
+            .filter_map(serde_json::Value::as_str);
+        let parsed = parse_log_events(logs).map_err(invalid_event)?;
+        transaction.events.extend(parsed.events);
+        transaction.logs_truncated = parsed.truncated;
+        Ok(transaction)
+    }
+}
@@ -0,0 +1,534 @@
+//! Feeds recorded `getTransaction` results through the indexer, no network needed
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::{Discriminator, Event};
+use base64::Engine;
+use program::instructions::{
+    CooldownUpdatedEvent, FeeCollectedEvent, LogoModeratedEvent, LogoPlacedEvent,
+    PlacementHiddenEvent, PlacementUnhiddenEvent,
+};
+use program::state::{LogoPlacement, ModerationAction, ModerationReason};
+use solplace_client::{get_logo_placement_pda, transaction_cpi_events, BoundingBox};
+use solplace_indexer::*;
+
+const NEW_YORK: (i32, i32) = (40_712_800, -74_006_000);
+const PARIS: (i32, i32) = (48_858_400, 2_294_500);
+
+/// Fixture accounts are sha256 of a name
+fn key(name: &str) -> Pubkey {
+    Pubkey::new_from_array(hash(name.as_bytes()).to_bytes())
+}
+
+fn fixture(name: &str) -> serde_json::Value {
+    let path = format!("{}/tests/fixtures/{name}.json", env!("CARGO_MANIFEST_DIR"));
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+fn transaction(name: &str) -> IndexedTransaction {
+    IndexedTransaction::from_rpc(&fixture(name)).unwrap()
+}
+
+/// A successful transaction in which the program logged `events` with `emit!`
+fn logged(slot: u64, signature: &str, events: &[Vec<u8>]) -> IndexedTransaction {
+    let program_id = program::ID.to_string();
+    let mut logs = vec![format!("Program {program_id} invoke [1]")];
+    logs.extend(events.iter().map(|data| {
+        format!(
+            "Program data: {}",
+            base64::engine::general_purpose::STANDARD.encode(data)
+        )
+    }));
+    logs.push(format!("Program {program_id} success"));
+
+    let mut value = fixture("place_new");
+    value["slot"] = slot.into();
+    value["transaction"]["signatures"][0] = signature.into();
+    value["meta"]["logMessages"] = logs.into();
+    IndexedTransaction::from_rpc(&value).unwrap()
+}
+
+/// A successful transaction in which the program emitted `events` with `emit_cpi!`
+fn emitted(slot: u64, signature: &str, events: &[Vec<u8>]) -> IndexedTransaction {
+    let instructions: Vec<_> = events
+        .iter()
+        .map(|data| {
+            serde_json::json!({
+                "programIdIndex": 2,
+                "accounts": [1],
+                "data": bs58::encode([EVENT_IX_TAG_LE, data].concat()).into_string(),
+                "stackHeight": 2,
+            })
+        })
+        .collect();
+
+    let mut value = fixture("place_overwrite");
+    value["slot"] = slot.into();
+    value["transaction"]["signatures"][0] = signature.into();
+    value["meta"]["innerInstructions"] =
+        serde_json::json!([{ "index": 0, "instructions": instructions }]);
+    IndexedTransaction::from_rpc(&value).unwrap()
+}
+
+fn indexed_store() -> Store {
+    let mut store = Store::open_in_memory().unwrap();
+    for name in [
+        "place_new",
+        "place_overwrite",
+        "place_second_cell",
+        "place_failed",
+        "spoofed_event",
+    ] {
+        store.apply(&transaction(name)).unwrap();
+    }
+    store
+}
+
+#[test]
+fn rebuilds_cells_users_and_mints() {
+    let store = indexed_store();
+
+    let cell = store.cell(NEW_YORK.0, NEW_YORK.1).unwrap().unwrap();
+    assert_eq!(cell.token_mint, key("wif"));
+    assert_eq!(cell.placed_by, key("bob"));
+    assert_eq!(cell.logo_uri, "https://example.com/wif.png");
+    assert_eq!(cell.placements, 2);
+    assert_eq!(cell.overwrite_count, 1);
+    assert_eq!(cell.slot, 101);
+
+    let alice = store.user(&key("alice")).unwrap().unwrap();
+    assert_eq!(alice.placements, 2);
+    assert_eq!(alice.fees_paid, 2_000_000);
+    assert_eq!(alice.last_placement, 1_700_000_140);
+    assert_eq!(
+        store.user(&key("bob")).unwrap().unwrap().fees_paid,
+        3_750_000
+    );
+
+    let bonk = store.mint(&key("bonk")).unwrap().unwrap();
+    assert_eq!((bonk.placements, bonk.cells), (2, 1));
+    let wif = store.mint(&key("wif")).unwrap().unwrap();
+    assert_eq!((wif.placements, wif.cells), (1, 1));
+
+    let europe: BoundingBox = "35000000,-10000000,60000000,30000000".parse().unwrap();
+    let cells = store.cells_in(&europe).unwrap();
+    assert_eq!(cells.len(), 1);
+    assert_eq!((cells[0].lat, cells[0].lng), PARIS);
+    assert_eq!(store.cells_in(&BoundingBox::WORLD).unwrap().len(), 2);
+    assert_eq!(store.last_slot().unwrap(), Some(104));
+}
+
+#[test]
+fn decodes_self_cpi_and_legacy_log_events() {
+    let overwrite = transaction("place_overwrite");
+    assert!(!overwrite.logs_truncated);
+    let [event] = overwrite.events.as_slice() else {
+        panic!("expected one event");
+    };
+    assert_eq!(
+        event.cell,
+        get_logo_placement_pda(NEW_YORK.0, NEW_YORK.1, &program::ID).0
+    );
+    assert_eq!(event.previous_token_mint, Some(key("bonk")));
+    assert_eq!(event.previous_placed_by, Some(key("alice")));
+    assert_eq!(event.previous_placed_at, Some(1_700_000_100));
+    assert_eq!(event.overwrite_count, 1);
+    assert_eq!(
+        (event.base_fee, event.holder_discount, event.fee_paid),
+        (5_000_000, 1_250_000, 3_750_000)
+    );
+    assert_eq!(event.holder_discount_bps, 2500);
+
+    // Cooldown and fee events ride in the same transaction
+    let cooldowns: Vec<CooldownUpdatedEvent> =
+        transaction_cpi_events(&fixture("place_overwrite")).unwrap();
+    assert_eq!(cooldowns.len(), 1);
+    assert_eq!(cooldowns[0].cooldown_ends_at, 1_700_000_131);
+    let fees: Vec<FeeCollectedEvent> = transaction_cpi_events(&fixture("place_overwrite")).unwrap();
+    assert_eq!(fees.len(), 1);
+    assert_eq!((fees[0].cell, fees[0].fee_paid), (event.cell, 3_750_000));
+
+    let second = &transaction("place_second_cell").events[0];
+    assert_eq!(second.previous_token_mint, None);
+    assert_eq!(second.previous_placed_at, None);
+    assert_eq!(second.rent_paid, 2_498_640);
+
+    // Logged events predate the breakdown, it is derived from the fee schedule
+    let legacy = &transaction("place_new").events[0];
+    assert_eq!(legacy.user, key("alice"));
+    assert_eq!(
+        legacy.cell,
+        get_logo_placement_pda(NEW_YORK.0, NEW_YORK.1, &program::ID).0
+    );
+    assert_eq!(legacy.previous_placed_by, None);
+    assert_eq!((legacy.base_fee, legacy.holder_discount), (1_000_000, 0));
+    assert_eq!(legacy.rent_paid, 0);
+}
+
+#[test]
+fn decodes_events_in_the_original_layout() {
+    // Field by field as the original program logged it
+    let mut data = LogoPlacedEvent::DISCRIMINATOR.to_vec();
+    data.extend(key("erin").to_bytes());
+    data.extend(PARIS.0.to_le_bytes());
+    data.extend(PARIS.1.to_le_bytes());
+    data.extend(key("popcat").to_bytes());
+    data.extend(30u32.to_le_bytes());
+    data.extend(b"https://example.com/popcat.png");
+    data.extend(5_000_000u64.to_le_bytes());
+    data.push(1);
+    data.extend(1_700_000_400i64.to_le_bytes());
+
+    let transaction = logged(110, "original-layout", &[data]);
+    let [event] = transaction.events.as_slice() else {
+        panic!("expected one event");
+    };
+    assert_eq!((event.user, event.token_mint), (key("erin"), key("popcat")));
+    assert_eq!((event.lat, event.lng), PARIS);
+    assert_eq!(event.logo_uri, "https://example.com/popcat.png");
+    assert_eq!(event.logo_hash, [0; 32]);
+    assert!(event.is_overwrite);
+    assert_eq!(
+        (event.base_fee, event.holder_discount, event.fee_paid),
+        (5_000_000, 0, 5_000_000)
+    );
+    assert_eq!(event.holder_discount_bps, 0);
+    assert_eq!(event.timestamp, 1_700_000_400);
+}
+
+#[test]
+fn ignores_failed_and_foreign_events() {
+    let failed = transaction("place_failed");
+    assert!(failed.events.is_empty());
+    let spoofed = transaction("spoofed_event");
+    assert!(spoofed.events.is_empty());
+
+    let store = indexed_store();
+    assert!(store.user(&key("carol")).unwrap().is_none());
+    assert!(store.user(&key("mallory")).unwrap().is_none());
+    assert!(store.mint(&key("scam")).unwrap().is_none());
+}
+
+#[test]
+fn reapplying_a_transaction_is_a_no_op() {
+    let mut store = indexed_store();
+    assert_eq!(store.apply(&transaction("place_overwrite")).unwrap(), 0);
+    assert_eq!(
+        store
+            .cell(NEW_YORK.0, NEW_YORK.1)
+            .unwrap()
+            .unwrap()
+            .placements,
+        2
+    );
+    assert_eq!(store.user(&key("bob")).unwrap().unwrap().placements, 1);
+}
+
+#[test]
+fn rolls_back_above_a_slot() {
+    let mut store = indexed_store();
+    assert_eq!(
+        store.rollback(100).unwrap(),
+        Rollback {
+            slot: 100,
+            removed: 2,
+            cells: vec![NEW_YORK, PARIS],
+        }
+    );
+
+    let cell = store.cell(NEW_YORK.0, NEW_YORK.1).unwrap().unwrap();
+    assert_eq!(cell.token_mint, key("bonk"));
+    assert_eq!(cell.placed_by, key("alice"));
+    assert_eq!(cell.placements, 1);
+    assert!(store.cell(PARIS.0, PARIS.1).unwrap().is_none());
+
+    assert!(store.user(&key("bob")).unwrap().is_none());
+    assert!(store.mint(&key("wif")).unwrap().is_none());
+    let bonk = store.mint(&key("bonk")).unwrap().unwrap();
+    assert_eq!((bonk.placements, bonk.cells), (1, 1));
+    assert_eq!(store.last_slot().unwrap(), Some(100));
+
+    // The dropped transactions land again and are re-indexed
+    store.apply(&transaction("place_overwrite")).unwrap();
+    assert_eq!(
+        store
+            .cell(NEW_YORK.0, NEW_YORK.1)
+            .unwrap()
+            .unwrap()
+            .token_mint,
+        key("wif")
+    );
+    assert_eq!(store.mint(&key("bonk")).unwrap().unwrap().cells, 0);
+}
+
+#[test]
+fn flags_truncated_logs() {
+    let mut value = fixture("place_new");
+    value["meta"]["logMessages"]
+        .as_array_mut()
+        .unwrap()
+        .push("Log truncated".into());
+    let transaction = IndexedTransaction::from_rpc(&value).unwrap();
+    assert!(transaction.logs_truncated);
+    assert_eq!(transaction.events.len(), 1);
+}
+
+#[test]
+fn events_fold_over_imported_snapshots() {
+    let snapshot = LogoPlacement {
+        version: LogoPlacement::VERSION,
+        coordinates: [NEW_YORK.0, NEW_YORK.1],
+        token_mint: key("pepe"),
+        logo_uri: "https://example.com/pepe.png".to_string(),
+        logo_hash: [1; 32],
+        placed_by: key("dave"),
+        placed_at: 1_700_000_000,
+        overwrite_count: 4,
+        bump: 255,
+    };
+    let mut store = Store::open_in_memory().unwrap();
+    assert_eq!(
+        store
+            .import_placements(99, std::slice::from_ref(&snapshot))
+            .unwrap(),
+        1
+    );
+    assert_eq!(store.import_placements(99, &[snapshot]).unwrap(), 0);
+    let imported = store.cell(NEW_YORK.0, NEW_YORK.1).unwrap().unwrap();
+    assert_eq!(
+        (
+            imported.token_mint,
+            imported.placements,
+            imported.overwrite_count
+        ),
+        (key("pepe"), 1, 4)
+    );
+    // Cells read back as the accounts they were imported from
+    let placement = LogoPlacement::from(imported);
+    assert_eq!(placement.coordinates, [NEW_YORK.0, NEW_YORK.1]);
+    assert_eq!(
+        (placement.placed_by, placement.overwrite_count),
+        (key("dave"), 4)
+    );
+
+    store.apply(&transaction("place_new")).unwrap();
+    let cell = store.cell(NEW_YORK.0, NEW_YORK.1).unwrap().unwrap();
+    assert_eq!((cell.token_mint, cell.placements), (key("bonk"), 2));
+    assert_eq!(store.mint(&key("pepe")).unwrap().unwrap().cells, 0);
+    // Snapshots are not transactions, sync resumes from the last real signature
+    assert_eq!(store.last_slot().unwrap(), Some(100));
+}
+
+#[test]
+fn hides_reported_cells_until_unhidden() {
+    let mut store = indexed_store();
+    let cell = get_logo_placement_pda(NEW_YORK.0, NEW_YORK.1, &program::ID).0;
+    let placed_at = store.cell(NEW_YORK.0, NEW_YORK.1).unwrap().unwrap().placed_at;
+    let hidden = |placed_at: i64| PlacementHiddenEvent {
+        lat: NEW_YORK.0,
+        lng: NEW_YORK.1,
+        token_mint: key("wif"),
+        placed_at,
+        report_count: 3,
+        timestamp: 1_700_000_500,
+    };
+
+    // Status changes only count when emitted through self-CPI, logs can be forged
+    let forged = logged(105, "hide-logged", &[hidden(placed_at).data()]);
+    assert!(forged.statuses.is_empty());
+
+    // Hiding the overwritten placement leaves the current one alone
+    let stale = emitted(105, "hide-stale", &[hidden(1_700_000_100).data()]);
+    assert_eq!(stale.statuses.len(), 1);
+    store.apply(&stale).unwrap();
+    assert!(!store.cell(NEW_YORK.0, NEW_YORK.1).unwrap().unwrap().hidden);
+
+    store
+        .apply(&emitted(106, "hide", &[hidden(placed_at).data()]))
+        .unwrap();
+    let shown = store.cell(NEW_YORK.0, NEW_YORK.1).unwrap().unwrap();
+    assert!(shown.hidden);
+    assert_eq!(shown.token_mint, key("wif"));
+    let cells = store.cells_in(&BoundingBox::WORLD).unwrap();
+    assert_eq!(cells.len(), 1);
+    assert_eq!((cells[0].lat, cells[0].lng), PARIS);
+    // A hidden logo still holds its cell
+    assert_eq!(store.mint(&key("wif")).unwrap().unwrap().cells, 1);
+
+    let unhidden = PlacementUnhiddenEvent {
+        logo_placement: cell,
+        placed_at,
+        report_count: 2,
+        timestamp: 1_700_000_600,
+    };
+    store
+        .apply(&emitted(107, "unhide", &[unhidden.data()]))
+        .unwrap();
+    assert!(!store.cell(NEW_YORK.0, NEW_YORK.1).unwrap().unwrap().hidden);
+    assert_eq!(store.cells_in(&BoundingBox::WORLD).unwrap().len(), 2);
+
+    // Rolling back the resolution hides the logo again
+    assert_eq!(store.rollback(106).unwrap().removed, 1);
+    assert!(store.cell(NEW_YORK.0, NEW_YORK.1).unwrap().unwrap().hidden);
+    assert_eq!(store.rollback(104).unwrap().removed, 2);
+    assert!(!store.cell(NEW_YORK.0, NEW_YORK.1).unwrap().unwrap().hidden);
+}
+
+#[test]
+fn clears_taken_down_cells() {
+    let mut store = indexed_store();
+    let placed_at = store.cell(NEW_YORK.0, NEW_YORK.1).unwrap().unwrap().placed_at;
+    let moderated = |placed_at: i64, action: ModerationAction| LogoModeratedEvent {
+        lat: NEW_YORK.0,
+        lng: NEW_YORK.1,
+        token_mint: key("wif"),
+        placed_by: key("bob"),
+        placed_at,
+        moderator: key("moderator"),
+        reason: ModerationReason::Scam,
+        action,
+        reclaimed_lamports: 0,
+        rent_to: key("treasury"),
+        timestamp: 1_700_000_500,
+    };
+
+    // Taking down the overwritten placement leaves the current one alone
+    store
+        .apply(&emitted(
+            105,
+            "takedown-stale",
+            &[moderated(1_700_000_100, ModerationAction::Close).data()],
+        ))
+        .unwrap();
+    assert!(store.cell(NEW_YORK.0, NEW_YORK.1).unwrap().is_some());
+
+    store
+        .apply(&emitted(
+            106,
+            "takedown",
+            &[moderated(placed_at, ModerationAction::Blank).data()],
+        ))
+        .unwrap();
+    assert_eq!(store.cell(NEW_YORK.0, NEW_YORK.1).unwrap(), None);
+    let cells = store.cells_in(&BoundingBox::WORLD).unwrap();
+    assert_eq!(cells.len(), 1);
+    assert_eq!((cells[0].lat, cells[0].lng), PARIS);
+    assert_eq!(store.mint(&key("wif")).unwrap().unwrap().cells, 0);
+
+    // Rolling back the takedown shows the logo again
+    assert_eq!(store.rollback(105).unwrap().removed, 1);
+    assert_eq!(
+        store.cell(NEW_YORK.0, NEW_YORK.1).unwrap().unwrap().token_mint,
+        key("wif")
+    );
+    assert_eq!(store.mint(&key("wif")).unwrap().unwrap().cells, 1);
+}
+
+#[test]
+fn journals_placements_and_statuses_in_order() {
+    let mut store = indexed_store();
+    let placed_at = store.cell(NEW_YORK.0, NEW_YORK.1).unwrap().unwrap().placed_at;
+    let hidden = PlacementHiddenEvent {
+        lat: NEW_YORK.0,
+        lng: NEW_YORK.1,
+        token_mint: key("wif"),
+        placed_at,
+        report_count: 3,
+        timestamp: 1_700_000_500,
+    };
+    store
+        .apply(&emitted(106, "hide", &[hidden.data()]))
+        .unwrap();
+
+    let journal = store
+        .journal_since(JournalPosition::default(), None)
+        .unwrap();
+    let positions: Vec<_> = journal
+        .iter()
+        .map(|entry| entry.position.to_string())
+        .collect();
+    assert_eq!(positions, ["1.0", "2.0", "3.0", "3.1"]);
+    assert_eq!(store.journal_position().unwrap(), journal[3].position);
+    assert_eq!(
+        (journal[3].lat, journal[3].lng, &journal[3].change),
+        (
+            NEW_YORK.0,
+            NEW_YORK.1,
+            &JournalChange::Status {
+                placed_at,
+                status: CellStatus::Hidden
+            }
+        )
+    );
+
+    // Resuming reads on from a position, or from a slot
+    let after = "2.0".parse().unwrap();
+    assert_eq!(store.journal_since(after, None).unwrap(), journal[2..]);
+    assert_eq!(store.journal_since(JournalPosition::default(), Some(101)).unwrap().len(), 2);
+
+    // Rolled back rows never give their position to later entries
+    store.rollback(101).unwrap();
+    store
+        .apply(&emitted(102, "hide-again", &[hidden.data()]))
+        .unwrap();
+    let redone = store.journal_since(after, None).unwrap();
+    assert_eq!(redone.len(), 1);
+    assert_eq!(redone[0].position.to_string(), "2.2");
+}
+
+#[test]
+fn journals_snapshots_by_slot_and_statuses_of_unindexed_cells() {
+    let mut store = Store::open_in_memory().unwrap();
+    // Paris was placed before the index starts, only its statuses are seen
+    let cell = get_logo_placement_pda(PARIS.0, PARIS.1, &program::ID).0;
+    let hidden = PlacementHiddenEvent {
+        lat: PARIS.0,
+        lng: PARIS.1,
+        token_mint: key("wif"),
+        placed_at: 1_700_000_000,
+        report_count: 3,
+        timestamp: 1_700_000_500,
+    };
+    store
+        .apply(&emitted(100, "hide", &[hidden.data()]))
+        .unwrap();
+    let snapshot = LogoPlacement {
+        version: LogoPlacement::VERSION,
+        coordinates: [NEW_YORK.0, NEW_YORK.1],
+        token_mint: key("pepe"),
+        logo_uri: "https://example.com/pepe.png".to_string(),
+        logo_hash: [1; 32],
+        placed_by: key("dave"),
+        placed_at: 1_700_000_000,
+        overwrite_count: 0,
+        bump: 255,
+    };
+    store.import_placements(105, &[snapshot]).unwrap();
+    // Unhide events name the cell by address, the hide located it
+    let unhidden = PlacementUnhiddenEvent {
+        logo_placement: cell,
+        placed_at: 1_700_000_000,
+        report_count: 2,
+        timestamp: 1_700_000_600,
+    };
+    store
+        .apply(&emitted(106, "unhide", &[unhidden.data()]))
+        .unwrap();
+
+    // The snapshot of slot 105 follows the slot 100 status even though it has no transaction
+    let journal: Vec<_> = store
+        .journal_since(JournalPosition::default(), None)
+        .unwrap()
+        .into_iter()
+        .map(|entry| (entry.position.to_string(), entry.slot, (entry.lat, entry.lng)))
+        .collect();
+    assert_eq!(
+        journal,
+        [
+            ("0.1".to_string(), 100, PARIS),
+            ("1.1".to_string(), 105, NEW_YORK),
+            ("1.2".to_string(), 106, PARIS),
+        ]
+    );
+}
@@ -0,0 +1,574 @@
+use std::collections::HashMap;
+
+use program::constants::*;
+use program::state::LogoBitmap;
+
+pub type Rgba = [u8; 4];
+
+#[derive(Debug, thiserror::Error)]
+pub enum BitmapError {
+    #[error("invalid PNG: {0}")]
+    Png(String),
+
+    #[error("unsupported bitmap size {0}, expected one of {LOGO_BITMAP_SIZES:?}")]
+    UnsupportedSize(u8),
+
+    #[error("invalid bitmap header")]
+    InvalidHeader,
+
+    #[error("bitmap payload is truncated")]
+    Truncated,
+
+    #[error("bitmap payload has {0} trailing bytes")]
+    TrailingBytes(usize),
+
+    #[error("pixel runs overflow the bitmap")]
+    InvalidPixelData,
+
+    #[error("palette index {0} is out of range")]
+    PaletteIndexOutOfRange(u8),
+
+    #[error("logo bitmap upload is incomplete ({written}/{total} bytes)")]
+    Incomplete { written: usize, total: usize },
+}
+
+/// Palette-indexed square logo, as stored in a `LogoBitmap` account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bitmap {
+    pub size: u8,
+    pub palette: Vec<Rgba>,
+    /// One palette index per pixel, row-major
+    pub pixels: Vec<u8>,
+}
+
+impl Bitmap {
+    /// Downscale an RGBA image to `size` x `size` and quantize it to at most 16 colors
+    pub fn from_rgba(width: u32, height: u32, rgba: &[u8], size: u8) -> Result<Self, BitmapError> {
+        if !LOGO_BITMAP_SIZES.contains(&size) {
+            return Err(BitmapError::UnsupportedSize(size));
+        }
+        let expected_len = (width as usize)
+            .checked_mul(height as usize)
+            .and_then(|pixels| pixels.checked_mul(4));
+        if width == 0 || height == 0 || expected_len != Some(rgba.len()) {
+            return Err(BitmapError::Png("pixel buffer does not match dimensions".to_string()));
+        }
+
+        let resized = resize(width, height, rgba, size as u32);
+        let palette = median_cut(&resized, LOGO_BITMAP_MAX_COLORS);
+        let pixels = resized
+            .iter()
+            .map(|color| nearest_index(&palette, color))
+            .collect();
+
+        Ok(Self {
+            size,
+            palette,
+            pixels,
+        })
+    }
+
+    /// Serialize to the on-chain payload, choosing the smaller pixel encoding
+    pub fn encode(&self) -> Vec<u8> {
+        let packed = pack(&self.pixels);
+        let rle = run_length_encode(&self.pixels);
+        let (encoding, pixel_data) = if rle.len() < packed.len() {
+            (LOGO_BITMAP_ENCODING_RLE, rle)
+        } else {
+            (LOGO_BITMAP_ENCODING_PACKED, packed)
+        };
+
+        let mut payload = Vec::with_capacity(
+            LOGO_BITMAP_HEADER_LEN + self.palette.len() * 4 + pixel_data.len(),
+        );
+        payload.extend_from_slice(&[
+            LOGO_BITMAP_VERSION,
+            self.size,
+            self.size,
+            encoding,
+            self.palette.len() as u8,
+        ]);
+        for color in &self.palette {
+            payload.extend_from_slice(color);
+        }
+        payload.extend_from_slice(&pixel_data);
+        payload
+    }
+
+    /// Parse an on-chain payload
+    pub fn decode(payload: &[u8]) -> Result<Self, BitmapError> {
+        if payload.len() < LOGO_BITMAP_HEADER_LEN {
+            return Err(BitmapError::Truncated);
+        }
+        let (version, width, height, encoding, palette_len) = (
+            payload[0],
+            payload[1],
+            payload[2],
+            payload[3],
+            payload[4] as usize,
+        );
+        if version != LOGO_BITMAP_VERSION
+            || width != height
+            || !(1..=LOGO_BITMAP_MAX_COLORS).contains(&palette_len)
+        {
+            return Err(BitmapError::InvalidHeader);
+        }
+        if !LOGO_BITMAP_SIZES.contains(&width) {
+            return Err(BitmapError::UnsupportedSize(width));
+        }
+
+        let palette_end = LOGO_BITMAP_HEADER_LEN + palette_len * 4;
+        let palette_bytes = payload
+            .get(LOGO_BITMAP_HEADER_LEN..palette_end)
+            .ok_or(BitmapError::Truncated)?;
+        let palette = palette_bytes
+            .chunks_exact(4)
+            .map(|c| [c[0], c[1], c[2], c[3]])
+            .collect::<Vec<_>>();
+
+        let pixel_count = width as usize * height as usize;
+        let pixel_data = &payload[palette_end..];
+        let pixels = match encoding {
+            LOGO_BITMAP_ENCODING_PACKED => unpack(pixel_data, pixel_count)?,
+            LOGO_BITMAP_ENCODING_RLE => run_length_decode(pixel_data, pixel_count)?,
+            _ => return Err(BitmapError::InvalidHeader),
+        };
+        if let Some(&index) = pixels.iter().find(|&&i| i as usize >= palette_len) {
+            return Err(BitmapError::PaletteIndexOutOfRange(index));
+        }
+
+        Ok(Self {
+            size: width,
+            palette,
+            pixels,
+        })
+    }
+
+    /// Expand palette indices back to RGBA pixels
+    pub fn to_rgba(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|&index| self.palette[index as usize])
+            .collect()
+    }
+}
+
+/// Encode a PNG image into an on-chain logo bitmap payload
+pub fn encode_png(png_bytes: &[u8], size: u8) -> Result<Vec<u8>, BitmapError> {
+    let (width, height, rgba) = read_png(png_bytes)?;
+    Ok(Bitmap::from_rgba(width, height, &rgba, size)?.encode())
+}
+
+/// Render an on-chain logo bitmap payload as a PNG image
+pub fn decode_to_png(payload: &[u8]) -> Result<Vec<u8>, BitmapError> {
+    let bitmap = Bitmap::decode(payload)?;
+    write_png(bitmap.size as u32, bitmap.size as u32, &bitmap.to_rgba())
+}
+
+/// Decode the payload of a `LogoBitmap` account once its upload is complete
+pub fn decode_logo_bitmap(account: &LogoBitmap) -> Result<Bitmap, BitmapError> {
+    if !account.is_complete() {
+        return Err(BitmapError::Incomplete {
+            written: account.data.len(),
+            total: account.total_len as usize,
+        });
+    }
+    Bitmap::decode(&account.data)
+}
+
+/// Decode a PNG into 8-bit RGBA pixels
+pub fn read_png(png_bytes: &[u8]) -> Result<(u32, u32, Vec<u8>), BitmapError> {
+    let png_error = |err: png::DecodingError| BitmapError::Png(err.to_string());
+
+    let mut decoder = png::Decoder::new(png_bytes);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info().map_err(png_error)?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer).map_err(png_error)?;
+    buffer.truncate(info.buffer_size());
+
+    let rgba = match info.color_type {
+        png::ColorType::Rgba => buffer,
+        png::ColorType::Rgb => buffer
+            .chunks_exact(3)
+            .flat_map(|c| [c[0], c[1], c[2], 255])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => buffer
+            .chunks_exact(2)
+            .flat_map(|c| [c[0], c[0], c[0], c[1]])
+            .collect(),
+        png::ColorType::Grayscale => buffer.iter().flat_map(|&g| [g, g, g, 255]).collect(),
+        png::ColorType::Indexed => {
+            return Err(BitmapError::Png("indexed color was not expanded".to_string()))
+        }
+    };
+    Ok((info.width, info.height, rgba))
+}
+
+/// Encode 8-bit RGBA pixels as a PNG
+pub fn write_png(width: u32, height: u32, rgba: &[u8]) -> Result<Vec<u8>, BitmapError> {
+    let png_error = |err: png::EncodingError| BitmapError::Png(err.to_string());
+
+    let mut png_bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png_bytes, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(png_error)?;
+    writer.write_image_data(rgba).map_err(png_error)?;
+    writer.finish().map_err(png_error)?;
+    Ok(png_bytes)
+}
+
+/// Box-filter downscale (nearest neighbour when upscaling) to a square of `size`
+pub fn resize(width: u32, height: u32, rgba: &[u8], size: u32) -> Vec<Rgba> {
+    let (width, height, size) = (width as usize, height as usize, size as usize);
+    let mut out = Vec::with_capacity(size * size);
+    for ty in 0..size {
+        let y0 = ty * height / size;
+        let y1 = ((ty + 1) * height / size).max(y0 + 1);
+        for tx in 0..size {
+            let x0 = tx * width / size;
+            let x1 = ((tx + 1) * width / size).max(x0 + 1);
+
+            // Average with premultiplied alpha so transparent pixels don't bleed color
+            let mut sum = [0u64; 4];
+            for y in y0..y1 {
+                for x in x0..x1 {
+                    let i = (y * width + x) * 4;
+                    let alpha = rgba[i + 3] as u64;
+                    for c in 0..3 {
+                        sum[c] += rgba[i + c] as u64 * alpha;
+                    }
+                    sum[3] += alpha;
+                }
+            }
+            let count = ((y1 - y0) * (x1 - x0)) as u64;
+            out.push(match std::num::NonZeroU64::new(sum[3]) {
+                None => [0, 0, 0, 0],
+                Some(weight) => [
+                    (sum[0] / weight) as u8,
+                    (sum[1] / weight) as u8,
+                    (sum[2] / weight) as u8,
+                    (sum[3] / count) as u8,
+                ],
+            });
+        }
+    }
+    out
+}
+
+/// Reduce the image colors to at most `max_colors` palette entries
+fn median_cut(pixels: &[Rgba], max_colors: usize) -> Vec<Rgba> {
+    let mut counts: HashMap<Rgba, u64> = HashMap::new();
+    for &color in pixels {
+        *counts.entry(color).or_default() += 1;
+    }
+    let mut colors: Vec<(Rgba, u64)> = counts.into_iter().collect();
+    colors.sort_unstable();
+
+    let mut boxes = vec![colors];
+    while boxes.len() < max_colors {
+        // Split the box with the widest channel range
+        let Some((index, channel)) = boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| b.len() > 1)
+            .map(|(i, b)| {
+                let (channel, range) = widest_channel(b);
+                (i, channel, range)
+            })
+            .max_by_key(|&(_, _, range)| range)
+            .map(|(i, channel, _)| (i, channel))
+        else {
+            break;
+        };
+
+        let mut colors = boxes.swap_remove(index);
+        colors.sort_unstable_by_key(|(color, _)| color[channel]);
+        let total: u64 = colors.iter().map(|(_, count)| count).sum();
+        let mut seen = 0;
+        let split = colors
+            .iter()
+            .position(|(_, count)| {
+                seen += count;
+                seen * 2 >= total
+            })
+            .map_or(1, |i| (i + 1).clamp(1, colors.len() - 1));
+        let upper = colors.split_off(split);
+        boxes.push(colors);
+        boxes.push(upper);
+    }
+
+    boxes
+        .iter()
+        .map(|colors| {
+            let total: u64 = colors.iter().map(|(_, count)| count).sum();
+            let mut mean = [0u8; 4];
+            for (c, channel) in mean.iter_mut().enumerate() {
+                let sum: u64 = colors
+                    .iter()
+                    .map(|(color, count)| color[c] as u64 * count)
+                    .sum();
+                *channel = ((sum + total / 2) / total) as u8;
+            }
+            mean
+        })
+        .collect()
+}
+
+fn widest_channel(colors: &[(Rgba, u64)]) -> (usize, u8) {
+    (0..4)
+        .map(|c| {
+            let min = colors.iter().map(|(color, _)| color[c]).min().unwrap_or(0);
+            let max = colors.iter().map(|(color, _)| color[c]).max().unwrap_or(0);
+            (c, max - min)
+        })
+        .max_by_key(|&(_, range)| range)
+        .unwrap_or((0, 0))
+}
+
+fn nearest_index(palette: &[Rgba], color: &Rgba) -> u8 {
+    palette
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, entry)| {
+            (0..4)
+                .map(|c| {
+                    let d = entry[c] as i32 - color[c] as i32;
+                    d * d
+                })
+                .sum::<i32>()
+        })
+        .map_or(0, |(i, _)| i as u8)
+}
+
+fn pack(pixels: &[u8]) -> Vec<u8> {
+    pixels
+        .chunks(2)
+        .map(|pair| pair[0] << 4 | pair.get(1).copied().unwrap_or(0))
+        .collect()
+}
+
+fn unpack(data: &[u8], pixel_count: usize) -> Result<Vec<u8>, BitmapError> {
+    let expected = pixel_count.div_ceil(2);
+    if data.len() < expected {
+        return Err(BitmapError::Truncated);
+    }
+    if data.len() > expected {
+        return Err(BitmapError::TrailingBytes(data.len() - expected));
+    }
+    let mut pixels: Vec<u8> = data.iter().flat_map(|&b| [b >> 4, b & 0x0f]).collect();
+    pixels.truncate(pixel_count);
+    Ok(pixels)
+}
+
+fn run_length_encode(pixels: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut iter = pixels.iter().peekable();
+    while let Some(&index) = iter.next() {
+        let mut run = 1u8;
+        while run < 16 && iter.peek() == Some(&&index) {
+            iter.next();
+            run += 1;
+        }
+        out.push((run - 1) << 4 | index);
+    }
+    out
+}
+
+fn run_length_decode(data: &[u8], pixel_count: usize) -> Result<Vec<u8>, BitmapError> {
+    let mut pixels = Vec::with_capacity(pixel_count);
+    for (consumed, &byte) in data.iter().enumerate() {
+        if pixels.len() == pixel_count {
+            return Err(BitmapError::TrailingBytes(data.len() - consumed));
+        }
+        let run = (byte >> 4) as usize + 1;
+        if pixels.len() + run > pixel_count {
+            return Err(BitmapError::InvalidPixelData);
+        }
+        pixels.extend(std::iter::repeat_n(byte & 0x0f, run));
+    }
+    if pixels.len() < pixel_count {
+        return Err(BitmapError::Truncated);
+    }
+    Ok(pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgba = [255, 0, 0, 255];
+    const BLUE: Rgba = [0, 0, 255, 255];
+    const CLEAR: Rgba = [0, 0, 0, 0];
+
+    /// Square logo split into a red left half and a blue right half
+    fn halves(size: u32) -> Vec<u8> {
+        (0..size * size)
+            .flat_map(|i| if i % size < size / 2 { RED } else { BLUE })
+            .collect()
+    }
+
+    fn bitmap(size: u8, palette: Vec<Rgba>, pixels: Vec<u8>) -> Bitmap {
+        Bitmap { size, palette, pixels }
+    }
+
+    fn header(size: u8, encoding: u8, palette_len: u8) -> Vec<u8> {
+        let mut payload = vec![LOGO_BITMAP_VERSION, size, size, encoding, palette_len];
+        for _ in 0..palette_len {
+            payload.extend_from_slice(&RED);
+        }
+        payload
+    }
+
+    #[test]
+    fn round_trips_run_length_payloads() {
+        let original = Bitmap::from_rgba(64, 64, &halves(64), 16).unwrap();
+        assert_eq!(original.size, 16);
+        assert_eq!(original.palette.len(), 2);
+
+        let payload = original.encode();
+        assert_eq!(payload[3], LOGO_BITMAP_ENCODING_RLE);
+        assert!(payload.len() <= MAX_LOGO_BITMAP_BYTES);
+        let decoded = Bitmap::decode(&payload).unwrap();
+        assert_eq!(decoded, original);
+        assert_eq!(decoded.to_rgba(), halves(16));
+    }
+
+    #[test]
+    fn round_trips_packed_payloads() {
+        // Alternating pixels never repeat, so packing beats run lengths
+        let pixels: Vec<u8> = (0..32 * 32).map(|i| (i % 2) as u8).collect();
+        let original = bitmap(32, vec![RED, CLEAR], pixels);
+
+        let payload = original.encode();
+        assert_eq!(payload[3], LOGO_BITMAP_ENCODING_PACKED);
+        assert_eq!(payload.len(), LOGO_BITMAP_HEADER_LEN + 2 * 4 + 32 * 32 / 2);
+        assert_eq!(Bitmap::decode(&payload).unwrap(), original);
+    }
+
+    #[test]
+    fn long_runs_are_split_at_sixteen_pixels() {
+        let original = bitmap(16, vec![BLUE], vec![0; 16 * 16]);
+        let payload = original.encode();
+        assert_eq!(&payload[LOGO_BITMAP_HEADER_LEN + 4..], [0xf0; 16]);
+        assert_eq!(Bitmap::decode(&payload).unwrap(), original);
+    }
+
+    #[test]
+    fn round_trips_png_files() {
+        let png = write_png(64, 64, &halves(64)).unwrap();
+        assert_eq!(read_png(&png).unwrap(), (64, 64, halves(64)));
+
+        let payload = encode_png(&png, 32).unwrap();
+        let rendered = decode_to_png(&payload).unwrap();
+        assert_eq!(read_png(&rendered).unwrap(), (32, 32, halves(32)));
+        assert!(matches!(read_png(b"not a png"), Err(BitmapError::Png(_))));
+    }
+
+    #[test]
+    fn quantizes_to_the_palette_limit() {
+        // 64 distinct grays
+        let rgba: Vec<u8> = (0..64u8).flat_map(|i| [i * 4, i * 4, i * 4, 255]).collect();
+        let quantized = Bitmap::from_rgba(8, 8, &rgba, 16).unwrap();
+        assert_eq!(quantized.palette.len(), LOGO_BITMAP_MAX_COLORS);
+        assert!(Bitmap::decode(&quantized.encode()).is_ok());
+    }
+
+    #[test]
+    fn rejects_mismatched_pixel_buffers() {
+        assert!(matches!(Bitmap::from_rgba(2, 2, &[0; 15], 16), Err(BitmapError::Png(_))));
+        assert!(matches!(Bitmap::from_rgba(0, 2, &[], 16), Err(BitmapError::Png(_))));
+        // 65536 * 65536 * 4 wraps to 0 in u32 arithmetic
+        assert!(matches!(Bitmap::from_rgba(65_536, 65_536, &[], 16), Err(BitmapError::Png(_))));
+        assert!(matches!(Bitmap::from_rgba(u32::MAX, u32::MAX, &[], 16), Err(BitmapError::Png(_))));
+        assert!(matches!(
+            Bitmap::from_rgba(4, 4, &[0; 64], 24),
+            Err(BitmapError::UnsupportedSize(24))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_headers() {
+        assert!(matches!(Bitmap::decode(&[LOGO_BITMAP_VERSION, 16, 16, 0]), Err(BitmapError::Truncated)));
+
+        let mut wrong_version = header(16, LOGO_BITMAP_ENCODING_RLE, 1);
+        wrong_version[0] += 1;
+        let mut not_square = header(16, LOGO_BITMAP_ENCODING_RLE, 1);
+        not_square[2] = 32;
+        for payload in [
+            wrong_version,
+            not_square,
+            header(16, LOGO_BITMAP_ENCODING_RLE, 0),
+            header(16, LOGO_BITMAP_ENCODING_RLE, 17),
+            header(16, 2, 1),
+        ] {
+            assert!(matches!(Bitmap::decode(&payload), Err(BitmapError::InvalidHeader)));
+        }
+        assert!(matches!(
+            Bitmap::decode(&header(8, LOGO_BITMAP_ENCODING_RLE, 1)),
+            Err(BitmapError::UnsupportedSize(8))
+        ));
+
+        // The palette is cut short
+        let mut payload = header(16, LOGO_BITMAP_ENCODING_RLE, 2);
+        payload.truncate(LOGO_BITMAP_HEADER_LEN + 6);
+        assert!(matches!(Bitmap::decode(&payload), Err(BitmapError::Truncated)));
+    }
+
+    #[test]
+    fn rejects_malformed_packed_pixels() {
+        let mut payload = header(16, LOGO_BITMAP_ENCODING_PACKED, 1);
+        payload.extend_from_slice(&[0; 16 * 16 / 2 - 1]);
+        assert!(matches!(Bitmap::decode(&payload), Err(BitmapError::Truncated)));
+
+        payload.extend_from_slice(&[0, 0]);
+        assert!(matches!(Bitmap::decode(&payload), Err(BitmapError::TrailingBytes(1))));
+
+        // Index 1 with a single palette entry
+        payload.pop();
+        payload[LOGO_BITMAP_HEADER_LEN + 4] = 0x01;
+        assert!(matches!(Bitmap::decode(&payload), Err(BitmapError::PaletteIndexOutOfRange(1))));
+    }
+
+    #[test]
+    fn rejects_malformed_run_lengths() {
+        let mut payload = header(16, LOGO_BITMAP_ENCODING_RLE, 1);
+        payload.extend_from_slice(&[0xf0; 15]);
+        assert!(matches!(Bitmap::decode(&payload), Err(BitmapError::Truncated)));
+
+        // A run past the last pixel
+        let mut overflowing = payload.clone();
+        overflowing.extend_from_slice(&[0xf0, 0x10]);
+        assert!(matches!(Bitmap::decode(&overflowing), Err(BitmapError::TrailingBytes(1))));
+        let mut overflowing = payload.clone();
+        overflowing.push(0xe0);
+        overflowing.push(0x10);
+        assert!(matches!(Bitmap::decode(&overflowing), Err(BitmapError::InvalidPixelData)));
+
+        payload.push(0xf0);
+        assert!(Bitmap::decode(&payload).is_ok());
+        payload[LOGO_BITMAP_HEADER_LEN + 4] = 0xf3;
+        assert!(matches!(Bitmap::decode(&payload), Err(BitmapError::PaletteIndexOutOfRange(3))));
+    }
+
+    #[test]
+    fn decodes_only_complete_bitmap_accounts() {
+        let payload = bitmap(16, vec![BLUE], vec![0; 16 * 16]).encode();
+        let mut account = LogoBitmap {
+            coordinates: [0, 0],
+            placed_at: 0,
+            uploader: Default::default(),
+            total_len: payload.len() as u16,
+            data: payload[..LOGO_BITMAP_HEADER_LEN].to_vec(),
+            bump: 0,
+        };
+        assert!(matches!(
+            decode_logo_bitmap(&account),
+            Err(BitmapError::Incomplete { written: 5, .. })
+        ));
+
+        account.data = payload;
+        assert_eq!(decode_logo_bitmap(&account).unwrap().to_rgba(), [BLUE; 16 * 16].concat());
+    }
+}
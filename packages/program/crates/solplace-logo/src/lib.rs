@@ -0,0 +1,12 @@
+//! Off-chain logo utilities for SolPlace
+//!
+//! `place_logo` stores a SHA-256 of the logo image bytes in `LogoPlacement::logo_hash`.
+//! This crate computes that hash for clients and verifies served images against it.
+//! It also converts PNG logos to and from the compact payload of `LogoBitmap` accounts.
+//! Reading placements and bitmaps from chain is left to `solplace-client`.
+
+pub mod bitmap;
+pub mod verify;
+
+pub use bitmap::*;
+pub use verify::*;
@@ -0,0 +1,136 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use solplace_logo::*;
+
+#[derive(Parser)]
+#[command(name = "solplace-logo", about = "Hash and verify SolPlace logo images")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the SHA-256 to pass as `logo_hash` to `place_logo`
+    Hash {
+        /// Logo URL (https, ipfs://, ar://) or local file path
+        source: String,
+    },
+    /// Check a logo image against an expected hash
+    /// `solplace verify` checks against the on-chain hash of a placement
+    Verify {
+        /// Logo URL or local file path
+        source: String,
+        /// Expected hash in hex
+        #[arg(long)]
+        hash: String,
+    },
+    /// Convert logos to and from on-chain bitmap payloads
+    #[command(subcommand)]
+    Bitmap(BitmapCommand),
+}
+
+#[derive(Subcommand)]
+enum BitmapCommand {
+    /// Encode a PNG into a `write_logo_bitmap` payload
+    Encode {
+        /// Input PNG file
+        input: PathBuf,
+        /// Output payload file
+        #[arg(short, long)]
+        output: PathBuf,
+        /// Bitmap size in pixels (16 or 32)
+        #[arg(long, default_value_t = 32)]
+        size: u8,
+    },
+    /// Render a payload file as PNG
+    Decode {
+        /// Input payload file
+        input: PathBuf,
+        /// Output PNG file
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum CliError {
+    #[error(transparent)]
+    Verify(#[from] VerifyError),
+
+    #[error(transparent)]
+    Bitmap(#[from] BitmapError),
+
+    #[error("{path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+fn read_file(path: &PathBuf) -> Result<Vec<u8>, CliError> {
+    std::fs::read(path).map_err(|source| CliError::Io {
+        path: path.clone(),
+        source,
+    })
+}
+
+fn write_file(path: &PathBuf, contents: &[u8]) -> Result<(), CliError> {
+    std::fs::write(path, contents).map_err(|source| CliError::Io {
+        path: path.clone(),
+        source,
+    })
+}
+
+fn run(cli: Cli) -> Result<ExitCode, CliError> {
+    match cli.command {
+        Command::Hash { source } => {
+            let image = LogoSource::parse(&source).fetch()?;
+            println!("{}", hex::encode(logo_hash(&image)));
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Verify { source, hash } => {
+            let verification = verify_logo(&LogoSource::parse(&source), parse_logo_hash(&hash)?)?;
+            println!("{verification}");
+            if verification.matches() {
+                Ok(ExitCode::SUCCESS)
+            } else {
+                Ok(ExitCode::FAILURE)
+            }
+        }
+        Command::Bitmap(command) => {
+            run_bitmap(command)?;
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+fn run_bitmap(command: BitmapCommand) -> Result<(), CliError> {
+    match command {
+        BitmapCommand::Encode {
+            input,
+            output,
+            size,
+        } => {
+            let payload = encode_png(&read_file(&input)?, size)?;
+            write_file(&output, &payload)?;
+            println!("{} bytes", payload.len());
+        }
+        BitmapCommand::Decode { input, output } => {
+            write_file(&output, &decode_to_png(&read_file(&input)?)?)?;
+        }
+    }
+    Ok(())
+}
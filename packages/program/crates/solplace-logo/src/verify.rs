@@ -0,0 +1,221 @@
+use std::io::Read;
+use std::path::PathBuf;
+
+use sha2::{Digest, Sha256};
+
+/// Gateway used to fetch `ipfs://` logos
+pub const IPFS_GATEWAY: &str = "https://ipfs.io/ipfs/";
+/// Gateway used to fetch `ar://` logos
+pub const ARWEAVE_GATEWAY: &str = "https://arweave.net/";
+/// Largest logo body we are willing to download
+pub const MAX_LOGO_BYTES: u64 = 5 * 1024 * 1024;
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerifyError {
+    #[error("failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("failed to fetch {url}: {message}")]
+    Http { url: String, message: String },
+
+    #[error("logo is larger than {MAX_LOGO_BYTES} bytes")]
+    TooLarge,
+
+    #[error("invalid hash {0:?}: expected 64 hex characters")]
+    InvalidHash(String),
+}
+
+/// Where to read a logo image from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogoSource {
+    Url(String),
+    File(PathBuf),
+}
+
+impl LogoSource {
+    /// Parse a logo URI or local path
+    /// `ipfs://` and `ar://` URIs are resolved through the public gateways
+    pub fn parse(source: &str) -> Self {
+        if let Some(cid) = source.strip_prefix("ipfs://") {
+            LogoSource::Url(format!("{IPFS_GATEWAY}{cid}"))
+        } else if let Some(tx_id) = source.strip_prefix("ar://") {
+            LogoSource::Url(format!("{ARWEAVE_GATEWAY}{tx_id}"))
+        } else if source.starts_with("https://") || source.starts_with("http://") {
+            LogoSource::Url(source.to_string())
+        } else {
+            LogoSource::File(PathBuf::from(source))
+        }
+    }
+
+    /// Read the raw image bytes
+    pub fn fetch(&self) -> Result<Vec<u8>, VerifyError> {
+        let mut bytes = Vec::new();
+        match self {
+            LogoSource::File(path) => {
+                let file = std::fs::File::open(path).map_err(|source| VerifyError::Io {
+                    path: path.clone(),
+                    source,
+                })?;
+                file.take(MAX_LOGO_BYTES + 1)
+                    .read_to_end(&mut bytes)
+                    .map_err(|source| VerifyError::Io {
+                        path: path.clone(),
+                        source,
+                    })?;
+            }
+            LogoSource::Url(url) => {
+                let http_error = |message: String| VerifyError::Http {
+                    url: url.clone(),
+                    message,
+                };
+                let response = ureq::get(url)
+                    .call()
+                    .map_err(|err| http_error(err.to_string()))?;
+                response
+                    .into_reader()
+                    .take(MAX_LOGO_BYTES + 1)
+                    .read_to_end(&mut bytes)
+                    .map_err(|err| http_error(err.to_string()))?;
+            }
+        }
+
+        if bytes.len() as u64 > MAX_LOGO_BYTES {
+            return Err(VerifyError::TooLarge);
+        }
+        Ok(bytes)
+    }
+}
+
+/// Result of comparing a logo body against an expected hash
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verification {
+    pub expected: [u8; 32],
+    pub actual: [u8; 32],
+}
+
+impl Verification {
+    pub fn matches(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+impl std::fmt::Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.matches() {
+            write!(f, "OK {}", hex::encode(self.actual))
+        } else {
+            write!(
+                f,
+                "MISMATCH expected {} got {}",
+                hex::encode(self.expected),
+                hex::encode(self.actual)
+            )
+        }
+    }
+}
+
+/// SHA-256 of the logo image bytes, as passed to `place_logo`
+pub fn logo_hash(image: &[u8]) -> [u8; 32] {
+    Sha256::digest(image).into()
+}
+
+/// Parse a hex-encoded logo hash
+pub fn parse_logo_hash(hex_hash: &str) -> Result<[u8; 32], VerifyError> {
+    let mut hash = [0u8; 32];
+    hex::decode_to_slice(hex_hash.trim(), &mut hash)
+        .map_err(|_| VerifyError::InvalidHash(hex_hash.to_string()))?;
+    Ok(hash)
+}
+
+/// Fetch a logo and compare its bytes against `expected`
+pub fn verify_logo(source: &LogoSource, expected: [u8; 32]) -> Result<Verification, VerifyError> {
+    let image = source.fetch()?;
+    Ok(Verification {
+        expected,
+        actual: logo_hash(&image),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // SHA-256 of "abc", FIPS 180-2 test vector
+    const ABC_HASH: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+    fn temp_file(name: &str, contents: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("solplace-logo-{}-{name}", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn parses_logo_sources() {
+        let cases = [
+            ("https://example.com/logo.png", LogoSource::Url("https://example.com/logo.png".into())),
+            ("http://example.com/logo.png", LogoSource::Url("http://example.com/logo.png".into())),
+            ("ipfs://bafy/logo.png", LogoSource::Url(format!("{IPFS_GATEWAY}bafy/logo.png"))),
+            ("ar://tx-id", LogoSource::Url(format!("{ARWEAVE_GATEWAY}tx-id"))),
+            ("./logo.png", LogoSource::File("./logo.png".into())),
+            ("ftp://example.com/logo.png", LogoSource::File("ftp://example.com/logo.png".into())),
+        ];
+        for (source, expected) in cases {
+            assert_eq!(LogoSource::parse(source), expected, "{source}");
+        }
+    }
+
+    #[test]
+    fn hashes_image_bytes() {
+        assert_eq!(hex::encode(logo_hash(b"abc")), ABC_HASH);
+        assert_eq!(parse_logo_hash(ABC_HASH).unwrap(), logo_hash(b"abc"));
+        // Surrounding whitespace and uppercase hex are accepted
+        assert_eq!(
+            parse_logo_hash(&format!(" {}\n", ABC_HASH.to_uppercase())).unwrap(),
+            logo_hash(b"abc")
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_hashes() {
+        for hash in ["", "abc", &ABC_HASH[2..], &format!("{ABC_HASH}00"), &ABC_HASH.replace('b', "g")] {
+            assert!(
+                matches!(parse_logo_hash(hash), Err(VerifyError::InvalidHash(_))),
+                "{hash}"
+            );
+        }
+    }
+
+    #[test]
+    fn verifies_local_files() {
+        let path = temp_file("verify.png", b"abc");
+        let source = LogoSource::File(path.clone());
+
+        let verification = verify_logo(&source, parse_logo_hash(ABC_HASH).unwrap()).unwrap();
+        assert!(verification.matches());
+        assert_eq!(verification.to_string(), format!("OK {ABC_HASH}"));
+
+        let verification = verify_logo(&source, [0; 32]).unwrap();
+        assert!(!verification.matches());
+        assert_eq!(
+            verification.to_string(),
+            format!("MISMATCH expected {} got {ABC_HASH}", hex::encode([0; 32]))
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn refuses_oversized_and_missing_files() {
+        let path = temp_file("large.png", &vec![0; MAX_LOGO_BYTES as usize + 1]);
+        assert!(matches!(LogoSource::File(path.clone()).fetch(), Err(VerifyError::TooLarge)));
+        std::fs::remove_file(&path).unwrap();
+
+        let path = temp_file("limit.png", &vec![0; MAX_LOGO_BYTES as usize]);
+        assert_eq!(LogoSource::File(path.clone()).fetch().unwrap().len() as u64, MAX_LOGO_BYTES);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(LogoSource::File(path).fetch(), Err(VerifyError::Io { .. })));
+    }
+}
@@ -0,0 +1,94 @@
+use solplace_logo::{write_png, BitmapError, Rgba};
+
+/// RGBA image composited with straight (non-premultiplied) alpha
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<Rgba>,
+}
+
+impl Canvas {
+    pub fn new(width: u32, height: u32, background: Rgba) -> Self {
+        Canvas {
+            width,
+            height,
+            pixels: vec![background; width as usize * height as usize],
+        }
+    }
+
+    pub fn pixel(&self, x: u32, y: u32) -> Rgba {
+        self.pixels[(y * self.width + x) as usize]
+    }
+
+    /// Blend a `size` square of pixels over the canvas with its top left at `(x, y)`,
+    /// clipping what falls outside
+    pub fn draw(&mut self, x: i64, y: i64, size: u32, square: &[Rgba]) {
+        for row in 0..size {
+            let canvas_y = y + i64::from(row);
+            if canvas_y < 0 || canvas_y >= i64::from(self.height) {
+                continue;
+            }
+            for column in 0..size {
+                let canvas_x = x + i64::from(column);
+                if canvas_x < 0 || canvas_x >= i64::from(self.width) {
+                    continue;
+                }
+                let index = canvas_y as usize * self.width as usize + canvas_x as usize;
+                let source = square[(row * size + column) as usize];
+                self.pixels[index] = blend(source, self.pixels[index]);
+            }
+        }
+    }
+
+    pub fn to_png(&self) -> Result<Vec<u8>, BitmapError> {
+        let rgba: Vec<u8> = self.pixels.iter().flatten().copied().collect();
+        write_png(self.width, self.height, &rgba)
+    }
+}
+
+/// Porter-Duff "source over destination"
+fn blend(source: Rgba, destination: Rgba) -> Rgba {
+    let source_alpha = u32::from(source[3]);
+    if source_alpha == 255 {
+        return source;
+    }
+    if source_alpha == 0 {
+        return destination;
+    }
+    // Work in 0..=255 * 255 to keep integer precision
+    let destination_alpha = u32::from(destination[3]) * (255 - source_alpha) / 255;
+    let alpha = source_alpha + destination_alpha;
+    let mut out = [0, 0, 0, alpha as u8];
+    for channel in 0..3 {
+        out[channel] = ((u32::from(source[channel]) * source_alpha
+            + u32::from(destination[channel]) * destination_alpha)
+            / alpha) as u8;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blends_and_clips() {
+        let mut canvas = Canvas::new(2, 2, [255, 255, 255, 255]);
+        // Only the top right pixel of the square lands on the canvas
+        canvas.draw(
+            -1,
+            1,
+            2,
+            &[[0; 4], [255, 0, 0, 128], [0, 0, 0, 255], [0, 0, 0, 255]],
+        );
+        assert_eq!(canvas.pixel(0, 0), [255, 255, 255, 255]);
+        assert_eq!(canvas.pixel(0, 1), [255, 127, 127, 255]);
+        assert_eq!(canvas.pixel(1, 1), [255, 255, 255, 255]);
+
+        // Over a transparent canvas the source is kept as is
+        let mut clear = Canvas::new(1, 1, [0; 4]);
+        clear.draw(0, 0, 1, &[[10, 20, 30, 40]]);
+        assert_eq!(clear.pixel(0, 0), [10, 20, 30, 40]);
+    }
+}
@@ -0,0 +1,28 @@
+use std::path::PathBuf;
+
+use solplace_indexer::IndexerError;
+use solplace_logo::BitmapError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum RenderError {
+    #[error("{path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error(transparent)]
+    Png(#[from] BitmapError),
+
+    #[error(transparent)]
+    Indexer(#[from] IndexerError),
+
+    #[error("invalid account dump: {0}")]
+    InvalidDump(String),
+
+    #[error("{width}x{height} image exceeds the {MAX_IMAGE_EDGE} pixel limit, lower the zoom")]
+    TooLarge { width: u64, height: u64 },
+}
+
+/// Longest edge of a single rendered image
+pub const MAX_IMAGE_EDGE: u64 = 16_384;
@@ -0,0 +1,18 @@
+//! Map snapshot renderer for SolPlace
+//!
+//! Projects `LogoPlacement`s read from an indexer store or an account dump to Web
+//! Mercator and composites their logos into PNGs: a single image for a bounding box,
+//! or an XYZ tile pyramid. Logos come from a content-addressed cache directory and
+//! are checked against the on-chain `logo_hash`, so rendering works offline.
+
+pub mod canvas;
+pub mod error;
+pub mod logos;
+pub mod render;
+pub mod source;
+
+pub use canvas::*;
+pub use error::*;
+pub use logos::*;
+pub use render::*;
+pub use source::*;
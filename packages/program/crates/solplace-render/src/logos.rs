@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use program::state::LogoPlacement;
+use solplace_logo::{logo_hash, read_png, resize, LogoSource, Rgba, VerifyError};
+
+use crate::*;
+
+/// Logo pixels scaled to a square
+pub type Logo = Rc<Vec<Rgba>>;
+
+/// Directory of logo images named by the hex SHA-256 of their bytes
+///
+/// The name is the `logo_hash` stored on chain, so a file that does not hash to its
+/// name is ignored rather than rendered. Decoded and scaled logos are memoized.
+pub struct LogoCache {
+    dir: PathBuf,
+    scaled: HashMap<([u8; 32], u32), Option<Logo>>,
+}
+
+impl LogoCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        LogoCache {
+            dir: dir.into(),
+            scaled: HashMap::new(),
+        }
+    }
+
+    pub fn path(&self, hash: &[u8; 32]) -> PathBuf {
+        self.dir.join(format!("{}.png", hex::encode(hash)))
+    }
+
+    /// The logo with `hash` scaled to a `size` square, `None` when missing or unreadable
+    pub fn scaled(&mut self, hash: &[u8; 32], size: u32) -> Option<Logo> {
+        if let Some(scaled) = self.scaled.get(&(*hash, size)) {
+            return scaled.clone();
+        }
+        let scaled = self
+            .read(hash)
+            .map(|(width, height, rgba)| Rc::new(resize(width, height, &rgba, size)));
+        self.scaled.insert((*hash, size), scaled.clone());
+        scaled
+    }
+
+    fn read(&self, hash: &[u8; 32]) -> Option<(u32, u32, Vec<u8>)> {
+        let bytes = std::fs::read(self.path(hash)).ok()?;
+        if logo_hash(&bytes) != *hash {
+            return None;
+        }
+        read_png(&bytes).ok()
+    }
+
+    /// Download the logos of `placements` missing from the cache
+    ///
+    /// Only images matching their placement's `logo_hash` are stored. Returns the
+    /// logos that could not be cached, with the reason.
+    pub fn fetch_missing(
+        &mut self,
+        placements: &[LogoPlacement],
+    ) -> Result<Vec<(String, String)>, RenderError> {
+        std::fs::create_dir_all(&self.dir).map_err(|source| RenderError::Io {
+            path: self.dir.clone(),
+            source,
+        })?;
+
+        let mut failed = Vec::new();
+        let mut seen = std::collections::HashSet::new();
+        for placement in placements {
+            let path = self.path(&placement.logo_hash);
+            if !seen.insert(placement.logo_hash) || path.exists() {
+                continue;
+            }
+            match fetch_verified(&placement.logo_uri, &placement.logo_hash) {
+                Ok(bytes) => write(&path, &bytes)?,
+                Err(message) => failed.push((placement.logo_uri.clone(), message)),
+            }
+        }
+        // Newly cached logos replace memoized misses
+        self.scaled.retain(|_, scaled| scaled.is_some());
+        Ok(failed)
+    }
+}
+
+fn fetch_verified(uri: &str, expected: &[u8; 32]) -> Result<Vec<u8>, String> {
+    let bytes = LogoSource::parse(uri)
+        .fetch()
+        .map_err(|err: VerifyError| err.to_string())?;
+    if logo_hash(&bytes) != *expected {
+        return Err("image does not match the on-chain logo hash".to_string());
+    }
+    read_png(&bytes).map_err(|err| err.to_string())?;
+    Ok(bytes)
+}
+
+fn write(path: &Path, bytes: &[u8]) -> Result<(), RenderError> {
+    std::fs::write(path, bytes).map_err(|source| RenderError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
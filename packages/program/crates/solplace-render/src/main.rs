@@ -0,0 +1,149 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+use program::state::LogoPlacement;
+use solplace_client::BoundingBox;
+use solplace_indexer::Store;
+use solplace_render::*;
+
+#[derive(Parser)]
+#[command(
+    name = "solplace-render",
+    about = "Render SolPlace map snapshots to PNG"
+)]
+struct Cli {
+    #[command(flatten)]
+    source: Source,
+    /// Logo cache, PNGs named by the hex SHA-256 of their bytes
+    #[arg(long, global = true, default_value = "logos")]
+    logos: PathBuf,
+    /// Edge of a logo marker in pixels
+    #[arg(long, global = true, default_value_t = DEFAULT_LOGO_SIZE)]
+    logo_size: u32,
+    /// Download logos missing from the cache before rendering
+    #[arg(long, global = true)]
+    fetch: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+struct Source {
+    /// SQLite database written by solplace-indexer
+    #[arg(long, global = true)]
+    db: Option<PathBuf>,
+    /// `getProgramAccounts` response saved with base64 encoding
+    #[arg(long, global = true)]
+    accounts: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Render one image of a bounding box
+    Bbox {
+        /// `min_lat,min_lng,max_lat,max_lng` in microdegrees
+        #[arg(long)]
+        bbox: BoundingBox,
+        #[arg(long)]
+        zoom: u8,
+        /// PNG file to write
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Render an XYZ tile pyramid to `out/z/x/y.png`
+    Tiles {
+        #[arg(long, default_value_t = 0)]
+        min_zoom: u8,
+        #[arg(long)]
+        max_zoom: u8,
+        /// Only render placements inside `min_lat,min_lng,max_lat,max_lng`
+        #[arg(long)]
+        bbox: Option<BoundingBox>,
+        #[arg(long)]
+        out: PathBuf,
+    },
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum CliError {
+    #[error(transparent)]
+    Render(#[from] RenderError),
+
+    #[error("{path}: {message}")]
+    Dump { path: PathBuf, message: String },
+
+    #[error("zoom must be at most {MAX_ZOOM}")]
+    Zoom,
+}
+
+fn run(cli: Cli) -> Result<(), CliError> {
+    let bbox = match &cli.command {
+        Command::Bbox { bbox, .. } => *bbox,
+        Command::Tiles { bbox, .. } => bbox.unwrap_or(BoundingBox::WORLD),
+    };
+    let placements = load(&cli.source, &bbox)?;
+
+    let mut renderer = Renderer::new(LogoCache::new(&cli.logos));
+    renderer.logo_size = cli.logo_size;
+    if cli.fetch {
+        for (uri, reason) in renderer.logos.fetch_missing(&placements)? {
+            eprintln!("warning: {uri}: {reason}");
+        }
+    }
+
+    match cli.command {
+        Command::Bbox { bbox, zoom, out } => {
+            if zoom > MAX_ZOOM {
+                return Err(CliError::Zoom);
+            }
+            let canvas = renderer.render_bbox(&placements, &bbox, zoom)?;
+            let png = canvas.to_png().map_err(RenderError::from)?;
+            std::fs::write(&out, png).map_err(|source| RenderError::Io { path: out, source })?;
+        }
+        Command::Tiles {
+            min_zoom,
+            max_zoom,
+            out,
+            ..
+        } => {
+            if max_zoom > MAX_ZOOM {
+                return Err(CliError::Zoom);
+            }
+            let written = renderer.render_pyramid(&placements, min_zoom, max_zoom, &out)?;
+            eprintln!("wrote {written} tiles to {}", out.display());
+        }
+    }
+    Ok(())
+}
+
+fn load(source: &Source, bbox: &BoundingBox) -> Result<Vec<LogoPlacement>, CliError> {
+    if let Some(db) = &source.db {
+        let store = Store::open(db).map_err(RenderError::from)?;
+        return Ok(placements_from_store(&store, bbox)?);
+    }
+
+    let path = source.accounts.clone().expect("clap requires a source");
+    let dump = std::fs::read_to_string(&path)
+        .map_err(|err| err.to_string())
+        .and_then(|json| serde_json::from_str(&json).map_err(|err| err.to_string()))
+        .map_err(|message| CliError::Dump {
+            path: path.clone(),
+            message,
+        })?;
+    Ok(placements_from_dump(&dump)?
+        .into_iter()
+        .filter(|placement| bbox.contains(placement.coordinates[0], placement.coordinates[1]))
+        .collect())
+}
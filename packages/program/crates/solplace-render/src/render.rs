@@ -0,0 +1,188 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::rc::Rc;
+
+use program::state::LogoPlacement;
+use solplace_client::{project, BoundingBox, Tile, TILE_SIZE};
+use solplace_logo::Rgba;
+
+use crate::*;
+
+/// Edge of a logo marker in pixels, at every zoom
+pub const DEFAULT_LOGO_SIZE: u32 = 32;
+/// Deepest zoom rendered, where a 256 pixel tile spans about 10 meters
+pub const MAX_ZOOM: u8 = 22;
+
+/// Composites placements onto Web Mercator images
+///
+/// Each placement is drawn as a `logo_size` square centered on its cell, oldest
+/// first so the latest placement ends up on top. Logos missing from the cache are
+/// drawn as a square colored after the mint.
+pub struct Renderer {
+    pub logos: LogoCache,
+    pub logo_size: u32,
+    pub background: Rgba,
+}
+
+impl Renderer {
+    pub fn new(logos: LogoCache) -> Self {
+        Renderer {
+            logos,
+            logo_size: DEFAULT_LOGO_SIZE,
+            background: [0; 4],
+        }
+    }
+
+    /// Image of `bbox` at `zoom`
+    /// A box crossing the antimeridian is rendered as one continuous image
+    pub fn render_bbox(
+        &mut self,
+        placements: &[LogoPlacement],
+        bbox: &BoundingBox,
+        zoom: u8,
+    ) -> Result<Canvas, RenderError> {
+        let (left, top) = project(bbox.max_lat, bbox.min_lng, zoom);
+        let (mut right, bottom) = project(bbox.min_lat, bbox.max_lng, zoom);
+        if bbox.min_lng > bbox.max_lng {
+            right += world_size(zoom);
+        }
+        let (left, top) = (left.floor(), top.floor());
+        let width = ((right - left).ceil() as u64).max(1);
+        let height = ((bottom - top).ceil() as u64).max(1);
+        if width > MAX_IMAGE_EDGE || height > MAX_IMAGE_EDGE {
+            return Err(RenderError::TooLarge { width, height });
+        }
+
+        let mut canvas = Canvas::new(width as u32, height as u32, self.background);
+        self.draw_all(&mut canvas, sorted(placements.iter()), (left, top), zoom);
+        Ok(canvas)
+    }
+
+    /// One `TILE_SIZE` XYZ tile
+    pub fn render_tile(&mut self, placements: &[LogoPlacement], tile: Tile) -> Canvas {
+        let mut canvas = Canvas::new(TILE_SIZE, TILE_SIZE, self.background);
+        self.draw_tile(&mut canvas, sorted(placements.iter()), tile);
+        canvas
+    }
+
+    /// Write `out_dir/z/x/y.png` for every tile from `min_zoom` to `max_zoom` a marker
+    /// shows on, and return the number of tiles written
+    ///
+    /// Tiles without markers are left out; map clients show them as empty.
+    pub fn render_pyramid(
+        &mut self,
+        placements: &[LogoPlacement],
+        min_zoom: u8,
+        max_zoom: u8,
+        out_dir: &Path,
+    ) -> Result<usize, RenderError> {
+        let placements = sorted(placements.iter());
+        let mut written = 0;
+        for zoom in min_zoom..=max_zoom {
+            for (tile, placements) in self.tiles_touched(&placements, zoom) {
+                let mut canvas = Canvas::new(TILE_SIZE, TILE_SIZE, self.background);
+                self.draw_tile(&mut canvas, placements, tile);
+
+                let dir = out_dir.join(tile.z.to_string()).join(tile.x.to_string());
+                std::fs::create_dir_all(&dir).map_err(|source| RenderError::Io {
+                    path: dir.clone(),
+                    source,
+                })?;
+                let path = dir.join(format!("{}.png", tile.y));
+                std::fs::write(&path, canvas.to_png()?)
+                    .map_err(|source| RenderError::Io { path, source })?;
+                written += 1;
+            }
+        }
+        Ok(written)
+    }
+
+    /// Tiles at `zoom` each marker overlaps, with the markers on them in drawing order
+    fn tiles_touched<'a>(
+        &self,
+        placements: &[&'a LogoPlacement],
+        zoom: u8,
+    ) -> BTreeMap<Tile, Vec<&'a LogoPlacement>> {
+        let tiles_per_edge = 1i64 << zoom;
+        let tile_size = f64::from(TILE_SIZE);
+        let half = f64::from(self.logo_size) / 2.0;
+
+        let mut tiles: BTreeMap<Tile, Vec<&LogoPlacement>> = BTreeMap::new();
+        for &placement in placements {
+            let (x, y) = project(placement.coordinates[0], placement.coordinates[1], zoom);
+            let first_x = ((x - half) / tile_size).floor() as i64;
+            let last_x = ((x + half) / tile_size).floor() as i64;
+            let first_y = (((y - half) / tile_size).floor() as i64).max(0);
+            let last_y = (((y + half) / tile_size).floor() as i64).min(tiles_per_edge - 1);
+            for tile_y in first_y..=last_y {
+                // Markers straddling the antimeridian show on both edges of the map
+                for tile_x in first_x..=last_x.min(first_x + tiles_per_edge - 1) {
+                    let tile = Tile {
+                        z: zoom,
+                        x: tile_x.rem_euclid(tiles_per_edge) as u32,
+                        y: tile_y as u32,
+                    };
+                    tiles.entry(tile).or_default().push(placement);
+                }
+            }
+        }
+        tiles
+    }
+
+    fn draw_tile(&mut self, canvas: &mut Canvas, placements: Vec<&LogoPlacement>, tile: Tile) {
+        let origin = (
+            f64::from(tile.x) * f64::from(TILE_SIZE),
+            f64::from(tile.y) * f64::from(TILE_SIZE),
+        );
+        self.draw_all(canvas, placements, origin, tile.z);
+    }
+
+    /// Draw `placements` onto `canvas`, whose top left is at `origin` on the world map
+    fn draw_all(
+        &mut self,
+        canvas: &mut Canvas,
+        placements: Vec<&LogoPlacement>,
+        origin: (f64, f64),
+        zoom: u8,
+    ) {
+        let world = world_size(zoom);
+        let size = self.logo_size;
+        let half = f64::from(size) / 2.0;
+        for placement in placements {
+            let (x, y) = project(placement.coordinates[0], placement.coordinates[1], zoom);
+            let top = (y - origin.1 - half).round() as i64;
+            // The same cell also appears one world over on either side of the antimeridian
+            let lefts: Vec<i64> = [x - world, x, x + world]
+                .into_iter()
+                .map(|x| (x - origin.0 - half).round() as i64)
+                .filter(|&left| left < i64::from(canvas.width) && left + i64::from(size) > 0)
+                .collect();
+            if lefts.is_empty() || top >= i64::from(canvas.height) || top + i64::from(size) <= 0 {
+                continue;
+            }
+
+            let square = self
+                .logos
+                .scaled(&placement.logo_hash, size)
+                .unwrap_or_else(|| {
+                    let color = placeholder_color(&placement.token_mint);
+                    Rc::new(vec![color; (size * size) as usize])
+                });
+            for left in lefts {
+                canvas.draw(left, top, size, &square);
+            }
+        }
+    }
+}
+
+/// Width of the world map at `zoom` in pixels
+fn world_size(zoom: u8) -> f64 {
+    f64::from(TILE_SIZE) * (1u64 << zoom) as f64
+}
+
+/// Visible placements, oldest first
+fn sorted<'a>(placements: impl Iterator<Item = &'a LogoPlacement>) -> Vec<&'a LogoPlacement> {
+    let mut placements: Vec<_> = placements.filter(|p| !p.is_blank()).collect();
+    placements.sort_by_key(|p| (p.placed_at, p.coordinates));
+    placements
+}
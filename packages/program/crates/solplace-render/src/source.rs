@@ -0,0 +1,42 @@
+use anchor_lang::prelude::Pubkey;
+use program::state::LogoPlacement;
+use solplace_client::{parse_program_accounts, visible_logo_placements, BoundingBox};
+use solplace_indexer::Store;
+
+use crate::*;
+
+/// Current placements inside `bbox` according to an indexer store
+///
+/// The store drops taken down cells and leaves hidden ones out of `cells_in`; blank cells
+/// are skipped too.
+pub fn placements_from_store(
+    store: &Store,
+    bbox: &BoundingBox,
+) -> Result<Vec<LogoPlacement>, RenderError> {
+    Ok(store
+        .cells_in(bbox)?
+        .into_iter()
+        .map(LogoPlacement::from)
+        .filter(|placement| !placement.is_blank())
+        .collect())
+}
+
+/// `LogoPlacement`s of a `getProgramAccounts` dump in `base64` encoding
+///
+/// Takes the RPC response or its `result` array. Other account types are skipped,
+/// as are placements a moderator blanked and those the dump's report tallies hide.
+pub fn placements_from_dump(dump: &serde_json::Value) -> Result<Vec<LogoPlacement>, RenderError> {
+    let invalid = |err: solplace_client::ClientError| RenderError::InvalidDump(err.to_string());
+    let accounts = parse_program_accounts(dump).map_err(invalid)?;
+    Ok(visible_logo_placements(accounts)
+        .map_err(invalid)?
+        .into_iter()
+        .map(|(_, placement)| placement)
+        .collect())
+}
+
+/// Stable color for a mint whose logo is unavailable
+pub fn placeholder_color(token_mint: &Pubkey) -> [u8; 4] {
+    let bytes = token_mint.to_bytes();
+    [bytes[0], bytes[1], bytes[2], 255]
+}
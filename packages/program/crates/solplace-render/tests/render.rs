@@ -0,0 +1,219 @@
+//! Renders the fixture account dump with the cached fixture logos
+
+use std::path::PathBuf;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::hash;
+use program::state::LogoPlacement;
+use solplace_client::{get_logo_placement_pda, project, BoundingBox};
+use solplace_indexer::{CellStatus, CellStatusEvent, IndexedTransaction, Store};
+use solplace_logo::read_png;
+use solplace_render::*;
+
+const NEW_YORK: BoundingBox = BoundingBox {
+    min_lat: 40_690_000,
+    min_lng: -74_030_000,
+    max_lat: 40_780_000,
+    max_lng: -73_960_000,
+};
+const RED: [u8; 4] = [255, 0, 0, 255];
+const BLUE: [u8; 4] = [0, 0, 255, 255];
+const CLEAR: [u8; 4] = [0; 4];
+
+/// Fixture accounts are sha256 of a name
+fn key(name: &str) -> Pubkey {
+    Pubkey::new_from_array(hash(name.as_bytes()).to_bytes())
+}
+
+fn fixture(path: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(path)
+}
+
+fn placements() -> Vec<LogoPlacement> {
+    let dump = serde_json::from_str(&std::fs::read_to_string(fixture("accounts.json")).unwrap());
+    placements_from_dump(&dump.unwrap()).unwrap()
+}
+
+fn renderer(logos: PathBuf) -> Renderer {
+    Renderer::new(LogoCache::new(logos))
+}
+
+/// Empty scratch directory unique to a test
+fn scratch(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("solplace-render-{}-{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Pixel of `(lat, lng)` on an image of `bbox` at `zoom`
+fn pixel_of(bbox: &BoundingBox, zoom: u8, lat: i32, lng: i32) -> (u32, u32) {
+    let (left, top) = project(bbox.max_lat, bbox.min_lng, zoom);
+    let (x, y) = project(lat, lng, zoom);
+    ((x - left.floor()) as u32, (y - top.floor()) as u32)
+}
+
+#[test]
+fn reads_placements_from_an_account_dump() {
+    let placements = placements();
+    // The blanked Tokyo cell is left out
+    let mints: Vec<_> = placements.iter().map(|p| p.token_mint).collect();
+    assert_eq!(mints, [key("bonk"), key("wif"), key("pepe")]);
+    assert_eq!(placements[0].coordinates, [40_712_800, -74_006_000]);
+
+    let not_a_dump = serde_json::json!({ "result": { "value": null } });
+    assert!(matches!(
+        placements_from_dump(&not_a_dump),
+        Err(RenderError::InvalidDump(_))
+    ));
+}
+
+#[test]
+fn renders_cached_logos_in_a_bbox() {
+    let canvas = renderer(fixture("logos"))
+        .render_bbox(&placements(), &NEW_YORK, 12)
+        .unwrap();
+    assert_eq!((canvas.width, canvas.height), (205, 347));
+
+    let (x, y) = pixel_of(&NEW_YORK, 12, 40_712_800, -74_006_000);
+    assert_eq!(canvas.pixel(x, y), RED);
+    // wif is blue on its left half and see-through on its right half
+    let (x, y) = pixel_of(&NEW_YORK, 12, 40_758_000, -73_985_500);
+    assert_eq!(canvas.pixel(x - 8, y), BLUE);
+    assert_eq!(canvas.pixel(x + 8, y), CLEAR);
+    assert_eq!(canvas.pixel(0, 0), CLEAR);
+
+    // The image round-trips through PNG
+    let (width, height, rgba) = read_png(&canvas.to_png().unwrap()).unwrap();
+    assert_eq!((width, height), (205, 347));
+    assert_eq!(rgba.len(), 205 * 347 * 4);
+}
+
+#[test]
+fn draws_placeholders_for_missing_or_tampered_logos() {
+    let paris = BoundingBox {
+        min_lat: 48_850_000,
+        min_lng: 2_280_000,
+        max_lat: 48_870_000,
+        max_lng: 2_310_000,
+    };
+    let canvas = renderer(fixture("logos"))
+        .render_bbox(&placements(), &paris, 14)
+        .unwrap();
+    let (x, y) = pixel_of(&paris, 14, 48_858_400, 2_294_500);
+    assert_eq!(canvas.pixel(x, y), placeholder_color(&key("pepe")));
+
+    // A cache file that does not hash to its name is ignored
+    let logos = scratch("tampered");
+    for entry in std::fs::read_dir(fixture("logos")).unwrap() {
+        let path = entry.unwrap().path();
+        std::fs::copy(&path, logos.join(path.file_name().unwrap())).unwrap();
+    }
+    let bonk = placements()[0].logo_hash;
+    let wif = placements()[1].logo_hash;
+    std::fs::copy(
+        logos.join(format!("{}.png", hex::encode(wif))),
+        logos.join(format!("{}.png", hex::encode(bonk))),
+    )
+    .unwrap();
+
+    let canvas = renderer(logos)
+        .render_bbox(&placements(), &NEW_YORK, 12)
+        .unwrap();
+    let (x, y) = pixel_of(&NEW_YORK, 12, 40_712_800, -74_006_000);
+    assert_eq!(canvas.pixel(x, y), placeholder_color(&key("bonk")));
+}
+
+#[test]
+fn writes_an_xyz_pyramid() {
+    let out = scratch("pyramid");
+    let written = renderer(fixture("logos"))
+        .render_pyramid(&placements(), 0, 3, &out)
+        .unwrap();
+
+    let mut tiles: Vec<String> = Vec::new();
+    for z in std::fs::read_dir(&out).unwrap() {
+        for x in std::fs::read_dir(z.unwrap().path()).unwrap() {
+            for y in std::fs::read_dir(x.unwrap().path()).unwrap() {
+                let path = y.unwrap().path();
+                tiles.push(path.strip_prefix(&out).unwrap().display().to_string());
+            }
+        }
+    }
+    tiles.sort();
+    assert_eq!(written, tiles.len());
+    // Markers spill into the neighbouring tiles they overlap
+    assert_eq!(
+        tiles,
+        [
+            "0/0/0.png",
+            "1/0/0.png",
+            "1/1/0.png",
+            "2/1/1.png",
+            "2/2/1.png",
+            "3/2/2.png",
+            "3/2/3.png",
+            "3/3/2.png",
+            "3/4/2.png"
+        ]
+    );
+
+    let (width, height, rgba) = read_png(&std::fs::read(out.join("0/0/0.png")).unwrap()).unwrap();
+    assert_eq!((width, height), (256, 256));
+    let (x, y) = project(40_712_800, -74_006_000, 0);
+    let index = (y as usize * 256 + x as usize) * 4;
+    assert_eq!(rgba[index..index + 4], RED);
+}
+
+#[test]
+fn renders_the_indexed_map() {
+    let mut store = Store::open_in_memory().unwrap();
+    assert_eq!(store.import_placements(300, &placements()).unwrap(), 3);
+
+    let indexed = placements_from_store(&store, &NEW_YORK).unwrap();
+    assert_eq!(indexed.len(), 2);
+    let mut renderer = renderer(fixture("logos"));
+    assert_eq!(
+        renderer.render_bbox(&indexed, &NEW_YORK, 12).unwrap(),
+        renderer.render_bbox(&placements(), &NEW_YORK, 12).unwrap()
+    );
+
+    assert!(matches!(
+        renderer.render_bbox(&indexed, &BoundingBox::WORLD, 8),
+        Err(RenderError::TooLarge {
+            width: 65_536,
+            height: _
+        })
+    ));
+}
+
+#[test]
+fn skips_taken_down_cells() {
+    let mut store = Store::open_in_memory().unwrap();
+    store.import_placements(300, &placements()).unwrap();
+    let taken_down = &placements_from_store(&store, &NEW_YORK).unwrap()[0];
+    let [lat, lng] = taken_down.coordinates;
+
+    store
+        .apply(&IndexedTransaction {
+            slot: 301,
+            signature: "takedown".to_string(),
+            block_time: None,
+            events: Vec::new(),
+            statuses: vec![CellStatusEvent {
+                cell: get_logo_placement_pda(lat, lng, &program::ID).0,
+                coordinates: Some((lat, lng)),
+                placed_at: taken_down.placed_at,
+                status: CellStatus::TakenDown,
+                timestamp: 1_700_000_500,
+            }],
+            logs_truncated: false,
+        })
+        .unwrap();
+
+    let indexed = placements_from_store(&store, &NEW_YORK).unwrap();
+    assert_eq!(indexed.len(), 1);
+    assert_ne!(indexed[0].coordinates, taken_down.coordinates);
+}
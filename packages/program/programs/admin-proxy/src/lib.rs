@@ -0,0 +1,89 @@
+//! Minimal stand-in for a multisig vault (e.g. Squads) administering SolPlace
+//!
+//! The `authority` PDA of this program is proposed as SolPlace admin and then signs every
+//! admin instruction through CPI. It performs no authorization of its own and only exists
+//! for the integration tests, never make a deployed instance the real admin.
+
+use anchor_lang::prelude::*;
+use solplace::cpi::accounts::{AcceptAdmin, ProposeAdmin, SetPaused};
+use solplace::program::SolplaceProgram;
+use solplace::ProgramConfig;
+
+declare_id!("ECSzws2fq8opjX9sY1iaH4LenEurw9Um3797Cxb4CGMn");
+
+pub const AUTHORITY_SEED: &[u8] = b"authority";
+
+#[program]
+pub mod admin_proxy {
+    use super::*;
+
+    pub fn accept_admin(ctx: Context<ProxyCall>) -> Result<()> {
+        let bump = [ctx.bumps.authority];
+        let signer_seeds: &[&[&[u8]]] = &[&[AUTHORITY_SEED, &bump]];
+        solplace::cpi::accept_admin(CpiContext::new_with_signer(
+            ctx.accounts.solplace_program.to_account_info(),
+            AcceptAdmin {
+                config: ctx.accounts.config.to_account_info(),
+                pending_admin: ctx.accounts.authority.to_account_info(),
+                event_authority: ctx.accounts.solplace_event_authority.to_account_info(),
+                program: ctx.accounts.solplace_program.to_account_info(),
+            },
+            signer_seeds,
+        ))
+    }
+
+    pub fn propose_admin(ctx: Context<ProxyCall>, new_admin: Option<Pubkey>) -> Result<()> {
+        let bump = [ctx.bumps.authority];
+        let signer_seeds: &[&[&[u8]]] = &[&[AUTHORITY_SEED, &bump]];
+        solplace::cpi::propose_admin(
+            CpiContext::new_with_signer(
+                ctx.accounts.solplace_program.to_account_info(),
+                ProposeAdmin {
+                    config: ctx.accounts.config.to_account_info(),
+                    admin: ctx.accounts.authority.to_account_info(),
+                    event_authority: ctx.accounts.solplace_event_authority.to_account_info(),
+                    program: ctx.accounts.solplace_program.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            new_admin,
+        )
+    }
+
+    pub fn set_paused(ctx: Context<ProxyCall>, paused: bool) -> Result<()> {
+        let bump = [ctx.bumps.authority];
+        let signer_seeds: &[&[&[u8]]] = &[&[AUTHORITY_SEED, &bump]];
+        solplace::cpi::set_paused(
+            CpiContext::new_with_signer(
+                ctx.accounts.solplace_program.to_account_info(),
+                SetPaused {
+                    config: ctx.accounts.config.to_account_info(),
+                    admin: ctx.accounts.authority.to_account_info(),
+                    event_authority: ctx.accounts.solplace_event_authority.to_account_info(),
+                    program: ctx.accounts.solplace_program.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            paused,
+        )
+    }
+}
+
+#[derive(Accounts)]
+pub struct ProxyCall<'info> {
+    /// SolPlace program configuration, validated by SolPlace
+    #[account(mut)]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// PDA acting as SolPlace admin
+    /// CHECK: Only used as a CPI signer
+    #[account(seeds = [AUTHORITY_SEED], bump)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Signs the events SolPlace emits through self-CPI
+    /// CHECK: Validated by SolPlace
+    #[account(seeds = [b"__event_authority"], bump, seeds::program = solplace_program.key())]
+    pub solplace_event_authority: UncheckedAccount<'info>,
+
+    pub solplace_program: Program<'info, SolplaceProgram>,
+}
@@ -1,25 +1,63 @@
 use anchor_lang::prelude::*;
 
 // PDA Seeds
+pub const CONFIG_SEED: &[u8] = b"config";
 pub const COOLDOWN_SEED: &[u8] = b"cooldown";
 pub const LOGO_PLACEMENT_SEED: &[u8] = b"logo_placement";
+pub const MINT_PROFILE_SEED: &[u8] = b"mint_profile";
+pub const LOGO_BITMAP_SEED: &[u8] = b"logo_bitmap";
+pub const REPORT_SEED: &[u8] = b"report";
+pub const REPORT_TALLY_SEED: &[u8] = b"report_tally";
+pub const DENIED_MINT_SEED: &[u8] = b"denied_mint";
+pub const DENIED_USER_SEED: &[u8] = b"denied_user";
+pub const RESTRICTED_ZONE_SEED: &[u8] = b"restricted_zone";
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
 
 // Fee Configuration
 pub const BASE_PLACEMENT_FEE: u64 = 1_000_000;    // 0.001 SOL in lamports
 pub const OVERWRITE_MULTIPLIER: u64 = 5;          // 5x fee for overwrites
 pub const TREASURY_PERCENTAGE: u8 = 100;          // 100% to treasury
+pub const BPS_DENOMINATOR: u16 = 10_000;          // 100% in basis points
+
+// Holder Tiers: (minimum share of supply, fee discount), both in basis points
+pub const HOLDER_TIERS: [(u16, u16); 3] = [
+    (10, 1_000),                                  // >= 0.1% of supply: 10% off
+    (100, 2_500),                                 // >= 1% of supply: 25% off
+    (1_000, 5_000),                               // >= 10% of supply: 50% off
+];
 
 // Rate Limiting
 pub const COOLDOWN_PERIOD: i64 = 30;              // 30 seconds between placements
 
+// Reporting
+pub const REPORT_STAKE: u64 = 10_000_000;         // 0.01 SOL staked per report
+pub const REPORT_HIDE_THRESHOLD: u16 = 3;         // Open reports before a logo is hidden
+
 // Coordinate Constraints
 pub const MIN_LATITUDE: i32 = -90_000_000;        // -90° in microdegrees
 pub const MAX_LATITUDE: i32 = 90_000_000;         // +90° in microdegrees
 pub const MIN_LONGITUDE: i32 = -180_000_000;      // -180° in microdegrees
 pub const MAX_LONGITUDE: i32 = 180_000_000;       // +180° in microdegrees
 
+// Restricted Zones
+pub const ZONE_TILE_SIZE: i32 = 1_000_000;        // 1° zone tiles in microdegrees
+pub const MAX_ZONE_SHAPES: usize = 8;             // Shapes per zone tile
+pub const MAX_ZONE_POLYGON_VERTICES: usize = 16;  // Vertices per polygon
+
 // Logo Constraints
 pub const MAX_LOGO_URI_LENGTH: usize = 200;       // Max characters for logo URI
+pub const MAX_ALLOWED_DOMAINS: usize = 16;        // Max entries in the https domain allowlist
+pub const MAX_DOMAIN_LENGTH: usize = 64;          // Max characters per allowlisted domain
+
+// Logo Bitmap Constraints
+pub const LOGO_BITMAP_VERSION: u8 = 1;            // Payload format version
+pub const LOGO_BITMAP_SIZES: [u8; 2] = [16, 32];  // Allowed square sizes in pixels
+pub const LOGO_BITMAP_MAX_COLORS: usize = 16;     // Palette entries (4-bit indices)
+pub const LOGO_BITMAP_ENCODING_PACKED: u8 = 0;    // Two 4-bit palette indices per byte
+pub const LOGO_BITMAP_ENCODING_RLE: u8 = 1;       // One (run - 1) << 4 | index byte per run
+pub const LOGO_BITMAP_HEADER_LEN: usize = 5;      // version + width + height + encoding + palette_len
+pub const MAX_LOGO_BITMAP_BYTES: usize =          // Header + RGBA palette + 32x32 packed 4-bit pixels
+    LOGO_BITMAP_HEADER_LEN + LOGO_BITMAP_MAX_COLORS * 4 + 32 * 32 / 2;
 
 // Treasury address (replace with actual treasury)
 pub const TREASURY_ADDRESS: Pubkey = anchor_lang::solana_program::pubkey!("3ojcMQjKYfME4qGmgE8Qb9odKcu4kSes1xhcVXD7DXCd");
@@ -37,4 +37,109 @@ pub enum SolplaceError {
 
     #[msg("Invalid account: discriminator mismatch")]
     InvalidAccount,
+
+    #[msg("Holder token account must belong to the user and match the token mint")]
+    InvalidHolderTokenAccount,
+
+    #[msg("Logo URI must not be empty")]
+    EmptyLogoUri,
+
+    #[msg("Canonical logo can only be replaced by the mint authority or a larger holder")]
+    CanonicalLogoLocked,
+
+    #[msg("Invalid mint profile account")]
+    InvalidMintProfile,
+
+    #[msg("Previous mint profile is required when overwriting another token")]
+    MissingPreviousMintProfile,
+
+    #[msg("Logo hash is required: SHA-256 of the logo image bytes")]
+    MissingLogoHash,
+
+    #[msg("Only the current placer of the logo can do this")]
+    NotLogoPlacer,
+
+    #[msg("Invalid logo bitmap: unsupported version, size, encoding or palette")]
+    InvalidLogoBitmap,
+
+    #[msg("Logo bitmap exceeds the maximum payload size")]
+    LogoBitmapTooLarge,
+
+    #[msg("Logo bitmap chunk is out of order or belongs to another upload")]
+    InvalidLogoBitmapChunk,
+
+    #[msg("Signer is not authorized to perform this action")]
+    Unauthorized,
+
+    #[msg("Logo URI must use the https, ipfs or ar scheme")]
+    UnsupportedUriScheme,
+
+    #[msg("Logo URI contains control characters or backslashes")]
+    InvalidUriCharacters,
+
+    #[msg("Logo URI host is missing or invalid")]
+    InvalidUriHost,
+
+    #[msg("Logo URI host is not in the domain allowlist")]
+    UriDomainNotAllowed,
+
+    #[msg("Invalid allowlist domain: use a lowercase hostname of at most 64 characters")]
+    InvalidDomain,
+
+    #[msg("Too many allowlist domains")]
+    TooManyDomains,
+
+    #[msg("Placer account does not match the logo placement")]
+    InvalidPlacer,
+
+    #[msg("Placement is already blank")]
+    PlacementAlreadyBlank,
+
+    #[msg("You cannot report your own placement")]
+    CannotReportOwnPlacement,
+
+    #[msg("Reporter account does not match the report")]
+    InvalidReporter,
+
+    #[msg("This token mint is blocked from placements")]
+    MintDenied,
+
+    #[msg("This wallet is blocked from placements")]
+    UserDenied,
+
+    #[msg("These coordinates are inside a restricted zone")]
+    PlacementRestricted,
+
+    #[msg("Invalid restricted zone account")]
+    InvalidRestrictedZone,
+
+    #[msg("Zone tile is out of range")]
+    InvalidZoneTile,
+
+    #[msg("Invalid restricted zone shape")]
+    InvalidZoneShape,
+
+    #[msg("Restricted zone shape does not overlap its tile")]
+    ZoneOutsideTile,
+
+    #[msg("Too many shapes in restricted zone tile")]
+    TooManyZoneShapes,
+
+    #[msg("Program is paused")]
+    ProgramPaused,
+
+    #[msg("Account already uses the current layout")]
+    AccountAlreadyMigrated,
+
+    #[msg("Rent payer does not match the deny list entry")]
+    InvalidRentPayer,
+
+    #[msg("Claiming a canonical logo requires holding the token")]
+    CanonicalClaimWithoutBalance,
+
+    #[msg("Logo must match the token's canonical logo")]
+    CanonicalLogoMismatch,
+
+    #[msg("Bump is not the canonical bump of the account address")]
+    NonCanonicalBump,
 }
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::state::Account as SplTokenAccount;
+
+use crate::constants::*;
+use crate::error::SolplaceError;
+
+/// Fee discount (in basis points) earned by holding `balance` out of a mint's `supply`
+/// Returns the discount of the highest tier whose share threshold is reached
+pub fn holder_discount_bps(balance: u64, supply: u64) -> u16 {
+    if supply == 0 || balance == 0 {
+        return 0;
+    }
+
+    let share_bps = (balance as u128 * BPS_DENOMINATOR as u128) / supply as u128;
+
+    HOLDER_TIERS
+        .iter()
+        .rev()
+        .find(|(min_share_bps, _)| share_bps >= *min_share_bps as u128)
+        .map(|(_, discount_bps)| *discount_bps)
+        .unwrap_or(0)
+}
+
+/// Holder discount of `token_account`, which must be `user`'s account for `token_mint`
+pub fn holder_token_discount(
+    token_account: &SplTokenAccount,
+    user: &Pubkey,
+    token_mint: &Pubkey,
+    supply: u64,
+) -> Result<u16> {
+    // Only the user's own holdings of the placed token count
+    require!(
+        token_account.owner == *user,
+        SolplaceError::InvalidHolderTokenAccount
+    );
+    require!(
+        token_account.mint == *token_mint,
+        SolplaceError::InvalidHolderTokenAccount
+    );
+
+    Ok(holder_discount_bps(token_account.amount, supply))
+}
+
+/// Placement fee in lamports after applying a holder discount
+pub fn calculate_placement_fee(is_overwrite: bool, discount_bps: u16) -> u64 {
+    let base_fee = if is_overwrite {
+        BASE_PLACEMENT_FEE * OVERWRITE_MULTIPLIER
+    } else {
+        BASE_PLACEMENT_FEE
+    };
+
+    let discount_bps = discount_bps.min(BPS_DENOMINATOR) as u64;
+    base_fee - base_fee * discount_bps / BPS_DENOMINATOR as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUPPLY: u64 = 1_000_000;
+
+    fn token_account(owner: Pubkey, mint: Pubkey, amount: u64) -> SplTokenAccount {
+        SplTokenAccount {
+            mint,
+            owner,
+            amount,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn discount_tiers_start_at_their_share_threshold() {
+        // 0.1%, 1% and 10% of the supply
+        assert_eq!(holder_discount_bps(999, SUPPLY), 0);
+        assert_eq!(holder_discount_bps(1_000, SUPPLY), 1_000);
+        assert_eq!(holder_discount_bps(9_999, SUPPLY), 1_000);
+        assert_eq!(holder_discount_bps(10_000, SUPPLY), 2_500);
+        assert_eq!(holder_discount_bps(99_999, SUPPLY), 2_500);
+        assert_eq!(holder_discount_bps(100_000, SUPPLY), 5_000);
+        assert_eq!(holder_discount_bps(SUPPLY, SUPPLY), 5_000);
+    }
+
+    #[test]
+    fn no_discount_without_balance_or_supply() {
+        assert_eq!(holder_discount_bps(0, SUPPLY), 0);
+        assert_eq!(holder_discount_bps(1_000, 0), 0);
+        // Shares are computed in u128 so huge balances do not overflow
+        assert_eq!(holder_discount_bps(u64::MAX, u64::MAX), 5_000);
+        assert_eq!(holder_discount_bps(u64::MAX / 1_000, u64::MAX), 0);
+    }
+
+    #[test]
+    fn holder_token_account_must_match_user_and_mint() {
+        let user = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+
+        let own = token_account(user, mint, 10_000);
+        assert_eq!(holder_token_discount(&own, &user, &mint, SUPPLY).unwrap(), 2_500);
+
+        let wrong_owner = token_account(Pubkey::new_unique(), mint, 10_000);
+        assert_eq!(
+            holder_token_discount(&wrong_owner, &user, &mint, SUPPLY).unwrap_err(),
+            SolplaceError::InvalidHolderTokenAccount.into()
+        );
+
+        let wrong_mint = token_account(user, Pubkey::new_unique(), 10_000);
+        assert_eq!(
+            holder_token_discount(&wrong_mint, &user, &mint, SUPPLY).unwrap_err(),
+            SolplaceError::InvalidHolderTokenAccount.into()
+        );
+    }
+
+    #[test]
+    fn fees_apply_the_overwrite_multiplier_and_discount() {
+        assert_eq!(calculate_placement_fee(false, 0), BASE_PLACEMENT_FEE);
+        assert_eq!(
+            calculate_placement_fee(true, 0),
+            BASE_PLACEMENT_FEE * OVERWRITE_MULTIPLIER
+        );
+        assert_eq!(calculate_placement_fee(false, 2_500), 750_000);
+        assert_eq!(calculate_placement_fee(true, 5_000), 2_500_000);
+    }
+
+    #[test]
+    fn fee_discounts_never_round() {
+        // Base fees are multiples of BPS_DENOMINATOR, so every discount is exact
+        for discount_bps in 0..=BPS_DENOMINATOR {
+            for is_overwrite in [false, true] {
+                let base_fee = calculate_placement_fee(is_overwrite, 0);
+                assert_eq!(
+                    calculate_placement_fee(is_overwrite, discount_bps) as u128
+                        * BPS_DENOMINATOR as u128,
+                    base_fee as u128 * (BPS_DENOMINATOR - discount_bps) as u128
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn discounts_above_the_whole_fee_are_clamped() {
+        assert_eq!(calculate_placement_fee(false, BPS_DENOMINATOR), 0);
+        assert_eq!(calculate_placement_fee(true, u16::MAX), 0);
+    }
+}
@@ -0,0 +1,160 @@
+//! Integer geometry on microdegree coordinates
+//!
+//! Points are `[lat, lng]` pairs like `LogoPlacement::coordinates`. All math is done in
+//! `i64` so results are exact and identical on every validator.
+
+use crate::constants::ZONE_TILE_SIZE;
+
+/// Tile of the restricted zone grid containing a point, as `[tile_lat, tile_lng]`
+pub fn tile_of(lat: i32, lng: i32) -> [i16; 2] {
+    [
+        lat.div_euclid(ZONE_TILE_SIZE) as i16,
+        lng.div_euclid(ZONE_TILE_SIZE) as i16,
+    ]
+}
+
+/// Bounds of a tile as `[min_lat, min_lng, max_lat, max_lng]`, max exclusive
+pub fn tile_bounds(tile: [i16; 2]) -> [i32; 4] {
+    let min_lat = tile[0] as i32 * ZONE_TILE_SIZE;
+    let min_lng = tile[1] as i32 * ZONE_TILE_SIZE;
+    [min_lat, min_lng, min_lat + ZONE_TILE_SIZE, min_lng + ZONE_TILE_SIZE]
+}
+
+/// Whether a point lies inside a bounding box, edges included
+pub fn point_in_bbox(point: [i32; 2], min: [i32; 2], max: [i32; 2]) -> bool {
+    (min[0]..=max[0]).contains(&point[0]) && (min[1]..=max[1]).contains(&point[1])
+}
+
+/// Whether a point lies inside a simple polygon, edges and vertices included
+///
+/// Uses an even-odd ray cast towards increasing longitude. The polygon is implicitly
+/// closed, so the last vertex connects back to the first.
+pub fn point_in_polygon(point: [i32; 2], vertices: &[[i32; 2]]) -> bool {
+    if vertices.len() < 3 {
+        return false;
+    }
+
+    let (py, px) = (point[0] as i64, point[1] as i64);
+    let mut inside = false;
+    for (i, a) in vertices.iter().enumerate() {
+        let b = vertices[(i + 1) % vertices.len()];
+        let (ay, ax) = (a[0] as i64, a[1] as i64);
+        let (by, bx) = (b[0] as i64, b[1] as i64);
+
+        if on_segment(py, px, ay, ax, by, bx) {
+            return true;
+        }
+
+        // Edge straddles the ray's latitude, test whether the crossing is east of the point
+        if (ay > py) != (by > py) {
+            let lhs = (px - ax) * (by - ay);
+            let rhs = (py - ay) * (bx - ax);
+            let crosses = if by > ay { lhs < rhs } else { lhs > rhs };
+            if crosses {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+fn on_segment(py: i64, px: i64, ay: i64, ax: i64, by: i64, bx: i64) -> bool {
+    let cross = (bx - ax) * (py - ay) - (by - ay) * (px - ax);
+    cross == 0
+        && (ay.min(by)..=ay.max(by)).contains(&py)
+        && (ax.min(bx)..=ax.max(bx)).contains(&px)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SQUARE: [[i32; 2]; 4] = [[0, 0], [0, 10], [10, 10], [10, 0]];
+    // U shape opening north, the notch spans lng 4..6 above lat 4
+    const NOTCHED: [[i32; 2]; 8] = [
+        [0, 0],
+        [0, 10],
+        [10, 10],
+        [10, 6],
+        [4, 6],
+        [4, 4],
+        [10, 4],
+        [10, 0],
+    ];
+
+    #[test]
+    fn tiles_floor_towards_negative_infinity() {
+        assert_eq!(tile_of(0, 0), [0, 0]);
+        assert_eq!(tile_of(999_999, 1_000_000), [0, 1]);
+        assert_eq!(tile_of(-1, -1), [-1, -1]);
+        assert_eq!(tile_of(-90_000_000, -180_000_000), [-90, -180]);
+        assert_eq!(tile_of(90_000_000, 180_000_000), [90, 180]);
+        assert_eq!(tile_bounds([-1, 2]), [-1_000_000, 2_000_000, 0, 3_000_000]);
+    }
+
+    #[test]
+    fn bbox_includes_edges() {
+        assert!(point_in_bbox([5, 5], [0, 0], [10, 10]));
+        assert!(point_in_bbox([0, 10], [0, 0], [10, 10]));
+        assert!(!point_in_bbox([11, 5], [0, 0], [10, 10]));
+        assert!(!point_in_bbox([5, -1], [0, 0], [10, 10]));
+    }
+
+    #[test]
+    fn polygon_contains_interior_points() {
+        assert!(point_in_polygon([5, 5], &SQUARE));
+        assert!(point_in_polygon([1, 9], &SQUARE));
+        assert!(!point_in_polygon([11, 5], &SQUARE));
+        assert!(!point_in_polygon([5, -1], &SQUARE));
+    }
+
+    #[test]
+    fn polygon_includes_edges_and_vertices() {
+        assert!(point_in_polygon([0, 5], &SQUARE));
+        assert!(point_in_polygon([10, 10], &SQUARE));
+        assert!(point_in_polygon([5, 10], &SQUARE));
+    }
+
+    #[test]
+    fn polygon_handles_concave_shapes() {
+        assert!(point_in_polygon([2, 5], &NOTCHED));
+        assert!(point_in_polygon([8, 2], &NOTCHED));
+        assert!(!point_in_polygon([8, 5], &NOTCHED));
+        // Ray runs along the bottom edge of the notch
+        assert!(point_in_polygon([4, 3], &NOTCHED));
+        assert!(point_in_polygon([4, 5], &NOTCHED));
+        assert!(!point_in_polygon([6, 5], &NOTCHED));
+        assert!(!point_in_polygon([4, 12], &NOTCHED));
+    }
+
+    #[test]
+    fn polygon_works_with_either_winding_and_negative_coordinates() {
+        let triangle = [[-10_000_000, -20_000_000], [10_000_000, -20_000_000], [0, 20_000_000]];
+        let mut reversed = triangle;
+        reversed.reverse();
+        for polygon in [triangle, reversed] {
+            assert!(point_in_polygon([0, 0], &polygon));
+            assert!(point_in_polygon([-9_000_000, -19_000_000], &polygon));
+            assert!(!point_in_polygon([9_000_000, 10_000_000], &polygon));
+            assert!(!point_in_polygon([0, 21_000_000], &polygon));
+        }
+    }
+
+    #[test]
+    fn degenerate_polygons_contain_nothing() {
+        assert!(!point_in_polygon([0, 0], &[]));
+        assert!(!point_in_polygon([0, 0], &[[0, 0], [1, 1]]));
+    }
+
+    #[test]
+    fn polygon_handles_extreme_coordinates() {
+        let world = [
+            [-90_000_000, -180_000_000],
+            [-90_000_000, 180_000_000],
+            [90_000_000, 180_000_000],
+            [90_000_000, -180_000_000],
+        ];
+        assert!(point_in_polygon([89_999_999, 179_999_999], &world));
+        assert!(point_in_polygon([-90_000_000, 0], &world));
+    }
+}
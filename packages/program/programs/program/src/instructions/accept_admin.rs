@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.pending_admin == Some(pending_admin.key()) @ SolplaceError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Proposed admin, proves control of the key by signing
+    pub pending_admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let previous_admin = config.admin;
+    config.admin = ctx.accounts.pending_admin.key();
+    config.pending_admin = None;
+
+    emit_cpi!(AdminTransferredEvent {
+        previous_admin,
+        admin: config.admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Events
+#[event]
+pub struct AdminTransferredEvent {
+    pub previous_admin: Pubkey,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(token_mint: Pubkey)]
+pub struct AllowMint<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = moderator @ SolplaceError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Deny list entry being lifted, rent goes back to whoever paid it
+    #[account(
+        mut,
+        seeds = [DENIED_MINT_SEED, token_mint.as_ref()],
+        bump = denied_mint.bump,
+        has_one = payer @ SolplaceError::InvalidRentPayer,
+        close = payer,
+    )]
+    pub denied_mint: Account<'info, DenyListEntry>,
+
+    pub moderator: Signer<'info>,
+
+    /// Wallet that paid the rent of the entry
+    /// CHECK: Address is checked against the entry
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<AllowMint>, token_mint: Pubkey) -> Result<()> {
+    emit_cpi!(MintAllowedEvent {
+        token_mint,
+        moderator: ctx.accounts.moderator.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Events
+#[event]
+pub struct MintAllowedEvent {
+    pub token_mint: Pubkey,
+    pub moderator: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct AllowUser<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = moderator @ SolplaceError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Deny list entry being lifted, rent goes back to whoever paid it
+    #[account(
+        mut,
+        seeds = [DENIED_USER_SEED, user.as_ref()],
+        bump = denied_user.bump,
+        has_one = payer @ SolplaceError::InvalidRentPayer,
+        close = payer,
+    )]
+    pub denied_user: Account<'info, DenyListEntry>,
+
+    pub moderator: Signer<'info>,
+
+    /// Wallet that paid the rent of the entry
+    /// CHECK: Address is checked against the entry
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<AllowUser>, user: Pubkey) -> Result<()> {
+    emit_cpi!(UserAllowedEvent {
+        user,
+        moderator: ctx.accounts.moderator.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Events
+#[event]
+pub struct UserAllowedEvent {
+    pub user: Pubkey,
+    pub moderator: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(tile_lat: i16, tile_lng: i16)]
+pub struct ClearRestrictedZone<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ SolplaceError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Restricted zone being removed, rent goes to the admin
+    #[account(
+        mut,
+        seeds = [RESTRICTED_ZONE_SEED, &tile_lat.to_le_bytes(), &tile_lng.to_le_bytes()],
+        bump = restricted_zone.bump,
+        close = admin,
+    )]
+    pub restricted_zone: Account<'info, RestrictedZone>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClearRestrictedZone>, tile_lat: i16, tile_lng: i16) -> Result<()> {
+    emit_cpi!(RestrictedZoneClearedEvent {
+        tile_lat,
+        tile_lng,
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Events
+#[event]
+pub struct RestrictedZoneClearedEvent {
+    pub tile_lat: i16,
+    pub tile_lng: i16,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
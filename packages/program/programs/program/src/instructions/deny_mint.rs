@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(token_mint: Pubkey)]
+pub struct DenyMint<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = moderator @ SolplaceError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Deny list entry (PDA from token mint)
+    #[account(
+        init,
+        payer = payer,
+        space = DenyListEntry::SIZE,
+        seeds = [DENIED_MINT_SEED, token_mint.as_ref()],
+        bump,
+    )]
+    pub denied_mint: Account<'info, DenyListEntry>,
+
+    pub moderator: Signer<'info>,
+
+    /// Pays rent, separate from the moderator so PDA authorities holding data can sign
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DenyMint>, token_mint: Pubkey, reason: ModerationReason) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let moderator = ctx.accounts.moderator.key();
+    let denied_mint = &mut ctx.accounts.denied_mint;
+    denied_mint.address = token_mint;
+    denied_mint.reason = reason;
+    denied_mint.denied_by = moderator;
+    denied_mint.denied_at = clock.unix_timestamp;
+    denied_mint.payer = ctx.accounts.payer.key();
+    denied_mint.bump = ctx.bumps.denied_mint;
+
+    emit_cpi!(MintDeniedEvent {
+        token_mint,
+        reason,
+        moderator,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Events
+#[event]
+pub struct MintDeniedEvent {
+    pub token_mint: Pubkey,
+    pub reason: ModerationReason,
+    pub moderator: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct DenyUser<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = moderator @ SolplaceError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Deny list entry (PDA from user)
+    #[account(
+        init,
+        payer = payer,
+        space = DenyListEntry::SIZE,
+        seeds = [DENIED_USER_SEED, user.as_ref()],
+        bump,
+    )]
+    pub denied_user: Account<'info, DenyListEntry>,
+
+    pub moderator: Signer<'info>,
+
+    /// Pays rent, separate from the moderator so PDA authorities holding data can sign
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DenyUser>, user: Pubkey, reason: ModerationReason) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let moderator = ctx.accounts.moderator.key();
+    let denied_user = &mut ctx.accounts.denied_user;
+    denied_user.address = user;
+    denied_user.reason = reason;
+    denied_user.denied_by = moderator;
+    denied_user.denied_at = clock.unix_timestamp;
+    denied_user.payer = ctx.accounts.payer.key();
+    denied_user.bump = ctx.bumps.denied_user;
+
+    emit_cpi!(UserDeniedEvent {
+        user,
+        reason,
+        moderator,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Events
+#[event]
+pub struct UserDeniedEvent {
+    pub user: Pubkey,
+    pub reason: ModerationReason,
+    pub moderator: Pubkey,
+    pub timestamp: i64,
+}
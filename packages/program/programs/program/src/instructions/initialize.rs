@@ -1,9 +1,46 @@
 use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::program::SolplaceProgram;
+use crate::state::*;
 
 #[derive(Accounts)]
-pub struct Initialize {}
+pub struct Initialize<'info> {
+    /// Program configuration account (PDA)
+    #[account(
+        init,
+        payer = admin,
+        space = ProgramConfig::SIZE,
+        seeds = [CONFIG_SEED],
+        bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Initial admin, must be the program upgrade authority
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, SolplaceProgram>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ SolplaceError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
 
 pub fn handler(ctx: Context<Initialize>) -> Result<()> {
-    msg!("Greetings from: {:?}", ctx.program_id);
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.admin.key();
+    config.pending_admin = None;
+    config.moderator = ctx.accounts.admin.key();
+    config.allowed_domains = Vec::new();
+    config.paused = false;
+    config.bump = ctx.bumps.config;
+
+    msg!("SolPlace initialized, admin: {:?}", config.admin);
     Ok(())
 }
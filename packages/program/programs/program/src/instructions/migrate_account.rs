@@ -0,0 +1,282 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// Program configuration (PDA), migrations wait like placements while paused
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ SolplaceError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// `LogoPlacement` or `UserCooldown` account still in the v0 layout
+    /// `place_logo` upgrades the accounts it touches itself, this upgrades any account in bulk
+    /// CHECK: Owner and discriminator are validated in the instruction handler
+    #[account(mut)]
+    pub account: UncheckedAccount<'info>,
+
+    /// Anyone may migrate an account, the payer covers the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateAccount>) -> Result<()> {
+    let account = ctx.accounts.account.to_account_info();
+
+    // 1. Only accounts of this program can be migrated
+    require!(account.owner == ctx.program_id, SolplaceError::InvalidAccount);
+
+    // 2. Decode the old layout and re-encode it in the current one
+    let migration = migrate_data(&account.try_borrow_data()?)?;
+
+    // 3. Grow the account and write the new layout
+    write_migration(
+        &account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &migration,
+    )?;
+
+    emit_cpi!(AccountMigratedEvent {
+        account: account.key(),
+        from_version: 0,
+        to_version: migration.to_version,
+    });
+
+    Ok(())
+}
+
+/// A v0 account re-encoded in its current layout
+#[derive(Debug)]
+struct Migration {
+    pub data: Vec<u8>,
+    /// Account size of the current layout
+    pub size: usize,
+    pub to_version: u8,
+}
+
+/// Upgrade `account` in place when it is a v0 `LogoPlacement` or `UserCooldown` of this
+/// program, `payer` covers the extra rent
+/// Returns the version it was upgraded to, other accounts are left untouched
+pub(crate) fn upgrade_v0<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+) -> Result<Option<u8>> {
+    if account.owner != program_id || !is_v0(&account.try_borrow_data()?) {
+        return Ok(None);
+    }
+    let migration = migrate_data(&account.try_borrow_data()?)?;
+    write_migration(account, payer, system_program, &migration)?;
+    Ok(Some(migration.to_version))
+}
+
+fn is_v0(data: &[u8]) -> bool {
+    data.starts_with(LogoPlacementV0::DISCRIMINATOR) || data.starts_with(UserCooldownV0::DISCRIMINATOR)
+}
+
+/// Re-encode v0 `LogoPlacement` or `UserCooldown` account data in the current layout
+fn migrate_data(data: &[u8]) -> Result<Migration> {
+    let mut migrated = Vec::new();
+    if data.starts_with(LogoPlacementV0::DISCRIMINATOR) {
+        let logo_placement = LogoPlacement::try_deserialize_versioned(data)?;
+        logo_placement.try_serialize(&mut migrated)?;
+        Ok(Migration { data: migrated, size: LogoPlacement::SIZE, to_version: LogoPlacement::VERSION })
+    } else if data.starts_with(UserCooldownV0::DISCRIMINATOR) {
+        let user_cooldown = UserCooldown::try_deserialize_versioned(data)?;
+        user_cooldown.try_serialize(&mut migrated)?;
+        Ok(Migration { data: migrated, size: UserCooldown::SIZE, to_version: UserCooldown::VERSION })
+    } else if data.starts_with(LogoPlacement::DISCRIMINATOR)
+        || data.starts_with(UserCooldown::DISCRIMINATOR)
+    {
+        err!(SolplaceError::AccountAlreadyMigrated)
+    } else {
+        err!(SolplaceError::InvalidAccount)
+    }
+}
+
+/// Grow `account` to the migrated size and overwrite it with the new layout
+fn write_migration<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    migration: &Migration,
+) -> Result<()> {
+    grow_account(account, payer, system_program, migration.size)?;
+    let mut data = account.try_borrow_mut_data()?;
+    data[..migration.data.len()].copy_from_slice(&migration.data);
+    data[migration.data.len()..].fill(0);
+    Ok(())
+}
+
+/// Resize a program account to `new_size`, topping up rent from `payer`
+fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_size: usize,
+) -> Result<()> {
+    if account.data_len() >= new_size {
+        return Ok(());
+    }
+
+    let required = Rent::get()?.minimum_balance(new_size);
+    let shortfall = required.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    account.realloc(new_size, false)?;
+    Ok(())
+}
+
+// Events
+#[event]
+pub struct AccountMigratedEvent {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Account data as the v0 program left it, zero-padded to the allocated size
+    fn v0_account(account: &impl AccountSerialize, size: usize) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data.resize(size, 0);
+        data
+    }
+
+    /// The account once `write_migration` grew it
+    fn migrated_account(migration: &Migration) -> Vec<u8> {
+        let mut data = migration.data.clone();
+        data.resize(migration.size, 0);
+        data
+    }
+
+    #[test]
+    fn migrates_v0_logo_placements() {
+        let v0 = LogoPlacementV0 {
+            coordinates: [40_712_800, -74_006_000],
+            token_mint: Pubkey::new_unique(),
+            logo_uri: "https://example.com/logo.png".to_string(),
+            logo_hash: [7; 32],
+            placed_by: Pubkey::new_unique(),
+            placed_at: 1_700_000_000,
+            overwrite_count: 3,
+            bump: 254,
+        };
+        let migration = migrate_data(&v0_account(&v0, LogoPlacementV0::SIZE)).unwrap();
+        assert_eq!((migration.size, migration.to_version), (LogoPlacement::SIZE, 1));
+        assert!(migration.data.len() <= migration.size);
+
+        let data = migrated_account(&migration);
+        let placement = LogoPlacement::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(placement.version, LogoPlacement::VERSION);
+        assert_eq!(placement.coordinates, v0.coordinates);
+        assert_eq!(placement.token_mint, v0.token_mint);
+        assert_eq!(placement.logo_uri, v0.logo_uri);
+        assert_eq!(placement.placed_by, v0.placed_by);
+        assert_eq!(placement.placed_at, v0.placed_at);
+        assert_eq!(placement.overwrite_count, v0.overwrite_count);
+        assert_eq!(placement.bump, v0.bump);
+        assert_eq!(placement.logo_hash, v0.logo_hash);
+
+        // Reading the v0 account directly upgrades it the same way
+        let upgraded =
+            LogoPlacement::try_deserialize_versioned(&v0_account(&v0, LogoPlacementV0::SIZE)).unwrap();
+        assert_eq!(upgraded.logo_hash, v0.logo_hash);
+        assert_eq!(upgraded.placed_at, v0.placed_at);
+
+        assert_eq!(migrate_data(&data).unwrap_err(), SolplaceError::AccountAlreadyMigrated.into());
+    }
+
+    #[test]
+    fn migrates_v0_user_cooldowns() {
+        let v0 = UserCooldownV0 {
+            user: Pubkey::new_unique(),
+            last_placement: 1_700_000_000,
+            placement_count: 12,
+            bump: 253,
+        };
+        let migration = migrate_data(&v0_account(&v0, UserCooldownV0::SIZE)).unwrap();
+        assert_eq!((migration.size, migration.to_version), (UserCooldown::SIZE, 1));
+
+        let data = migrated_account(&migration);
+        let user_cooldown = UserCooldown::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(user_cooldown.version, UserCooldown::VERSION);
+        assert_eq!(user_cooldown.user, v0.user);
+        assert_eq!(user_cooldown.last_placement, v0.last_placement);
+        assert_eq!(user_cooldown.placement_count, v0.placement_count);
+        assert_eq!(user_cooldown.bump, v0.bump);
+
+        assert_eq!(migrate_data(&data).unwrap_err(), SolplaceError::AccountAlreadyMigrated.into());
+    }
+
+    /// Run `upgrade_v0` on `data` owned by `owner`
+    fn upgrade(owner: &Pubkey, data: &mut [u8]) -> Result<Option<u8>> {
+        let key = Pubkey::new_unique();
+        let system_program = anchor_lang::system_program::ID;
+        let (mut lamports, mut payer_lamports, mut program_lamports) = (1, 0, 0);
+        let (mut payer_data, mut program_data) = (Vec::new(), Vec::new());
+        let account = AccountInfo::new(&key, false, true, &mut lamports, data, owner, false, 0);
+        let payer = AccountInfo::new(
+            &key, true, true, &mut payer_lamports, &mut payer_data, &system_program, false, 0,
+        );
+        let program = AccountInfo::new(
+            &system_program, false, false, &mut program_lamports, &mut program_data,
+            &system_program, true, 0,
+        );
+        upgrade_v0(&account, &payer, &program, &crate::ID)
+    }
+
+    #[test]
+    fn upgrades_only_v0_accounts_of_this_program() {
+        let v0 = UserCooldownV0 {
+            user: Pubkey::new_unique(),
+            last_placement: 1_700_000_000,
+            placement_count: 12,
+            bump: 253,
+        };
+        let mut foreign = v0_account(&v0, UserCooldownV0::SIZE);
+        assert_eq!(upgrade(&Pubkey::new_unique(), &mut foreign).unwrap(), None);
+        assert_eq!(foreign, v0_account(&v0, UserCooldownV0::SIZE));
+
+        let current = UserCooldown::from(v0);
+        let mut data = v0_account(&current, UserCooldown::SIZE);
+        assert_eq!(upgrade(&crate::ID, &mut data).unwrap(), None);
+        assert_eq!(data, v0_account(&current, UserCooldown::SIZE));
+
+        let mut empty = Vec::new();
+        assert_eq!(upgrade(&crate::ID, &mut empty).unwrap(), None);
+    }
+
+    #[test]
+    fn rejects_other_accounts() {
+        let mut config = ProgramConfig::DISCRIMINATOR.to_vec();
+        config.resize(64, 0);
+        assert_eq!(migrate_data(&config).unwrap_err(), SolplaceError::InvalidAccount.into());
+        assert_eq!(migrate_data(&[]).unwrap_err(), SolplaceError::InvalidAccount.into());
+    }
+}
@@ -1,7 +1,40 @@
+pub mod accept_admin;
+pub mod allow_mint;
+pub mod allow_user;
+pub mod clear_restricted_zone;
+pub mod deny_mint;
+pub mod deny_user;
 pub mod initialize;
+pub mod migrate_account;
+pub mod moderate_logo;
 pub mod place_logo;
-pub mod expose_types;
+pub mod propose_admin;
+pub mod report_placement;
+pub mod resolve_report;
+pub mod set_canonical_logo;
+pub mod set_domain_allowlist;
+pub mod set_moderator;
+pub mod set_paused;
+pub mod set_restricted_zone;
+pub mod write_logo_bitmap;
 
+#[allow(ambiguous_glob_reexports)]
+pub use accept_admin::*;
+pub use allow_mint::*;
+pub use allow_user::*;
+pub use clear_restricted_zone::*;
+pub use deny_mint::*;
+pub use deny_user::*;
 pub use initialize::*;
+pub use migrate_account::*;
+pub use moderate_logo::*;
 pub use place_logo::*;
-pub use expose_types::*;
+pub use propose_admin::*;
+pub use report_placement::*;
+pub use resolve_report::*;
+pub use set_canonical_logo::*;
+pub use set_domain_allowlist::*;
+pub use set_moderator::*;
+pub use set_paused::*;
+pub use set_restricted_zone::*;
+pub use write_logo_bitmap::*;
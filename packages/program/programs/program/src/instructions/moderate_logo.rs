@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(lat: i32, lng: i32)]
+pub struct ModerateLogo<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = moderator @ SolplaceError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Placement being taken down
+    #[account(
+        mut,
+        seeds = [LOGO_PLACEMENT_SEED, &lat.to_le_bytes(), &lng.to_le_bytes()],
+        bump = logo_placement.bump,
+    )]
+    pub logo_placement: Account<'info, LogoPlacement>,
+
+    /// Mint profile of the moderated token, required unless the placement is already blank
+    #[account(mut)]
+    pub mint_profile: Option<Account<'info, MintProfile>>,
+
+    /// Companion bitmap of the placement (PDA from coordinates), closed when it exists
+    /// CHECK: Address is checked by seeds, only closed when owned by this program
+    #[account(
+        mut,
+        seeds = [LOGO_BITMAP_SEED, &lat.to_le_bytes(), &lng.to_le_bytes()],
+        bump,
+    )]
+    pub logo_bitmap: UncheckedAccount<'info>,
+
+    /// Report counter of the placement, decides who gets the rent of closed accounts
+    /// CHECK: Address is checked by seeds, only read when owned by this program
+    #[account(
+        seeds = [REPORT_TALLY_SEED, logo_placement.key().as_ref()],
+        bump,
+    )]
+    pub report_tally: UncheckedAccount<'info>,
+
+    /// Wallet that made the placement, refunded the rent of closed accounts unless a report
+    /// against the placement was upheld
+    /// CHECK: Address is checked against the placement
+    #[account(mut, address = logo_placement.placed_by @ SolplaceError::InvalidPlacer)]
+    pub placer: UncheckedAccount<'info>,
+
+    /// Treasury account that keeps the rent of closed accounts once a report was upheld
+    /// CHECK: Treasury address is validated by constraint
+    #[account(mut, address = TREASURY_ADDRESS @ SolplaceError::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
+
+    pub moderator: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ModerateLogo>,
+    lat: i32,
+    lng: i32,
+    reason: ModerationReason,
+    action: ModerationAction,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let logo_placement = &mut ctx.accounts.logo_placement;
+    let token_mint = logo_placement.token_mint;
+
+    // 1. Release the cell from the token's statistics
+    if !logo_placement.is_blank() {
+        let mint_profile = ctx
+            .accounts
+            .mint_profile
+            .as_mut()
+            .ok_or(SolplaceError::InvalidMintProfile)?;
+        require!(
+            mint_profile.token_mint == token_mint,
+            SolplaceError::InvalidMintProfile
+        );
+        mint_profile.release_cell();
+    } else {
+        require!(
+            action == ModerationAction::Close,
+            SolplaceError::PlacementAlreadyBlank
+        );
+    }
+
+    // 2. Refund rent to the placer unless a moderator upheld a report against the placement
+    let placed_by = logo_placement.placed_by;
+    let placed_at = logo_placement.placed_at;
+    let report_tally = &ctx.accounts.report_tally;
+    let report_upheld = report_tally.owner == ctx.program_id
+        && ReportTally::try_deserialize(&mut &report_tally.try_borrow_data()?[..])?
+            .has_upheld_report(logo_placement.key(), placed_at);
+    let rent_to = if report_upheld {
+        ctx.accounts.treasury.to_account_info()
+    } else {
+        ctx.accounts.placer.to_account_info()
+    };
+
+    // 3. Blank or close the placement
+    let mut reclaimed_lamports = 0;
+    match action {
+        ModerationAction::Blank => logo_placement.blank(),
+        ModerationAction::Close => {
+            reclaimed_lamports += logo_placement.get_lamports();
+            logo_placement.close(rent_to.clone())?;
+        }
+    }
+
+    // 4. Close the bitmap of the taken down logo
+    let logo_bitmap = ctx.accounts.logo_bitmap.to_account_info();
+    if logo_bitmap.owner == ctx.program_id {
+        reclaimed_lamports += logo_bitmap.lamports();
+        close_account(&logo_bitmap, &rent_to)?;
+    }
+
+    emit_cpi!(LogoModeratedEvent {
+        lat,
+        lng,
+        token_mint,
+        placed_by,
+        placed_at,
+        moderator: ctx.accounts.moderator.key(),
+        reason,
+        action,
+        reclaimed_lamports,
+        rent_to: rent_to.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Move all lamports of a program-owned account to `destination` and hand it back to the system program
+fn close_account<'info>(account: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+    **destination.try_borrow_mut_lamports()? += account.lamports();
+    **account.try_borrow_mut_lamports()? = 0;
+    account.assign(&anchor_lang::system_program::ID);
+    account.realloc(0, false)?;
+    Ok(())
+}
+
+// Events
+#[event]
+pub struct LogoModeratedEvent {
+    pub lat: i32,
+    pub lng: i32,
+    pub token_mint: Pubkey,
+    pub placed_by: Pubkey,
+    /// `placed_at` of the taken down placement
+    pub placed_at: i64,
+    pub moderator: Pubkey,
+    pub reason: ModerationReason,
+    pub action: ModerationAction,
+    /// Rent of the closed placement and bitmap accounts
+    pub reclaimed_lamports: u64,
+    /// Placer refunded `reclaimed_lamports`, or the treasury once a report was upheld
+    pub rent_to: Pubkey,
+    pub timestamp: i64,
+}
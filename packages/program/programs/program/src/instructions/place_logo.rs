@@ -1,28 +1,99 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
+use anchor_spl::token::{Mint, TokenAccount};
 use crate::constants::*;
 use crate::error::SolplaceError;
+use crate::fees::*;
+use crate::geo::tile_of;
+use crate::instructions::migrate_account::{upgrade_v0, AccountMigratedEvent};
+use crate::pda::{load_or_create, store};
 use crate::state::*;
+use crate::uri::validate_logo_uri;
 
+/// Events are emitted through a self-CPI signed by the event authority, so indexers read
+/// them from inner instructions instead of logs that nodes truncate and any program can forge
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(lat: i32, lng: i32, bumps: PlacementBumps)]
 pub struct PlaceLogo<'info> {
-    /// Individual logo placement account (PDA from coordinates)
-    /// CHECK: This PDA is manually validated in the instruction handler
-    #[account(mut)]
+    /// Program configuration (PDA), placements are refused while paused
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ SolplaceError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Individual logo placement account (PDA from coordinates), created on the first placement
+    /// Accounts still in the v0 layout are upgraded in place, the user pays the extra rent
+    /// CHECK: Derivation is checked here, the handler upgrades, creates and loads it as `LogoPlacement`
+    #[account(
+        mut,
+        seeds = [LOGO_PLACEMENT_SEED, &lat.to_le_bytes(), &lng.to_le_bytes()],
+        bump = bumps.logo_placement,
+    )]
     pub logo_placement: UncheckedAccount<'info>,
 
-    /// User cooldown account (PDA from user address)
-    /// CHECK: This PDA is manually validated in the instruction handler
-    #[account(mut)]
+    /// User cooldown account (PDA from user address), created on the user's first placement
+    /// Accounts still in the v0 layout are upgraded in place, the user pays the extra rent
+    /// CHECK: Derivation is checked here, the handler upgrades, creates and loads it as `UserCooldown`
+    #[cfg(not(feature = "idl-build"))]
+    #[account(
+        mut,
+        seeds = [COOLDOWN_SEED, user.key().as_ref()],
+        bump = bumps.user_cooldown,
+    )]
     pub user_cooldown: UncheckedAccount<'info>,
 
+    // No other instruction takes a `UserCooldown`, the IDL build sees the typed account so
+    // clients can still decode it
+    /// User cooldown account (PDA from user address), created on the user's first placement
+    /// Accounts still in the v0 layout are upgraded in place, the user pays the extra rent
+    #[cfg(feature = "idl-build")]
+    #[account(
+        mut,
+        seeds = [COOLDOWN_SEED, user.key().as_ref()],
+        bump = bumps.user_cooldown,
+    )]
+    pub user_cooldown: Account<'info, UserCooldown>,
+
     /// The token mint account that must be a valid SPL token
     pub token_mint: Account<'info, Mint>,
 
-    /// Treasury account that receives all fees
-    /// CHECK: Treasury address is validated in instruction
+    /// Optional user token account for `token_mint`, unlocks holder fee discounts
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Mint profile of the placed token (PDA from token mint)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = MintProfile::SIZE,
+        seeds = [MINT_PROFILE_SEED, token_mint.key().as_ref()],
+        bump,
+    )]
+    pub mint_profile: Box<Account<'info, MintProfile>>,
+
+    /// Mint profile of the token being overwritten, required when it differs from `token_mint`
     #[account(mut)]
-    pub treasury: AccountInfo<'info>,
+    pub previous_mint_profile: Option<Box<Account<'info, MintProfile>>>,
+
+    /// Deny list entry of the token mint, must be passed even when the mint is not blocked
+    /// CHECK: Derivation is checked here, the handler only looks at whether it exists
+    #[account(seeds = [DENIED_MINT_SEED, token_mint.key().as_ref()], bump = bumps.denied_mint)]
+    pub denied_mint: UncheckedAccount<'info>,
+
+    /// Deny list entry of the user, must be passed even when the user is not blocked
+    /// CHECK: Derivation is checked here, the handler only looks at whether it exists
+    #[account(seeds = [DENIED_USER_SEED, user.key().as_ref()], bump = bumps.denied_user)]
+    pub denied_user: UncheckedAccount<'info>,
+
+    /// Restricted zone of the target cell's tile, must be passed even when the tile has none
+    /// CHECK: This PDA is manually validated in the instruction handler (the IDL cannot express tile seeds)
+    pub restricted_zone: UncheckedAccount<'info>,
+
+    /// Treasury account that receives all fees
+    /// CHECK: Treasury address is validated by constraint
+    #[account(mut, address = TREASURY_ADDRESS @ SolplaceError::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
 
     /// User account that pays for the placement
     #[account(mut)]
@@ -31,190 +102,227 @@ pub struct PlaceLogo<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Bumps of the PDAs `place_logo` takes, found once by the client so the program checks each
+/// address with `create_program_address` instead of searching for the bump
+/// The mint profile is created by `init_if_needed`, which finds its canonical bump itself
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PlacementBumps {
+    pub logo_placement: u8,
+    pub user_cooldown: u8,
+    pub denied_mint: u8,
+    pub denied_user: u8,
+    pub restricted_zone: u8,
+}
+
 pub fn handler(
-    ctx: Context<PlaceLogo>,
+    mut ctx: Context<PlaceLogo>,
     lat: i32,
     lng: i32,
+    bumps: PlacementBumps,
     token_mint: Pubkey,
     logo_uri: String,
+    logo_hash: [u8; 32],
 ) -> Result<()> {
     let clock = Clock::get()?;
     let current_timestamp = clock.unix_timestamp;
+    let user = ctx.accounts.user.key();
 
     // 1. Validate coordinates
     validate_coordinates(lat, lng)?;
 
-    // 2. Validate logo URI length
-    require!(
-        logo_uri.len() <= MAX_LOGO_URI_LENGTH,
-        SolplaceError::LogoUriTooLong
-    );
+    // 2. Validate and normalize logo URI
+    let logo_uri = validate_logo_uri(&logo_uri, &ctx.accounts.config.allowed_domains)?;
 
-    // 3. Validate token mint matches the account
+    // 3. Validate logo content hash (SHA-256 of the image bytes)
+    require!(logo_hash != [0; 32], SolplaceError::MissingLogoHash);
+
+    // 4. Validate token mint matches the account
     require!(
         ctx.accounts.token_mint.key() == token_mint,
         SolplaceError::InvalidTokenMint
     );
 
-    // 4. Get logo placement PDA and validate
-    let (logo_placement_pda, logo_bump) = get_logo_placement_pda(lat, lng, ctx.program_id);
+    // 5. A token with a canonical logo is only placed with that logo
+    let mint_profile = &ctx.accounts.mint_profile;
     require!(
-        ctx.accounts.logo_placement.key() == logo_placement_pda,
-        SolplaceError::InvalidLogoPlacement
+        !mint_profile.has_canonical_logo()
+            || (logo_uri == mint_profile.canonical_uri && logo_hash == mint_profile.canonical_hash),
+        SolplaceError::CanonicalLogoMismatch
     );
 
-    // 5. Get user cooldown PDA and validate
-    let (cooldown_pda, cooldown_bump) = Pubkey::find_program_address(
-        &[COOLDOWN_SEED, ctx.accounts.user.key().as_ref()],
-        ctx.program_id,
+    // 6. Reject blocked mints and users (only this program can create the deny list PDAs)
+    // The addresses are checked with the supplied bumps alone, an existing entry is a denial
+    require!(
+        is_absent(&ctx.accounts.denied_mint),
+        SolplaceError::MintDenied
     );
     require!(
-        ctx.accounts.user_cooldown.key() == cooldown_pda,
-        SolplaceError::InvalidCooldown
+        is_absent(&ctx.accounts.denied_user),
+        SolplaceError::UserDenied
     );
 
-    // 6. Initialize or load logo placement account
-    let is_overwrite = !ctx.accounts.logo_placement.data_is_empty();
-    let mut logo_data = if is_overwrite {
-        // Load existing logo placement
-        let logo_account_data = ctx.accounts.logo_placement.try_borrow_data()?;
-        let mut logo_data_slice = &logo_account_data[..];
-        LogoPlacement::try_deserialize(&mut logo_data_slice)?
+    // 7. Reject coordinates inside a restricted zone of the cell's tile
+    let tile = tile_of(lat, lng);
+    let restricted_zone_pda = Pubkey::create_program_address(
+        &[
+            RESTRICTED_ZONE_SEED,
+            &tile[0].to_le_bytes(),
+            &tile[1].to_le_bytes(),
+            &[bumps.restricted_zone],
+        ],
+        ctx.program_id,
+    )
+    .map_err(|_| SolplaceError::InvalidRestrictedZone)?;
+    require!(
+        ctx.accounts.restricted_zone.key() == restricted_zone_pda,
+        SolplaceError::InvalidRestrictedZone
+    );
+    if ctx.accounts.restricted_zone.owner == ctx.program_id {
+        let zone_account_data = ctx.accounts.restricted_zone.try_borrow_data()?;
+        let restricted_zone = RestrictedZone::try_deserialize(&mut &zone_account_data[..])?;
+        require!(
+            !restricted_zone.contains(lat, lng),
+            SolplaceError::PlacementRestricted
+        );
     } else {
-        // Create new logo placement account
-        let space = LogoPlacement::SIZE;
-        let rent = Rent::get()?;
-        let lamports = rent.minimum_balance(space);
-
-        // Create account with PDA seeds
-        anchor_lang::system_program::create_account(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::CreateAccount {
-                    from: ctx.accounts.user.to_account_info(),
-                    to: ctx.accounts.logo_placement.to_account_info(),
-                },
-                &[&[
-                    LOGO_PLACEMENT_SEED,
-                    &lat.to_le_bytes(),
-                    &lng.to_le_bytes(),
-                    &[logo_bump],
-                ]],
-            ),
-            lamports,
-            space as u64,
-            ctx.program_id,
-        )?;
-
-        // Initialize logo data
-        LogoPlacement {
-            coordinates: [lat, lng],
-            token_mint: Pubkey::default(),  // Will be set below
-            logo_uri: String::new(),        // Will be set below
-            logo_hash: [0; 32],            // Will be set below
-            placed_by: Pubkey::default(),   // Will be set below
-            placed_at: 0,                  // Will be set below
-            overwrite_count: 0,
-            bump: logo_bump,
-        }
-    };
+        require!(
+            is_absent(&ctx.accounts.restricted_zone),
+            SolplaceError::InvalidRestrictedZone
+        );
+    }
 
-    // 7. Initialize or load user cooldown
-    let mut cooldown_data = if ctx.accounts.user_cooldown.data_is_empty() {
-        // Initialize new cooldown account
-        let space = UserCooldown::SIZE;
-        let rent = Rent::get()?;
-        let lamports = rent.minimum_balance(space);
-
-        // Create account
-        anchor_lang::system_program::create_account(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::CreateAccount {
-                    from: ctx.accounts.user.to_account_info(),
-                    to: ctx.accounts.user_cooldown.to_account_info(),
-                },
-                &[&[
-                    COOLDOWN_SEED,
-                    ctx.accounts.user.key().as_ref(),
-                    &[cooldown_bump],
-                ]],
-            ),
-            lamports,
-            space as u64,
-            ctx.program_id,
-        )?;
-
-        UserCooldown {
-            user: ctx.accounts.user.key(),
-            last_placement: 0,
-            placement_count: 0,
-            bump: cooldown_bump,
+    // 8. Upgrade v0 accounts, then load the placement and cooldown, creating missing ones
+    let user_info = ctx.accounts.user.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let logo_placement_info = ctx.accounts.logo_placement.to_account_info();
+    let user_cooldown_info = ctx.accounts.user_cooldown.to_account_info();
+    for account in [&logo_placement_info, &user_cooldown_info] {
+        if let Some(to_version) = upgrade_v0(account, &user_info, &system_program, ctx.program_id)? {
+            emit_cpi!(AccountMigratedEvent {
+                account: account.key(),
+                from_version: 0,
+                to_version,
+            });
         }
-    } else {
-        // Load existing cooldown
-        let cooldown_account_data = ctx.accounts.user_cooldown.try_borrow_data()?;
-        let mut cooldown_data_slice = &cooldown_account_data[..];
-        UserCooldown::try_deserialize(&mut cooldown_data_slice)?
-    };
+    }
+    let (mut logo_placement, placement_created): (LogoPlacement, _) = load_or_create(
+        &logo_placement_info,
+        &user_info,
+        &system_program,
+        &[LOGO_PLACEMENT_SEED, &lat.to_le_bytes(), &lng.to_le_bytes()],
+        bumps.logo_placement,
+        LogoPlacement::SIZE,
+        ctx.program_id,
+        "logo_placement",
+    )?;
+    let (mut user_cooldown, cooldown_created): (UserCooldown, _) = load_or_create(
+        &user_cooldown_info,
+        &user_info,
+        &system_program,
+        &[COOLDOWN_SEED, user.as_ref()],
+        bumps.user_cooldown,
+        UserCooldown::SIZE,
+        ctx.program_id,
+        "user_cooldown",
+    )?;
 
-    // 8. Check user cooldown
-    if cooldown_data.last_placement != 0 {
+    // 9. Check user cooldown (a freshly created cooldown has no previous placement)
+    if user_cooldown.last_placement != 0 {
         require!(
-            !cooldown_data.is_on_cooldown(current_timestamp),
+            !user_cooldown.is_on_cooldown(current_timestamp),
             SolplaceError::UserOnCooldown
         );
     }
 
-    // 9. Calculate placement fee
-    let placement_fee = if is_overwrite {
-        BASE_PLACEMENT_FEE * OVERWRITE_MULTIPLIER
-    } else {
-        BASE_PLACEMENT_FEE
-    };
+    // Fresh and moderated cells are both blank, and are priced and counted like empty cells
+    let is_overwrite = !logo_placement.is_blank();
 
-    // 10. Collect fee (transfer SOL from user to treasury)
+    // 10. Calculate placement fee, discounted for token holders
+    let holder_discount_bps = get_holder_discount(&ctx, &token_mint)?;
+    let base_fee = calculate_placement_fee(is_overwrite, 0);
+    let placement_fee = calculate_placement_fee(is_overwrite, holder_discount_bps);
+
+    // 11. Collect fee (transfer SOL from user to treasury)
     collect_fee(&ctx, placement_fee)?;
 
-    // 11. Calculate logo hash
-    let logo_hash = hash_logo_uri(&logo_uri);
 
-    // 12. Update logo placement data
-    logo_data.coordinates = [lat, lng];
-    logo_data.token_mint = token_mint;
-    logo_data.logo_uri = logo_uri.clone();
-    logo_data.logo_hash = logo_hash;
-    logo_data.placed_by = ctx.accounts.user.key();
-    logo_data.placed_at = current_timestamp;
+    // A mint profile `init_if_needed` created for this placement is still zeroed
+    let rent = Rent::get()?;
+    let rent_paid = [
+        (placement_created, LogoPlacement::SIZE),
+        (cooldown_created, UserCooldown::SIZE),
+        (ctx.accounts.mint_profile.token_mint == Pubkey::default(), MintProfile::SIZE),
+    ]
+    .into_iter()
+    .filter(|(created, _)| *created)
+    .map(|(_, size)| rent.minimum_balance(size))
+    .sum();
+
+    // 12. Update mint profile statistics
+    let previous_mint = logo_placement.token_mint;
+    let previous_placed_by = logo_placement.placed_by;
+    let previous_placed_at = logo_placement.placed_at;
+    update_mint_profiles(&mut ctx, is_overwrite, previous_mint, placement_fee)?;
+
+    // 13. Update logo placement data
+    logo_placement.version = LogoPlacement::VERSION;
+    logo_placement.coordinates = [lat, lng];
+    logo_placement.token_mint = token_mint;
+    logo_placement.logo_uri = logo_uri.clone();
+    logo_placement.logo_hash = logo_hash;
+    logo_placement.placed_by = user;
+    logo_placement.placed_at = current_timestamp;
+    logo_placement.bump = bumps.logo_placement;
     if is_overwrite {
-        logo_data.overwrite_count += 1;
+        logo_placement.overwrite_count += 1;
     }
-
-    // 13. Update user cooldown
-    cooldown_data.last_placement = current_timestamp;
-    cooldown_data.placement_count += 1;
-
-    // 14. Serialize and save logo placement data
-    let mut logo_account_data = ctx.accounts.logo_placement.try_borrow_mut_data()?;
-    
-    // Use Anchor's built-in serialization which includes discriminator
-    let mut dst = &mut logo_account_data[..];
-    logo_data.try_serialize(&mut dst)?;
-
-    // 15. Serialize and save cooldown data
-    let mut cooldown_account_data = ctx.accounts.user_cooldown.try_borrow_mut_data()?;
-    
-    // Use Anchor's built-in serialization which includes discriminator
-    let mut dst = &mut cooldown_account_data[..];
-    cooldown_data.try_serialize(&mut dst)?;
-
-    // 16. Emit event
-    emit!(LogoPlacedEvent {
-        user: ctx.accounts.user.key(),
+    let overwrite_count = logo_placement.overwrite_count;
+    store(&logo_placement_info, &logo_placement)?;
+
+    // 14. Update user cooldown
+    user_cooldown.version = UserCooldown::VERSION;
+    user_cooldown.user = user;
+    user_cooldown.last_placement = current_timestamp;
+    user_cooldown.placement_count += 1;
+    user_cooldown.bump = bumps.user_cooldown;
+    let placement_count = user_cooldown.placement_count;
+    store(&user_cooldown_info, &user_cooldown)?;
+
+    // 15. Emit events
+    let cell = logo_placement_info.key();
+    emit_cpi!(LogoPlacedEvent {
+        user,
+        cell,
         lat,
         lng,
         token_mint,
         logo_uri,
+        logo_hash,
+        previous_token_mint: is_overwrite.then_some(previous_mint),
+        previous_placed_by: is_overwrite.then_some(previous_placed_by),
+        previous_placed_at: is_overwrite.then_some(previous_placed_at),
+        overwrite_count,
+        base_fee,
+        holder_discount: base_fee - placement_fee,
+        holder_discount_bps,
+        fee_paid: placement_fee,
+        rent_paid,
+        is_overwrite,
+        timestamp: current_timestamp,
+    });
+    emit_cpi!(CooldownUpdatedEvent {
+        user,
+        last_placement: current_timestamp,
+        placement_count,
+        cooldown_ends_at: current_timestamp + COOLDOWN_PERIOD,
+    });
+    emit_cpi!(FeeCollectedEvent {
+        user,
+        cell,
+        token_mint,
+        base_fee,
+        holder_discount: base_fee - placement_fee,
+        holder_discount_bps,
         fee_paid: placement_fee,
         is_overwrite,
         timestamp: current_timestamp,
@@ -225,12 +333,6 @@ pub fn handler(
 
 // Helper functions
 fn collect_fee(ctx: &Context<PlaceLogo>, amount: u64) -> Result<()> {
-    // Check that treasury is the expected address
-    require!(
-        ctx.accounts.treasury.key() == TREASURY_ADDRESS,
-        SolplaceError::InvalidTreasury
-    );
-
     // Transfer SOL from user to treasury
     anchor_lang::system_program::transfer(
         CpiContext::new(
@@ -246,19 +348,116 @@ fn collect_fee(ctx: &Context<PlaceLogo>, amount: u64) -> Result<()> {
     Ok(())
 }
 
-fn hash_logo_uri(logo_uri: &str) -> [u8; 32] {
-    use anchor_lang::solana_program::hash::hash;
-    hash(logo_uri.as_bytes()).to_bytes()
+fn update_mint_profiles(
+    ctx: &mut Context<PlaceLogo>,
+    is_overwrite: bool,
+    previous_mint: Pubkey,
+    placement_fee: u64,
+) -> Result<()> {
+    let token_mint = ctx.accounts.token_mint.key();
+    let takes_cell = !is_overwrite || previous_mint != token_mint;
+
+    // Release the cell from the token being displaced
+    if is_overwrite && takes_cell {
+        let previous_profile = ctx
+            .accounts
+            .previous_mint_profile
+            .as_mut()
+            .ok_or(SolplaceError::MissingPreviousMintProfile)?;
+        require!(
+            previous_profile.token_mint == previous_mint
+                && previous_profile.key() != ctx.accounts.mint_profile.key(),
+            SolplaceError::InvalidMintProfile
+        );
+        previous_profile.release_cell();
+    } else {
+        // Only one copy of a profile may be written back on exit
+        require!(
+            ctx.accounts.previous_mint_profile.is_none(),
+            SolplaceError::InvalidMintProfile
+        );
+    }
+
+    let mint_profile = &mut ctx.accounts.mint_profile;
+    mint_profile.token_mint = token_mint;
+    mint_profile.bump = ctx.bumps.mint_profile;
+    mint_profile.record_placement(placement_fee, takes_cell);
+
+    Ok(())
+}
+
+/// Whether no account was ever created at `account`, only lamports may have been sent to it
+fn is_absent(account: &AccountInfo) -> bool {
+    account.owner == &anchor_lang::system_program::ID && account.data_is_empty()
+}
+
+fn get_holder_discount(ctx: &Context<PlaceLogo>, token_mint: &Pubkey) -> Result<u16> {
+    let Some(token_account) = ctx.accounts.user_token_account.as_ref() else {
+        return Ok(0);
+    };
+
+    holder_token_discount(
+        token_account,
+        &ctx.accounts.user.key(),
+        token_mint,
+        ctx.accounts.token_mint.supply,
+    )
 }
 
 // Events
 #[event]
 pub struct LogoPlacedEvent {
     pub user: Pubkey,
+    /// Logo placement PDA of the cell
+    pub cell: Pubkey,
     pub lat: i32,
     pub lng: i32,
     pub token_mint: Pubkey,
     pub logo_uri: String,
+    pub logo_hash: [u8; 32],
+    /// Token shown before this placement, `None` for empty and moderated cells
+    pub previous_token_mint: Option<Pubkey>,
+    /// Wallet that placed `previous_token_mint`
+    pub previous_placed_by: Option<Pubkey>,
+    /// When `previous_token_mint` was placed
+    pub previous_placed_at: Option<i64>,
+    /// Times the cell has been overwritten, this placement included
+    pub overwrite_count: u16,
+    /// Fee before the holder discount
+    pub base_fee: u64,
+    /// Lamports taken off `base_fee` by the holder discount
+    pub holder_discount: u64,
+    pub holder_discount_bps: u16,
+    /// Fee transferred to the treasury, `base_fee - holder_discount`
+    pub fee_paid: u64,
+    /// Rent deposited into the accounts the placement created
+    pub rent_paid: u64,
+    pub is_overwrite: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CooldownUpdatedEvent {
+    pub user: Pubkey,
+    pub last_placement: i64,
+    /// Placements by the user, this one included
+    pub placement_count: u32,
+    /// First timestamp the user can place again
+    pub cooldown_ends_at: i64,
+}
+
+#[event]
+pub struct FeeCollectedEvent {
+    pub user: Pubkey,
+    /// Logo placement PDA of the cell
+    pub cell: Pubkey,
+    pub token_mint: Pubkey,
+    /// Fee before the holder discount
+    pub base_fee: u64,
+    /// Lamports taken off `base_fee` by the holder discount
+    pub holder_discount: u64,
+    pub holder_discount_bps: u16,
+    /// Lamports transferred to the treasury
     pub fee_paid: u64,
     pub is_overwrite: bool,
     pub timestamp: i64,
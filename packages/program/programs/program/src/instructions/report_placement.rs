@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(lat: i32, lng: i32)]
+pub struct ReportPlacement<'info> {
+    /// Program configuration (PDA), reports are refused while paused
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ SolplaceError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Placement being reported
+    #[account(
+        seeds = [LOGO_PLACEMENT_SEED, &lat.to_le_bytes(), &lng.to_le_bytes()],
+        bump = logo_placement.bump,
+    )]
+    pub logo_placement: Account<'info, LogoPlacement>,
+
+    /// Report account (PDA from placement and reporter)
+    #[account(
+        init,
+        payer = reporter,
+        space = PlacementReport::SIZE,
+        seeds = [REPORT_SEED, logo_placement.key().as_ref(), reporter.key().as_ref()],
+        bump,
+    )]
+    pub report: Account<'info, PlacementReport>,
+
+    /// Report counter of the placement (PDA from placement)
+    #[account(
+        init_if_needed,
+        payer = reporter,
+        space = ReportTally::SIZE,
+        seeds = [REPORT_TALLY_SEED, logo_placement.key().as_ref()],
+        bump,
+    )]
+    pub report_tally: Account<'info, ReportTally>,
+
+    /// User filing the report, pays rent and stake
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ReportPlacement>,
+    lat: i32,
+    lng: i32,
+    reason: ModerationReason,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let logo_placement = &ctx.accounts.logo_placement;
+
+    // 1. Only live logos of other users can be reported
+    require!(!logo_placement.is_blank(), SolplaceError::PlacementAlreadyBlank);
+    require!(
+        logo_placement.placed_by != ctx.accounts.reporter.key(),
+        SolplaceError::CannotReportOwnPlacement
+    );
+
+    // 2. Lock the stake in the report account
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.reporter.to_account_info(),
+                to: ctx.accounts.report.to_account_info(),
+            },
+        ),
+        REPORT_STAKE,
+    )?;
+
+    let report = &mut ctx.accounts.report;
+    report.logo_placement = logo_placement.key();
+    report.reporter = ctx.accounts.reporter.key();
+    report.placed_at = logo_placement.placed_at;
+    report.reason = reason;
+    report.stake = REPORT_STAKE;
+    report.created_at = clock.unix_timestamp;
+    report.bump = ctx.bumps.report;
+
+    // 3. Count the report and hide the logo once the threshold is reached
+    let report_tally = &mut ctx.accounts.report_tally;
+    report_tally.sync(logo_placement.key(), logo_placement.placed_at);
+    report_tally.bump = ctx.bumps.report_tally;
+    report_tally.report_count += 1;
+
+    emit_cpi!(PlacementReportedEvent {
+        lat,
+        lng,
+        reporter: report.reporter,
+        reason,
+        report_count: report_tally.report_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    if !report_tally.hidden && report_tally.report_count >= REPORT_HIDE_THRESHOLD {
+        report_tally.hidden = true;
+        emit_cpi!(PlacementHiddenEvent {
+            lat,
+            lng,
+            token_mint: logo_placement.token_mint,
+            placed_at: logo_placement.placed_at,
+            report_count: report_tally.report_count,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
+
+// Events
+#[event]
+pub struct PlacementReportedEvent {
+    pub lat: i32,
+    pub lng: i32,
+    pub reporter: Pubkey,
+    pub reason: ModerationReason,
+    pub report_count: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlacementHiddenEvent {
+    pub lat: i32,
+    pub lng: i32,
+    pub token_mint: Pubkey,
+    /// `placed_at` of the hidden placement
+    pub placed_at: i64,
+    pub report_count: u16,
+    pub timestamp: i64,
+}
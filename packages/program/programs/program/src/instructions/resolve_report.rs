@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ResolveReport<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = moderator @ SolplaceError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Report being resolved, closed to the reporter
+    #[account(
+        mut,
+        seeds = [REPORT_SEED, report.logo_placement.as_ref(), reporter.key().as_ref()],
+        bump = report.bump,
+        has_one = reporter @ SolplaceError::InvalidReporter,
+        close = reporter,
+    )]
+    pub report: Account<'info, PlacementReport>,
+
+    /// Report counter of the reported placement
+    #[account(
+        mut,
+        seeds = [REPORT_TALLY_SEED, report.logo_placement.as_ref()],
+        bump = report_tally.bump,
+    )]
+    pub report_tally: Account<'info, ReportTally>,
+
+    /// User that filed the report, receives rent and refunded stake
+    /// CHECK: Address is checked against the report
+    #[account(mut)]
+    pub reporter: UncheckedAccount<'info>,
+
+    /// Treasury account that receives slashed stakes
+    /// CHECK: Treasury address is validated by constraint
+    #[account(mut, address = TREASURY_ADDRESS @ SolplaceError::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
+
+    pub moderator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ResolveReport>, resolution: ReportResolution) -> Result<()> {
+    let clock = Clock::get()?;
+    let report = &ctx.accounts.report;
+    let report_tally = &mut ctx.accounts.report_tally;
+
+    // 1. Slash the stake of rejected reports, the rest is returned when the report closes
+    if resolution == ReportResolution::Rejected {
+        report.sub_lamports(report.stake)?;
+        ctx.accounts.treasury.add_lamports(report.stake)?;
+    }
+
+    // 2. An upheld report keeps the rent of a later takedown from the placer
+    if resolution == ReportResolution::Upheld && report_tally.placed_at == report.placed_at {
+        report_tally.upheld_count = report_tally.upheld_count.saturating_add(1);
+    }
+
+    // 3. A rejected report no longer counts towards hiding the current placement
+    if resolution == ReportResolution::Rejected
+        && report_tally.placed_at == report.placed_at
+        && report_tally.report_count > 0
+    {
+        report_tally.report_count -= 1;
+        if report_tally.hidden && report_tally.report_count < REPORT_HIDE_THRESHOLD {
+            report_tally.hidden = false;
+            emit_cpi!(PlacementUnhiddenEvent {
+                logo_placement: report.logo_placement,
+                placed_at: report_tally.placed_at,
+                report_count: report_tally.report_count,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
+
+    emit_cpi!(ReportResolvedEvent {
+        logo_placement: report.logo_placement,
+        reporter: report.reporter,
+        moderator: ctx.accounts.moderator.key(),
+        resolution,
+        stake: report.stake,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Events
+#[event]
+pub struct ReportResolvedEvent {
+    pub logo_placement: Pubkey,
+    pub reporter: Pubkey,
+    pub moderator: Pubkey,
+    pub resolution: ReportResolution,
+    pub stake: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlacementUnhiddenEvent {
+    pub logo_placement: Pubkey,
+    /// `placed_at` of the placement shown again
+    pub placed_at: i64,
+    pub report_count: u16,
+    pub timestamp: i64,
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::state::*;
+use crate::uri::validate_logo_uri;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetCanonicalLogo<'info> {
+    /// Program configuration (PDA), changes are refused while paused
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ SolplaceError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Mint profile account (PDA from token mint)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = MintProfile::SIZE,
+        seeds = [MINT_PROFILE_SEED, token_mint.key().as_ref()],
+        bump,
+    )]
+    pub mint_profile: Account<'info, MintProfile>,
+
+    /// The token mint whose canonical logo is being set
+    pub token_mint: Account<'info, Mint>,
+
+    /// User token account for `token_mint`, required unless the user is the mint authority
+    /// A claim or challenge needs a nonzero balance
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Mint authority, or any user claiming/challenging the canonical logo
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetCanonicalLogo>,
+    logo_uri: String,
+    logo_hash: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // 1. Validate and normalize logo URI
+    let logo_uri = validate_logo_uri(&logo_uri, &ctx.accounts.config.allowed_domains)?;
+    require!(logo_hash != [0; 32], SolplaceError::MissingLogoHash);
+
+    // 2. Resolve the claimant's standing
+    let user = ctx.accounts.user.key();
+    let is_mint_authority = ctx.accounts.token_mint.mint_authority == Some(user).into();
+
+    let claimant_balance = match ctx.accounts.user_token_account.as_ref() {
+        Some(token_account) => {
+            require!(
+                token_account.owner == user
+                    && token_account.mint == ctx.accounts.token_mint.key(),
+                SolplaceError::InvalidHolderTokenAccount
+            );
+            token_account.amount
+        }
+        None => 0,
+    };
+
+    // 3. Check the claim against the current canonical logo
+    require!(
+        is_mint_authority || claimant_balance > 0,
+        SolplaceError::CanonicalClaimWithoutBalance
+    );
+    let mint_profile = &mut ctx.accounts.mint_profile;
+    require!(
+        mint_profile.can_set_canonical(is_mint_authority, claimant_balance),
+        SolplaceError::CanonicalLogoLocked
+    );
+
+    // 4. Update the canonical logo
+    mint_profile.token_mint = ctx.accounts.token_mint.key();
+    mint_profile.canonical_hash = logo_hash;
+    mint_profile.canonical_uri = logo_uri.clone();
+    mint_profile.canonical_set_by = user;
+    mint_profile.canonical_claim_balance = claimant_balance;
+    mint_profile.canonical_locked = is_mint_authority;
+    mint_profile.canonical_set_at = clock.unix_timestamp;
+    mint_profile.bump = ctx.bumps.mint_profile;
+
+    emit_cpi!(CanonicalLogoSetEvent {
+        token_mint: mint_profile.token_mint,
+        logo_uri,
+        logo_hash,
+        set_by: user,
+        is_mint_authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Events
+#[event]
+pub struct CanonicalLogoSetEvent {
+    pub token_mint: Pubkey,
+    pub logo_uri: String,
+    pub logo_hash: [u8; 32],
+    pub set_by: Pubkey,
+    pub is_mint_authority: bool,
+    pub timestamp: i64,
+}
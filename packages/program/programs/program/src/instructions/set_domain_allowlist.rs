@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::state::*;
+use crate::uri::validate_domain;
+
+#[derive(Accounts)]
+pub struct SetDomainAllowlist<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ SolplaceError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetDomainAllowlist>, allowed_domains: Vec<String>) -> Result<()> {
+    require!(
+        allowed_domains.len() <= MAX_ALLOWED_DOMAINS,
+        SolplaceError::TooManyDomains
+    );
+    for domain in &allowed_domains {
+        validate_domain(domain)?;
+    }
+
+    ctx.accounts.config.allowed_domains = allowed_domains;
+    Ok(())
+}
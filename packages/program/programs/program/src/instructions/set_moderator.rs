@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetModerator<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ SolplaceError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetModerator>, moderator: Pubkey) -> Result<()> {
+    ctx.accounts.config.moderator = moderator;
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(tile_lat: i16, tile_lng: i16)]
+pub struct SetRestrictedZone<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ SolplaceError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Restricted zone of the tile (PDA from tile indices)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = RestrictedZone::SIZE,
+        seeds = [RESTRICTED_ZONE_SEED, &tile_lat.to_le_bytes(), &tile_lng.to_le_bytes()],
+        bump,
+    )]
+    pub restricted_zone: Account<'info, RestrictedZone>,
+
+    pub admin: Signer<'info>,
+
+    /// Pays rent, separate from the admin so PDA authorities holding data can sign
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetRestrictedZone>,
+    tile_lat: i16,
+    tile_lng: i16,
+    shapes: Vec<ZoneShape>,
+) -> Result<()> {
+    let tile = [tile_lat, tile_lng];
+
+    // 1. Validate the tile and every shape in it
+    require!(
+        (MIN_LATITUDE / ZONE_TILE_SIZE..=MAX_LATITUDE / ZONE_TILE_SIZE).contains(&(tile_lat as i32))
+            && (MIN_LONGITUDE / ZONE_TILE_SIZE..=MAX_LONGITUDE / ZONE_TILE_SIZE)
+                .contains(&(tile_lng as i32)),
+        SolplaceError::InvalidZoneTile
+    );
+    require!(
+        shapes.len() <= MAX_ZONE_SHAPES,
+        SolplaceError::TooManyZoneShapes
+    );
+    for shape in &shapes {
+        shape.validate(tile)?;
+    }
+
+    // 2. Replace the tile's shapes
+    let restricted_zone = &mut ctx.accounts.restricted_zone;
+    restricted_zone.tile = tile;
+    restricted_zone.shapes = shapes;
+    restricted_zone.bump = ctx.bumps.restricted_zone;
+
+    emit_cpi!(RestrictedZoneSetEvent {
+        tile_lat,
+        tile_lng,
+        shape_count: restricted_zone.shapes.len() as u8,
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Events
+#[event]
+pub struct RestrictedZoneSetEvent {
+    pub tile_lat: i16,
+    pub tile_lng: i16,
+    pub shape_count: u8,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(lat: i32, lng: i32)]
+pub struct WriteLogoBitmap<'info> {
+    /// Program configuration (PDA), uploads are refused while paused
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ SolplaceError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Logo placement the bitmap belongs to
+    #[account(
+        seeds = [LOGO_PLACEMENT_SEED, &lat.to_le_bytes(), &lng.to_le_bytes()],
+        bump = logo_placement.bump,
+    )]
+    pub logo_placement: Account<'info, LogoPlacement>,
+
+    /// Companion bitmap account (PDA from coordinates)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = LogoBitmap::SIZE,
+        seeds = [LOGO_BITMAP_SEED, &lat.to_le_bytes(), &lng.to_le_bytes()],
+        bump,
+    )]
+    pub logo_bitmap: Account<'info, LogoBitmap>,
+
+    /// Current placer of the logo, pays for the bitmap account
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<WriteLogoBitmap>,
+    lat: i32,
+    lng: i32,
+    total_len: u16,
+    offset: u16,
+    chunk: Vec<u8>,
+) -> Result<()> {
+    let logo_placement = &ctx.accounts.logo_placement;
+    let logo_bitmap = &mut ctx.accounts.logo_bitmap;
+
+    // 1. Only the current placer may describe the logo
+    require!(
+        logo_placement.placed_by == ctx.accounts.user.key(),
+        SolplaceError::NotLogoPlacer
+    );
+
+    // 2. Append the chunk, a chunk at offset 0 starts a new upload
+    logo_bitmap.write_chunk(logo_placement.placed_at, total_len, offset, &chunk)?;
+    if offset == 0 {
+        logo_bitmap.coordinates = [lat, lng];
+        logo_bitmap.uploader = ctx.accounts.user.key();
+        logo_bitmap.bump = ctx.bumps.logo_bitmap;
+    }
+
+    if logo_bitmap.is_complete() {
+        emit_cpi!(LogoBitmapWrittenEvent {
+            lat,
+            lng,
+            uploader: logo_bitmap.uploader,
+            placed_at: logo_bitmap.placed_at,
+            size: total_len,
+        });
+    }
+
+    Ok(())
+}
+
+// Events
+#[event]
+pub struct LogoBitmapWrittenEvent {
+    pub lat: i32,
+    pub lng: i32,
+    pub uploader: Pubkey,
+    pub placed_at: i64,
+    pub size: u16,
+}
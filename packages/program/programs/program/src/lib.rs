@@ -1,7 +1,11 @@
 pub mod constants;
 pub mod error;
+pub mod fees;
+pub mod geo;
 pub mod instructions;
+pub mod pda;
 pub mod state;
+pub mod uri;
 
 use anchor_lang::prelude::*;
 
@@ -20,21 +24,121 @@ pub mod solplace_program {
         instructions::initialize::handler(ctx)
     }
 
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Option<Pubkey>) -> Result<()> {
+        instructions::propose_admin::handler(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin::handler(ctx)
+    }
+
+    pub fn set_domain_allowlist(
+        ctx: Context<SetDomainAllowlist>,
+        allowed_domains: Vec<String>,
+    ) -> Result<()> {
+        instructions::set_domain_allowlist::handler(ctx, allowed_domains)
+    }
+
+    pub fn set_restricted_zone(
+        ctx: Context<SetRestrictedZone>,
+        tile_lat: i16,
+        tile_lng: i16,
+        shapes: Vec<ZoneShape>,
+    ) -> Result<()> {
+        instructions::set_restricted_zone::handler(ctx, tile_lat, tile_lng, shapes)
+    }
+
+    pub fn clear_restricted_zone(
+        ctx: Context<ClearRestrictedZone>,
+        tile_lat: i16,
+        tile_lng: i16,
+    ) -> Result<()> {
+        instructions::clear_restricted_zone::handler(ctx, tile_lat, tile_lng)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        instructions::set_paused::handler(ctx, paused)
+    }
+
+    pub fn set_moderator(ctx: Context<SetModerator>, moderator: Pubkey) -> Result<()> {
+        instructions::set_moderator::handler(ctx, moderator)
+    }
+
+    pub fn moderate_logo(
+        ctx: Context<ModerateLogo>,
+        lat: i32,
+        lng: i32,
+        reason: ModerationReason,
+        action: ModerationAction,
+    ) -> Result<()> {
+        instructions::moderate_logo::handler(ctx, lat, lng, reason, action)
+    }
+
+    pub fn deny_mint(
+        ctx: Context<DenyMint>,
+        token_mint: Pubkey,
+        reason: ModerationReason,
+    ) -> Result<()> {
+        instructions::deny_mint::handler(ctx, token_mint, reason)
+    }
+
+    pub fn allow_mint(ctx: Context<AllowMint>, token_mint: Pubkey) -> Result<()> {
+        instructions::allow_mint::handler(ctx, token_mint)
+    }
+
+    pub fn deny_user(ctx: Context<DenyUser>, user: Pubkey, reason: ModerationReason) -> Result<()> {
+        instructions::deny_user::handler(ctx, user, reason)
+    }
+
+    pub fn allow_user(ctx: Context<AllowUser>, user: Pubkey) -> Result<()> {
+        instructions::allow_user::handler(ctx, user)
+    }
+
+    pub fn report_placement(
+        ctx: Context<ReportPlacement>,
+        lat: i32,
+        lng: i32,
+        reason: ModerationReason,
+    ) -> Result<()> {
+        instructions::report_placement::handler(ctx, lat, lng, reason)
+    }
+
+    pub fn resolve_report(ctx: Context<ResolveReport>, resolution: ReportResolution) -> Result<()> {
+        instructions::resolve_report::handler(ctx, resolution)
+    }
+
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler(ctx)
+    }
+
     pub fn place_logo(
         ctx: Context<PlaceLogo>,
         lat: i32,
         lng: i32,
+        bumps: PlacementBumps,
         token_mint: Pubkey,
         logo_uri: String,
+        logo_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::place_logo::handler(ctx, lat, lng, bumps, token_mint, logo_uri, logo_hash)
+    }
+
+    pub fn set_canonical_logo(
+        ctx: Context<SetCanonicalLogo>,
+        logo_uri: String,
+        logo_hash: [u8; 32],
     ) -> Result<()> {
-        instructions::place_logo::handler(ctx, lat, lng, token_mint, logo_uri)
+        instructions::set_canonical_logo::handler(ctx, logo_uri, logo_hash)
     }
 
-    /// This instruction exists solely to expose LogoPlacement and UserCooldown types to the IDL
-    /// It should never be called directly
-    pub fn _expose_types(_ctx: Context<ExposeTypes>) -> Result<()> {
-        // This instruction is never meant to be executed
-        // It only exists to force Anchor to include our account types in the IDL
-        err!(error::SolplaceError::InvalidCoordinates)
+    pub fn write_logo_bitmap(
+        ctx: Context<WriteLogoBitmap>,
+        lat: i32,
+        lng: i32,
+        total_len: u16,
+        offset: u16,
+        chunk: Vec<u8>,
+    ) -> Result<()> {
+        instructions::write_logo_bitmap::handler(ctx, lat, lng, total_len, offset, chunk)
     }
 }
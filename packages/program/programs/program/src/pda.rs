@@ -0,0 +1,216 @@
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::*;
+use crate::error::SolplaceError;
+
+/// Decode the program account at the PDA `account`, creating it with `space` bytes when it
+/// does not exist yet, like Anchor's `init_if_needed`
+/// Returns the account and whether it was created
+///
+/// The address must already be checked against `seeds` and `bump`, an existing account proves
+/// its bump because the program only creates accounts at canonical addresses.
+#[allow(clippy::too_many_arguments)]
+pub fn load_or_create<'info, T: AccountDeserialize + Default>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    bump: u8,
+    space: usize,
+    program_id: &Pubkey,
+    account_name: &str,
+) -> Result<(T, bool)> {
+    if account.owner == &anchor_lang::system_program::ID {
+        require_canonical_bump(seeds, bump, program_id)?;
+        create_pda_account(account, payer, system_program, seeds, bump, space, program_id)?;
+        return Ok((T::default(), true));
+    }
+
+    if account.owner != program_id {
+        return Err(Error::from(ErrorCode::AccountOwnedByWrongProgram)
+            .with_account_name(account_name)
+            .with_pubkeys((*account.owner, *program_id)));
+    }
+    if account.data_len() != space {
+        return Err(Error::from(ErrorCode::ConstraintSpace)
+            .with_account_name(account_name)
+            .with_values((space, account.data_len())));
+    }
+    let value = T::try_deserialize(&mut &account.try_borrow_data()?[..])
+        .map_err(|err| err.with_account_name(account_name))?;
+    Ok((value, false))
+}
+
+/// Check that `bump` is the one `find_program_address` returns for `seeds`, so no other
+/// address can stand in for the account
+///
+/// Only addresses without an account need this. The check tries every higher bump and costs
+/// as much as `find_program_address`.
+pub fn require_canonical_bump(seeds: &[&[u8]], bump: u8, program_id: &Pubkey) -> Result<()> {
+    for higher in (bump..u8::MAX).map(|bump| bump + 1) {
+        let higher = [higher];
+        let mut seeds_with_bump = seeds.to_vec();
+        seeds_with_bump.push(&higher);
+        if Pubkey::create_program_address(&seeds_with_bump, program_id).is_ok() {
+            return err!(SolplaceError::NonCanonicalBump);
+        }
+    }
+    Ok(())
+}
+
+/// Write `value` back to the program account it was loaded from
+pub fn store<T: AccountSerialize>(account: &AccountInfo, value: &T) -> Result<()> {
+    let mut data = account.try_borrow_mut_data()?;
+    value.try_serialize(&mut &mut data[..])
+}
+
+/// Create a program-owned PDA account of `space` bytes, funded by `payer`
+/// Lamports already sent to the address count towards rent, like Anchor's `init`
+fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    bump: u8,
+    space: usize,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let bump = [bump];
+    let mut seeds_with_bump = seeds.to_vec();
+    seeds_with_bump.push(&bump);
+    let signer_seeds = [seeds_with_bump.as_slice()];
+
+    let required = Rent::get()?.minimum_balance(space);
+    let current = account.lamports();
+
+    if current == 0 {
+        return anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                &signer_seeds,
+            ),
+            required,
+            space as u64,
+            program_id,
+        );
+    }
+
+    if current < required {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required - current,
+        )?;
+    }
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &signer_seeds,
+        ),
+        space as u64,
+    )?;
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Assign {
+                account_to_assign: account.clone(),
+            },
+            &signer_seeds,
+        ),
+        program_id,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{ProgramConfig, UserCooldown};
+    use anchor_lang::Discriminator;
+
+    fn cooldown_data() -> Vec<u8> {
+        let cooldown = UserCooldown {
+            version: UserCooldown::VERSION,
+            user: Pubkey::new_unique(),
+            last_placement: 1_700_000_000,
+            placement_count: 3,
+            bump: 254,
+        };
+        let mut data = Vec::new();
+        cooldown.try_serialize(&mut data).unwrap();
+        data.resize(UserCooldown::SIZE, 0);
+        data
+    }
+
+    /// Load `data` owned by `owner`, the creation path is never taken
+    fn load(owner: &Pubkey, data: &mut [u8]) -> Result<(UserCooldown, bool)> {
+        let key = Pubkey::new_unique();
+        let system_program = anchor_lang::system_program::ID;
+        let (mut lamports, mut payer_lamports, mut program_lamports) = (1, 0, 0);
+        let (mut payer_data, mut program_data) = (Vec::new(), Vec::new());
+        let account = AccountInfo::new(&key, false, true, &mut lamports, data, owner, false, 0);
+        let payer = AccountInfo::new(
+            &key, true, true, &mut payer_lamports, &mut payer_data, &system_program, false, 0,
+        );
+        let program = AccountInfo::new(
+            &system_program, false, false, &mut program_lamports, &mut program_data,
+            &system_program, true, 0,
+        );
+        load_or_create(
+            &account, &payer, &program, &[b"cooldown"], 254, UserCooldown::SIZE, &crate::ID,
+            "user_cooldown",
+        )
+    }
+
+    #[test]
+    fn loads_existing_accounts() {
+        let (cooldown, created) = load(&crate::ID, &mut cooldown_data()).unwrap();
+        assert!(!created);
+        assert_eq!((cooldown.placement_count, cooldown.bump), (3, 254));
+    }
+
+    #[test]
+    fn only_creates_at_canonical_bumps() {
+        let seeds: &[&[u8]] = &[b"cooldown", &[7; 32]];
+        let (_, canonical) = Pubkey::find_program_address(seeds, &crate::ID);
+        assert!(require_canonical_bump(seeds, canonical, &crate::ID).is_ok());
+
+        let lower = (0..canonical)
+            .rev()
+            .find(|bump| {
+                Pubkey::create_program_address(&[seeds[0], seeds[1], &[*bump]], &crate::ID).is_ok()
+            })
+            .unwrap();
+        assert_eq!(
+            require_canonical_bump(seeds, lower, &crate::ID).unwrap_err(),
+            SolplaceError::NonCanonicalBump.into()
+        );
+    }
+
+    #[test]
+    fn rejects_foreign_and_resized_accounts() {
+        let foreign = load(&Pubkey::new_unique(), &mut cooldown_data()).err().unwrap();
+        assert_eq!(foreign, ErrorCode::AccountOwnedByWrongProgram.into());
+
+        let mut data = cooldown_data();
+        data.push(0);
+        assert_eq!(load(&crate::ID, &mut data).err().unwrap(), ErrorCode::ConstraintSpace.into());
+
+        let mut config = ProgramConfig::DISCRIMINATOR.to_vec();
+        config.resize(UserCooldown::SIZE, 0);
+        assert_eq!(
+            load(&crate::ID, &mut config).err().unwrap(),
+            ErrorCode::AccountDiscriminatorMismatch.into()
+        );
+    }
+}
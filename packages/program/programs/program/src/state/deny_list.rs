@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::ModerationReason;
+
+/// An address blocked from placing logos, a token mint (PDA from `DENIED_MINT_SEED` and the mint)
+/// or a wallet (PDA from `DENIED_USER_SEED` and the wallet)
+/// The account existing is what blocks the address, closing it lifts the block
+#[account]
+pub struct DenyListEntry {
+    /// Blocked token mint or user wallet address
+    pub address: Pubkey,
+    /// Why the address was blocked
+    pub reason: ModerationReason,
+    /// Moderator that blocked the address
+    pub denied_by: Pubkey,
+    /// Unix timestamp when blocked
+    pub denied_at: i64,
+    /// Wallet that paid the rent, refunded when the block is lifted
+    pub payer: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DenyListEntry {
+    /// Calculate space needed for account
+    /// 8 (discriminator) + 32 (address) + 1 (reason) + 32 (denied_by) + 8 (denied_at) + 32 (payer) + 1 (bump)
+    pub const SIZE: usize = 8 + 32 + 1 + 32 + 8 + 32 + 1;
+}
+
+/// Helper function to generate denied mint PDA
+pub fn get_denied_mint_pda(token_mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    use crate::constants::DENIED_MINT_SEED;
+
+    Pubkey::find_program_address(&[DENIED_MINT_SEED, token_mint.as_ref()], program_id)
+}
+
+/// Helper function to generate denied user PDA
+pub fn get_denied_user_pda(user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    use crate::constants::DENIED_USER_SEED;
+
+    Pubkey::find_program_address(&[DENIED_USER_SEED, user.as_ref()], program_id)
+}
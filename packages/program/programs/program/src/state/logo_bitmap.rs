@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::state::LogoPlacement;
+
+/// Compact logo image stored next to a `LogoPlacement` (PDA from coordinates)
+/// Lets the map be rendered from chain state alone when the logo URI stops resolving
+#[account]
+pub struct LogoBitmap {
+    /// Coordinates in microdegrees [lat, lng]
+    pub coordinates: [i32; 2],
+    /// `placed_at` of the placement the bitmap was uploaded for
+    pub placed_at: i64,
+    /// User wallet address that uploaded the bitmap
+    pub uploader: Pubkey,
+    /// Expected payload length in bytes
+    pub total_len: u16,
+    /// Encoded payload: header, RGBA palette and pixel data (max 581 bytes)
+    pub data: Vec<u8>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl LogoBitmap {
+    /// Calculate space needed for account
+    /// 8 (discriminator) + 8 (coordinates) + 8 (placed_at) + 32 (uploader) + 2 (total_len) + 4 (vec length) + 581 (data) + 1 (bump)
+    pub const SIZE: usize = 8 + 8 + 8 + 32 + 2 + 4 + MAX_LOGO_BITMAP_BYTES + 1;
+
+    pub fn is_complete(&self) -> bool {
+        self.data.len() == self.total_len as usize
+    }
+
+    /// Append one uploaded chunk of the payload for the placement made at `placed_at`
+    /// A chunk at offset 0 starts a new upload, later chunks must follow in order
+    pub fn write_chunk(
+        &mut self,
+        placed_at: i64,
+        total_len: u16,
+        offset: u16,
+        chunk: &[u8],
+    ) -> Result<()> {
+        require!(
+            total_len as usize <= MAX_LOGO_BITMAP_BYTES,
+            SolplaceError::LogoBitmapTooLarge
+        );
+
+        if offset == 0 {
+            validate_logo_bitmap_header(chunk)?;
+            self.placed_at = placed_at;
+            self.total_len = total_len;
+            self.data.clear();
+        } else {
+            require!(
+                offset as usize == self.data.len()
+                    && total_len == self.total_len
+                    && self.placed_at == placed_at,
+                SolplaceError::InvalidLogoBitmapChunk
+            );
+        }
+        require!(
+            self.data.len() + chunk.len() <= total_len as usize,
+            SolplaceError::LogoBitmapTooLarge
+        );
+
+        self.data.extend_from_slice(chunk);
+        Ok(())
+    }
+
+    /// A bitmap only describes the placement it was uploaded for
+    pub fn is_current(&self, placement: &LogoPlacement) -> bool {
+        self.is_complete()
+            && !placement.is_blank()
+            && self.coordinates == placement.coordinates
+            && self.placed_at == placement.placed_at
+    }
+}
+
+/// Helper function to validate the header of an encoded logo bitmap
+/// Layout: version, width, height, encoding, palette_len
+pub fn validate_logo_bitmap_header(header: &[u8]) -> Result<()> {
+    require!(
+        header.len() >= LOGO_BITMAP_HEADER_LEN,
+        SolplaceError::InvalidLogoBitmap
+    );
+
+    let (version, width, height, encoding, palette_len) =
+        (header[0], header[1], header[2], header[3], header[4] as usize);
+    require!(
+        version == LOGO_BITMAP_VERSION
+            && width == height
+            && LOGO_BITMAP_SIZES.contains(&width)
+            && (encoding == LOGO_BITMAP_ENCODING_PACKED || encoding == LOGO_BITMAP_ENCODING_RLE)
+            && (1..=LOGO_BITMAP_MAX_COLORS).contains(&palette_len),
+        SolplaceError::InvalidLogoBitmap
+    );
+    Ok(())
+}
+
+/// Helper function to generate PDA for a logo bitmap
+pub fn get_logo_bitmap_pda(lat: i32, lng: i32, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            LOGO_BITMAP_SEED,
+            &lat.to_le_bytes(),
+            &lng.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: [u8; 5] = [LOGO_BITMAP_VERSION, 16, 16, LOGO_BITMAP_ENCODING_RLE, 1];
+
+    fn empty_bitmap() -> LogoBitmap {
+        LogoBitmap {
+            coordinates: [0, 0],
+            placed_at: 0,
+            uploader: Pubkey::default(),
+            total_len: 0,
+            data: Vec::new(),
+            bump: 0,
+        }
+    }
+
+    fn assert_error(result: Result<()>, error: SolplaceError) {
+        assert_eq!(result.unwrap_err(), error.into());
+    }
+
+    #[test]
+    fn chunks_written_in_order_complete_the_upload() {
+        let mut bitmap = empty_bitmap();
+        bitmap.write_chunk(100, 12, 0, &HEADER).unwrap();
+        assert!(!bitmap.is_complete());
+        bitmap.write_chunk(100, 12, 5, &[1, 2, 3]).unwrap();
+        bitmap.write_chunk(100, 12, 8, &[4, 5, 6, 7]).unwrap();
+        assert!(bitmap.is_complete());
+        assert_eq!(bitmap.data.len(), 12);
+        assert_eq!(bitmap.placed_at, 100);
+    }
+
+    #[test]
+    fn out_of_order_chunks_are_rejected() {
+        let mut bitmap = empty_bitmap();
+        bitmap.write_chunk(100, 12, 0, &HEADER).unwrap();
+        // A gap, a repeated chunk and a chunk of another upload size
+        assert_error(bitmap.write_chunk(100, 12, 6, &[1]), SolplaceError::InvalidLogoBitmapChunk);
+        assert_error(bitmap.write_chunk(100, 12, 0, &[1]), SolplaceError::InvalidLogoBitmap);
+        assert_error(bitmap.write_chunk(100, 11, 5, &[1]), SolplaceError::InvalidLogoBitmapChunk);
+        assert_eq!(bitmap.data, HEADER);
+    }
+
+    #[test]
+    fn chunks_of_an_older_placement_are_rejected() {
+        let mut bitmap = empty_bitmap();
+        bitmap.write_chunk(100, 12, 0, &HEADER).unwrap();
+        assert_error(bitmap.write_chunk(200, 12, 5, &[1]), SolplaceError::InvalidLogoBitmapChunk);
+
+        // Restarting at offset 0 begins an upload for the new placement
+        bitmap.write_chunk(200, 6, 0, &HEADER).unwrap();
+        assert_eq!((bitmap.placed_at, bitmap.data.len()), (200, 5));
+    }
+
+    #[test]
+    fn oversized_chunks_and_payloads_are_rejected() {
+        let mut bitmap = empty_bitmap();
+        assert_error(
+            bitmap.write_chunk(100, MAX_LOGO_BITMAP_BYTES as u16 + 1, 0, &HEADER),
+            SolplaceError::LogoBitmapTooLarge,
+        );
+        assert_error(bitmap.write_chunk(100, 4, 0, &HEADER), SolplaceError::LogoBitmapTooLarge);
+
+        bitmap.write_chunk(100, 7, 0, &HEADER).unwrap();
+        assert_error(bitmap.write_chunk(100, 7, 5, &[1, 2, 3]), SolplaceError::LogoBitmapTooLarge);
+        bitmap.write_chunk(100, 7, 5, &[1, 2]).unwrap();
+        assert!(bitmap.is_complete());
+    }
+
+    #[test]
+    fn first_chunk_needs_a_valid_header() {
+        let invalid = [
+            [LOGO_BITMAP_VERSION + 1, 16, 16, LOGO_BITMAP_ENCODING_RLE, 1],
+            [LOGO_BITMAP_VERSION, 16, 32, LOGO_BITMAP_ENCODING_RLE, 1],
+            [LOGO_BITMAP_VERSION, 24, 24, LOGO_BITMAP_ENCODING_RLE, 1],
+            [LOGO_BITMAP_VERSION, 16, 16, 7, 1],
+            [LOGO_BITMAP_VERSION, 16, 16, LOGO_BITMAP_ENCODING_PACKED, 0],
+            [LOGO_BITMAP_VERSION, 16, 16, LOGO_BITMAP_ENCODING_PACKED, LOGO_BITMAP_MAX_COLORS as u8 + 1],
+        ];
+        for header in invalid {
+            assert_error(
+                empty_bitmap().write_chunk(100, 100, 0, &header),
+                SolplaceError::InvalidLogoBitmap,
+            );
+        }
+        assert_error(
+            empty_bitmap().write_chunk(100, 100, 0, &HEADER[..4]),
+            SolplaceError::InvalidLogoBitmap,
+        );
+    }
+}
@@ -2,15 +2,19 @@ use anchor_lang::prelude::*;
 
 /// Individual logo placement account
 /// Each logo gets its own account, costs ~0.2-0.3 SOL rent
-#[account]
+/// The discriminator is sha256("account:LogoPlacementV1")[..8] so v0 accounts never decode as v1
+#[account(discriminator = [151, 161, 85, 172, 29, 86, 7, 224])]
+#[derive(Default)]
 pub struct LogoPlacement {
+    /// Layout version, see `LogoPlacement::VERSION`
+    pub version: u8,
     /// Coordinates in microdegrees [lat, lng]
     pub coordinates: [i32; 2],
     /// Token contract address
     pub token_mint: Pubkey,
     /// Resolved logo URL (max 200 chars)
     pub logo_uri: String,
-    /// Content hash for integrity verification
+    /// SHA-256 of the logo image bytes, supplied by the placer for integrity verification
     pub logo_hash: [u8; 32],
     /// User wallet address that placed this token
     pub placed_by: Pubkey,
@@ -24,10 +28,72 @@ pub struct LogoPlacement {
 
 impl LogoPlacement {
     /// Calculate space needed for account
+    /// 8 (discriminator) + 1 (version) + 8 (coordinates) + 32 (token_mint) + 4 (string length) + 200 (logo_uri) + 32 (logo_hash) + 32 (placed_by) + 8 (placed_at) + 2 (overwrite_count) + 1 (bump)
+    pub const SIZE: usize = 8 + 1 + 8 + 32 + 4 + 200 + 32 + 32 + 8 + 2 + 1;
+
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+
+    /// Decode the current layout, upgrading v0 accounts in memory
+    pub fn try_deserialize_versioned(data: &[u8]) -> Result<Self> {
+        if data.starts_with(LogoPlacementV0::DISCRIMINATOR) {
+            Ok(LogoPlacementV0::try_deserialize(&mut &data[..])?.into())
+        } else {
+            Self::try_deserialize(&mut &data[..])
+        }
+    }
+
+    /// Whether the logo was taken down by a moderator
+    pub fn is_blank(&self) -> bool {
+        self.token_mint == Pubkey::default()
+    }
+
+    /// Remove the logo while keeping the cell history
+    pub fn blank(&mut self) {
+        self.token_mint = Pubkey::default();
+        self.logo_uri.clear();
+        self.logo_hash = [0; 32];
+    }
+}
+
+/// Original logo placement layout, without a version field
+/// Only read to migrate existing accounts
+#[account(discriminator = [73, 115, 45, 46, 171, 45, 114, 126])]
+pub struct LogoPlacementV0 {
+    pub coordinates: [i32; 2],
+    pub token_mint: Pubkey,
+    pub logo_uri: String,
+    pub logo_hash: [u8; 32],
+    pub placed_by: Pubkey,
+    pub placed_at: i64,
+    pub overwrite_count: u16,
+    pub bump: u8,
+}
+
+impl LogoPlacementV0 {
     /// 8 (discriminator) + 8 (coordinates) + 32 (token_mint) + 4 (string length) + 200 (logo_uri) + 32 (logo_hash) + 32 (placed_by) + 8 (placed_at) + 2 (overwrite_count) + 1 (bump)
     pub const SIZE: usize = 8 + 8 + 32 + 4 + 200 + 32 + 32 + 8 + 2 + 1;
 }
 
+/// The v0 hash is carried over as is. The baseline program stored the SHA-256 of the URI
+/// rather than of the image bytes, so verifiers report it as a mismatch until the cell is
+/// placed again.
+impl From<LogoPlacementV0> for LogoPlacement {
+    fn from(v0: LogoPlacementV0) -> Self {
+        LogoPlacement {
+            version: LogoPlacement::VERSION,
+            coordinates: v0.coordinates,
+            token_mint: v0.token_mint,
+            logo_uri: v0.logo_uri,
+            logo_hash: v0.logo_hash,
+            placed_by: v0.placed_by,
+            placed_at: v0.placed_at,
+            overwrite_count: v0.overwrite_count,
+            bump: v0.bump,
+        }
+    }
+}
+
 /// Helper function to generate PDA for a logo placement
 pub fn get_logo_placement_pda(lat: i32, lng: i32, program_id: &Pubkey) -> (Pubkey, u8) {
     use crate::constants::LOGO_PLACEMENT_SEED;
@@ -48,11 +114,11 @@ pub fn validate_coordinates(lat: i32, lng: i32) -> Result<()> {
     use crate::error::SolplaceError;
     
     require!(
-        lat >= MIN_LATITUDE && lat <= MAX_LATITUDE,
+        (MIN_LATITUDE..=MAX_LATITUDE).contains(&lat),
         SolplaceError::InvalidLatitude
     );
     require!(
-        lng >= MIN_LONGITUDE && lng <= MAX_LONGITUDE,
+        (MIN_LONGITUDE..=MAX_LONGITUDE).contains(&lng),
         SolplaceError::InvalidLongitude
     );
     Ok(())
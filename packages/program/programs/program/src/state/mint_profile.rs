@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+
+/// Per-mint registry account (PDA from token mint)
+/// Holds the canonical logo used by every placement of the token and aggregate statistics
+#[account]
+#[derive(Default)]
+pub struct MintProfile {
+    /// Token contract address
+    pub token_mint: Pubkey,
+    /// Canonical logo URL (max 200 chars, empty until claimed)
+    pub canonical_uri: String,
+    /// SHA-256 of the canonical logo image bytes
+    pub canonical_hash: [u8; 32],
+    /// Wallet that set the canonical logo
+    pub canonical_set_by: Pubkey,
+    /// Token balance the claimant proved when setting the canonical logo
+    pub canonical_claim_balance: u64,
+    /// True once the mint authority set the logo, which ends any challenge
+    pub canonical_locked: bool,
+    /// Unix timestamp when the canonical logo was last set
+    pub canonical_set_at: i64,
+    /// Number of cells currently showing this token
+    pub cells_held: u32,
+    /// Total number of placements of this token
+    pub placement_count: u64,
+    /// Total fees paid in lamports for placements of this token
+    pub total_fees_paid: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MintProfile {
+    /// Calculate space needed for account
+    /// 8 (discriminator) + 32 (token_mint) + 4 (string length) + 200 (canonical_uri) + 32 (canonical_hash) + 32 (canonical_set_by) + 8 (canonical_claim_balance) + 1 (canonical_locked) + 8 (canonical_set_at) + 4 (cells_held) + 8 (placement_count) + 8 (total_fees_paid) + 1 (bump)
+    pub const SIZE: usize = 8 + 32 + 4 + 200 + 32 + 32 + 8 + 1 + 8 + 4 + 8 + 8 + 1;
+
+    pub fn has_canonical_logo(&self) -> bool {
+        !self.canonical_uri.is_empty()
+    }
+
+    /// Whether `claimant` may replace the current canonical logo
+    /// The mint authority always can; anyone else must hold the token, and only while the
+    /// logo is unclaimed or by proving a larger balance than the current claimant (a challenge)
+    pub fn can_set_canonical(&self, is_mint_authority: bool, claimant_balance: u64) -> bool {
+        if is_mint_authority {
+            return true;
+        }
+        if self.canonical_locked || claimant_balance == 0 {
+            return false;
+        }
+        !self.has_canonical_logo() || claimant_balance > self.canonical_claim_balance
+    }
+
+    /// Count a placement of this token paying `fee`
+    /// `takes_cell` unless the cell already showed this token before the placement
+    pub fn record_placement(&mut self, fee: u64, takes_cell: bool) {
+        self.placement_count += 1;
+        self.total_fees_paid += fee;
+        if takes_cell {
+            self.cells_held += 1;
+        }
+    }
+
+    /// Count a cell of this token lost to an overwrite by another token
+    pub fn release_cell(&mut self) {
+        self.cells_held = self.cells_held.saturating_sub(1);
+    }
+}
+
+/// Helper function to generate PDA for a mint profile
+pub fn get_mint_profile_pda(token_mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    use crate::constants::MINT_PROFILE_SEED;
+
+    Pubkey::find_program_address(&[MINT_PROFILE_SEED, token_mint.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claimed(balance: u64, locked: bool) -> MintProfile {
+        MintProfile {
+            canonical_uri: "https://example.com/logo.png".to_string(),
+            canonical_claim_balance: balance,
+            canonical_locked: locked,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn holders_claim_an_unset_canonical_logo() {
+        let profile = MintProfile::default();
+        assert!(!profile.has_canonical_logo());
+        assert!(profile.can_set_canonical(false, 1));
+        assert!(profile.can_set_canonical(true, 0));
+        // Wallets without the token cannot squat the logo
+        assert!(!profile.can_set_canonical(false, 0));
+    }
+
+    #[test]
+    fn holders_challenge_with_a_larger_balance() {
+        let profile = claimed(100, false);
+        assert!(!profile.can_set_canonical(false, 0));
+        assert!(!profile.can_set_canonical(false, 100));
+        assert!(profile.can_set_canonical(false, 101));
+        assert!(profile.can_set_canonical(true, 0));
+    }
+
+    #[test]
+    fn only_the_mint_authority_replaces_a_locked_logo() {
+        let profile = claimed(0, true);
+        assert!(!profile.can_set_canonical(false, u64::MAX));
+        assert!(profile.can_set_canonical(true, 0));
+    }
+
+    #[test]
+    fn first_placements_take_a_cell() {
+        let mut profile = MintProfile::default();
+        profile.record_placement(1_000_000, true);
+        profile.record_placement(750_000, true);
+        assert_eq!(profile.placement_count, 2);
+        assert_eq!(profile.cells_held, 2);
+        assert_eq!(profile.total_fees_paid, 1_750_000);
+    }
+
+    #[test]
+    fn overwrites_by_the_same_token_keep_the_cell_count() {
+        let mut profile = MintProfile::default();
+        profile.record_placement(1_000_000, true);
+        profile.record_placement(5_000_000, false);
+        assert_eq!(profile.placement_count, 2);
+        assert_eq!(profile.cells_held, 1);
+        assert_eq!(profile.total_fees_paid, 6_000_000);
+    }
+
+    #[test]
+    fn overwrites_by_another_token_move_the_cell() {
+        let mut displaced = MintProfile::default();
+        displaced.record_placement(1_000_000, true);
+        let mut placed = MintProfile::default();
+
+        placed.record_placement(5_000_000, true);
+        displaced.release_cell();
+        assert_eq!((placed.cells_held, displaced.cells_held), (1, 0));
+        // Profiles created before the counters existed never go negative
+        displaced.release_cell();
+        assert_eq!(displaced.cells_held, 0);
+        assert_eq!(displaced.placement_count, 1);
+    }
+}
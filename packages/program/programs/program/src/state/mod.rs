@@ -1,5 +1,19 @@
 pub mod user_cooldown;
 pub mod logo_placement;
+pub mod mint_profile;
+pub mod moderation;
+pub mod logo_bitmap;
+pub mod program_config;
+pub mod report;
+pub mod deny_list;
+pub mod restricted_zone;
 
 pub use user_cooldown::*;
-pub use logo_placement::*;
\ No newline at end of file
+pub use logo_placement::*;
+pub use mint_profile::*;
+pub use moderation::*;
+pub use logo_bitmap::*;
+pub use program_config::*;
+pub use report::*;
+pub use deny_list::*;
+pub use restricted_zone::*;
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Why a placement was taken down
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModerationReason {
+    /// Unsolicited advertising or repeated junk placements
+    Spam,
+    /// Hateful, violent or sexual imagery
+    Offensive,
+    /// Logo impersonates another project
+    Impersonation,
+    /// Phishing, rug pulls or other fraud
+    Scam,
+    /// Content that is illegal to host
+    Illegal,
+    /// Anything else, detailed off-chain
+    Other,
+}
+
+/// What happens to a moderated placement
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModerationAction {
+    /// Clear the logo but keep the account and cell history
+    Blank,
+    /// Close the account, refunding its rent to the placer unless a report against it was upheld
+    Close,
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// Global program configuration (PDA, single instance)
+#[account]
+pub struct ProgramConfig {
+    /// Wallet allowed to change the configuration
+    pub admin: Pubkey,
+    /// Proposed next admin, becomes admin once it accepts
+    pub pending_admin: Option<Pubkey>,
+    /// Wallet allowed to take down placements
+    pub moderator: Pubkey,
+    /// Hosts allowed in https logo URIs, empty allows any host
+    pub allowed_domains: Vec<String>,
+    /// Emergency switch, blocks every instruction that creates or changes user content
+    pub paused: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ProgramConfig {
+    /// Calculate space needed for account
+    /// 8 (discriminator) + 32 (admin) + 33 (pending_admin) + 32 (moderator) + 4 (vec length) + 16 * (4 (string length) + 64 (domain)) + 1 (paused) + 1 (bump)
+    pub const SIZE: usize = 8 + 32 + 33 + 32 + 4 + MAX_ALLOWED_DOMAINS * (4 + MAX_DOMAIN_LENGTH) + 1 + 1;
+}
+
+/// Helper function to generate PDA for the program config
+pub fn get_program_config_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED], program_id)
+}
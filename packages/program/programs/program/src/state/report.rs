@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use crate::state::ModerationReason;
+
+/// A user report against a placement (PDA from placement and reporter)
+/// Holds the reporter's stake until a moderator resolves it
+#[account]
+pub struct PlacementReport {
+    /// Reported logo placement account
+    pub logo_placement: Pubkey,
+    /// User wallet address that filed the report
+    pub reporter: Pubkey,
+    /// `placed_at` of the reported placement
+    pub placed_at: i64,
+    /// Why the placement was reported
+    pub reason: ModerationReason,
+    /// Lamports staked on top of rent
+    pub stake: u64,
+    /// Unix timestamp when reported
+    pub created_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PlacementReport {
+    /// Calculate space needed for account
+    /// 8 (discriminator) + 32 (logo_placement) + 32 (reporter) + 8 (placed_at) + 1 (reason) + 8 (stake) + 8 (created_at) + 1 (bump)
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 1 + 8 + 8 + 1;
+}
+
+/// Aggregate report counter of a placement (PDA from placement)
+/// Resets whenever the cell gets a new placement
+#[account]
+pub struct ReportTally {
+    /// Logo placement account the reports are about
+    pub logo_placement: Pubkey,
+    /// `placed_at` of the placement being counted
+    pub placed_at: i64,
+    /// Open reports against the current placement
+    pub report_count: u16,
+    /// Set once `report_count` reaches the hide threshold, the indexer and API leave the logo out
+    pub hidden: bool,
+    /// Reports against the current placement a moderator upheld, a takedown then keeps the rent
+    pub upheld_count: u16,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ReportTally {
+    /// Calculate space needed for account
+    /// 8 (discriminator) + 32 (logo_placement) + 8 (placed_at) + 2 (report_count) + 1 (hidden) + 2 (upheld_count) + 1 (bump)
+    pub const SIZE: usize = 8 + 32 + 8 + 2 + 1 + 2 + 1;
+
+    /// Start counting from scratch when the cell changed since the last report
+    pub fn sync(&mut self, logo_placement: Pubkey, placed_at: i64) {
+        if self.logo_placement != logo_placement || self.placed_at != placed_at {
+            self.logo_placement = logo_placement;
+            self.placed_at = placed_at;
+            self.report_count = 0;
+            self.hidden = false;
+            self.upheld_count = 0;
+        }
+    }
+
+    /// Whether a moderator upheld a report against the placement made at `placed_at`
+    pub fn has_upheld_report(&self, logo_placement: Pubkey, placed_at: i64) -> bool {
+        self.logo_placement == logo_placement && self.placed_at == placed_at && self.upheld_count > 0
+    }
+}
+
+/// Moderator verdict on a report
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportResolution {
+    /// The report was valid, the stake is refunded and a takedown keeps the placement rent
+    Upheld,
+    /// The report was frivolous, the stake is slashed to the treasury
+    Rejected,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upheld_reports_only_count_for_the_current_placement() {
+        let logo_placement = Pubkey::new_unique();
+        let mut tally = ReportTally {
+            logo_placement,
+            placed_at: 100,
+            report_count: 1,
+            hidden: false,
+            upheld_count: 0,
+            bump: 255,
+        };
+        assert!(!tally.has_upheld_report(logo_placement, 100));
+
+        tally.upheld_count = 1;
+        assert!(tally.has_upheld_report(logo_placement, 100));
+        assert!(!tally.has_upheld_report(logo_placement, 200));
+        assert!(!tally.has_upheld_report(Pubkey::new_unique(), 100));
+
+        // A new placement in the cell starts without upheld reports
+        tally.sync(logo_placement, 200);
+        assert_eq!((tally.report_count, tally.upheld_count), (0, 0));
+        assert!(!tally.has_upheld_report(logo_placement, 200));
+    }
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::SolplaceError;
+use crate::geo::*;
+
+/// No-placement areas of one zone tile (PDA from tile indices)
+/// A zone crossing tile borders is stored in every tile it touches
+#[account]
+pub struct RestrictedZone {
+    /// Tile indices [tile_lat, tile_lng], see `geo::tile_of`
+    pub tile: [i16; 2],
+    /// Restricted shapes overlapping the tile
+    pub shapes: Vec<ZoneShape>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RestrictedZone {
+    /// Calculate space needed for account
+    /// 8 (discriminator) + 4 (tile) + 4 (vec length) + 8 * (1 (variant) + 4 (vec length) + 16 * 8 (vertices)) + 1 (bump)
+    pub const SIZE: usize = 8 + 4 + 4 + MAX_ZONE_SHAPES * ZoneShape::MAX_SIZE + 1;
+
+    /// Whether any shape of the tile covers the coordinates
+    pub fn contains(&self, lat: i32, lng: i32) -> bool {
+        self.shapes.iter().any(|shape| shape.contains(lat, lng))
+    }
+}
+
+/// A restricted area in microdegrees, edges included
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ZoneShape {
+    /// Axis-aligned box from `min` to `max`, as [lat, lng]
+    BoundingBox { min: [i32; 2], max: [i32; 2] },
+    /// Simple polygon of [lat, lng] vertices, implicitly closed
+    Polygon { vertices: Vec<[i32; 2]> },
+}
+
+impl ZoneShape {
+    /// Largest serialized shape (a polygon with the maximum number of vertices)
+    pub const MAX_SIZE: usize = 1 + 4 + MAX_ZONE_POLYGON_VERTICES * 8;
+
+    pub fn contains(&self, lat: i32, lng: i32) -> bool {
+        match self {
+            ZoneShape::BoundingBox { min, max } => point_in_bbox([lat, lng], *min, *max),
+            ZoneShape::Polygon { vertices } => point_in_polygon([lat, lng], vertices),
+        }
+    }
+
+    /// Check the shape is well formed and overlaps `tile`
+    pub fn validate(&self, tile: [i16; 2]) -> Result<()> {
+        let (min, max) = match self {
+            ZoneShape::BoundingBox { min, max } => {
+                require!(
+                    min[0] <= max[0] && min[1] <= max[1],
+                    SolplaceError::InvalidZoneShape
+                );
+                (*min, *max)
+            }
+            ZoneShape::Polygon { vertices } => {
+                require!(
+                    (3..=MAX_ZONE_POLYGON_VERTICES).contains(&vertices.len()),
+                    SolplaceError::InvalidZoneShape
+                );
+                let min = vertices.iter().fold([i32::MAX; 2], |acc, v| {
+                    [acc[0].min(v[0]), acc[1].min(v[1])]
+                });
+                let max = vertices.iter().fold([i32::MIN; 2], |acc, v| {
+                    [acc[0].max(v[0]), acc[1].max(v[1])]
+                });
+                (min, max)
+            }
+        };
+
+        require!(
+            (MIN_LATITUDE..=MAX_LATITUDE).contains(&min[0])
+                && (MIN_LATITUDE..=MAX_LATITUDE).contains(&max[0])
+                && (MIN_LONGITUDE..=MAX_LONGITUDE).contains(&min[1])
+                && (MIN_LONGITUDE..=MAX_LONGITUDE).contains(&max[1]),
+            SolplaceError::InvalidZoneShape
+        );
+
+        let [tile_min_lat, tile_min_lng, tile_max_lat, tile_max_lng] = tile_bounds(tile);
+        require!(
+            min[0] < tile_max_lat
+                && max[0] >= tile_min_lat
+                && min[1] < tile_max_lng
+                && max[1] >= tile_min_lng,
+            SolplaceError::ZoneOutsideTile
+        );
+        Ok(())
+    }
+}
+
+/// Helper function to generate PDA for the restricted zone of a tile
+pub fn get_restricted_zone_pda(tile: [i16; 2], program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            RESTRICTED_ZONE_SEED,
+            &tile[0].to_le_bytes(),
+            &tile[1].to_le_bytes(),
+        ],
+        program_id,
+    )
+}
@@ -1,15 +1,36 @@
 use anchor_lang::prelude::*;
 
-#[account]
+/// Placement cooldown of one wallet
+/// The discriminator is sha256("account:UserCooldownV1")[..8] so v0 accounts never decode as v1
+#[account(discriminator = [61, 93, 53, 54, 160, 242, 44, 74])]
+#[derive(Default)]
 pub struct UserCooldown {
-    pub user: Pubkey,                       // User wallet address
-    pub last_placement: i64,                // Unix timestamp of last placement
-    pub placement_count: u32,               // Total number of placements by user
-    pub bump: u8,                           // PDA bump
+    /// Layout version, see `UserCooldown::VERSION`
+    pub version: u8,
+    /// User wallet address
+    pub user: Pubkey,
+    /// Unix timestamp of last placement
+    pub last_placement: i64,
+    /// Total number of placements by user
+    pub placement_count: u32,
+    /// PDA bump seed
+    pub bump: u8,
 }
 
 impl UserCooldown {
-    pub const SIZE: usize = 8 + 32 + 8 + 4 + 1; // discriminator + user + last_placement + placement_count + bump
+    pub const SIZE: usize = 8 + 1 + 32 + 8 + 4 + 1; // discriminator + version + user + last_placement + placement_count + bump
+
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+
+    /// Decode the current layout, upgrading v0 accounts in memory
+    pub fn try_deserialize_versioned(data: &[u8]) -> Result<Self> {
+        if data.starts_with(UserCooldownV0::DISCRIMINATOR) {
+            Ok(UserCooldownV0::try_deserialize(&mut &data[..])?.into())
+        } else {
+            Self::try_deserialize(&mut &data[..])
+        }
+    }
 
     pub fn is_on_cooldown(&self, current_timestamp: i64) -> bool {
         let time_since_last = current_timestamp - self.last_placement;
@@ -24,3 +45,36 @@ impl UserCooldown {
         }
     }
 }
+
+/// Original cooldown layout, without a version field
+/// Only read to migrate existing accounts
+#[account(discriminator = [198, 114, 196, 140, 118, 165, 129, 190])]
+pub struct UserCooldownV0 {
+    pub user: Pubkey,
+    pub last_placement: i64,
+    pub placement_count: u32,
+    pub bump: u8,
+}
+
+impl UserCooldownV0 {
+    pub const SIZE: usize = 8 + 32 + 8 + 4 + 1; // discriminator + user + last_placement + placement_count + bump
+}
+
+impl From<UserCooldownV0> for UserCooldown {
+    fn from(v0: UserCooldownV0) -> Self {
+        UserCooldown {
+            version: UserCooldown::VERSION,
+            user: v0.user,
+            last_placement: v0.last_placement,
+            placement_count: v0.placement_count,
+            bump: v0.bump,
+        }
+    }
+}
+
+/// Helper function to generate PDA for a user cooldown
+pub fn get_user_cooldown_pda(user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    use crate::constants::COOLDOWN_SEED;
+
+    Pubkey::find_program_address(&[COOLDOWN_SEED, user.as_ref()], program_id)
+}
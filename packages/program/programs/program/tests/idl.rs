@@ -0,0 +1,141 @@
+//! Checks that every account, event and error type keeps the name and code clients see
+//!
+//! The IDL check builds the IDL the same way `anchor build` does (nightly toolchain, separate
+//! target directory): `cargo test -p program --features idl-build --test idl`
+
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::Discriminator;
+use program::instructions::*;
+use program::state::*;
+
+/// Pairs a type with the name it is published under
+macro_rules! named {
+    ($($ty:ident),* $(,)?) => {
+        &[$((stringify!($ty), <$ty as Discriminator>::DISCRIMINATOR)),*]
+    };
+}
+
+const ACCOUNTS: &[(&str, &[u8])] = named![
+    DenyListEntry,
+    LogoBitmap,
+    LogoPlacement,
+    MintProfile,
+    PlacementReport,
+    ProgramConfig,
+    ReportTally,
+    RestrictedZone,
+    UserCooldown,
+];
+
+const EVENTS: &[(&str, &[u8])] = named![
+    AccountMigratedEvent,
+    AdminProposedEvent,
+    AdminTransferredEvent,
+    CanonicalLogoSetEvent,
+    CooldownUpdatedEvent,
+    FeeCollectedEvent,
+    LogoBitmapWrittenEvent,
+    LogoModeratedEvent,
+    LogoPlacedEvent,
+    MintAllowedEvent,
+    MintDeniedEvent,
+    PlacementHiddenEvent,
+    PlacementReportedEvent,
+    PlacementUnhiddenEvent,
+    ProgramPausedEvent,
+    ReportResolvedEvent,
+    RestrictedZoneClearedEvent,
+    RestrictedZoneSetEvent,
+    UserAllowedEvent,
+    UserDeniedEvent,
+];
+
+/// Accounts whose layout changed hash a versioned name, the bare name stays with the v0 layout
+const VERSIONED_ACCOUNTS: &[(&str, &str, &[u8])] = &[
+    ("LogoPlacement", "LogoPlacementV1", LogoPlacementV0::DISCRIMINATOR),
+    ("UserCooldown", "UserCooldownV1", UserCooldownV0::DISCRIMINATOR),
+];
+
+/// First 8 bytes of `sha256("<namespace>:<name>")`, how Anchor derives discriminators
+fn discriminator(namespace: &str, name: &str) -> Vec<u8> {
+    hash(format!("{namespace}:{name}").as_bytes()).to_bytes()[..8].to_vec()
+}
+
+#[test]
+fn discriminators_match_type_names() {
+    let mut seen = Vec::new();
+    for (name, _, v0) in VERSIONED_ACCOUNTS {
+        assert_eq!(*v0, discriminator("account", name), "unexpected v0 discriminator for {name}");
+        seen.push(*v0);
+    }
+    for (namespace, types) in [("account", ACCOUNTS), ("event", EVENTS)] {
+        for (name, actual) in types {
+            let hashed = VERSIONED_ACCOUNTS
+                .iter()
+                .find(|(versioned, ..)| namespace == "account" && versioned == name)
+                .map_or(*name, |(_, hashed, _)| *hashed);
+            assert_eq!(*actual, discriminator(namespace, hashed), "unexpected discriminator for {name}");
+            assert!(!seen.contains(actual), "{name} reuses a discriminator");
+            seen.push(*actual);
+        }
+    }
+}
+
+#[cfg(feature = "idl-build")]
+mod idl {
+    use std::path::PathBuf;
+
+    use anchor_lang_idl::build::IdlBuilder;
+    use anchor_lang_idl::types::Idl;
+
+    use super::*;
+
+    fn build_idl() -> Idl {
+        let program_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let target_dir = program_path.join("../../target/idl");
+
+        // Like `anchor build`, the IDL needs nightly to parse seeds; rustup sets this var for
+        // the outer cargo, and the builder reuses it for the inner one
+        std::env::set_var("RUSTUP_TOOLCHAIN", "nightly");
+
+        IdlBuilder::new()
+            .program_path(program_path)
+            .cargo_args(vec![
+                "--target-dir".into(),
+                target_dir.to_string_lossy().into_owned(),
+            ])
+            .build()
+            .expect("failed to build the IDL")
+    }
+
+    #[test]
+    fn idl_contains_all_types() {
+        let idl = build_idl();
+
+        let types: Vec<_> = idl.types.iter().map(|ty| ty.name.as_str()).collect();
+
+        for (name, discriminator) in ACCOUNTS {
+            let account = idl.accounts.iter().find(|account| account.name == *name);
+            let account = account.unwrap_or_else(|| panic!("account {name} missing from the IDL"));
+            assert_eq!(account.discriminator, *discriminator, "account {name} discriminator");
+            assert!(types.contains(name), "account type {name} missing from the IDL");
+        }
+        for (name, discriminator) in EVENTS {
+            let event = idl.events.iter().find(|event| event.name == *name);
+            let event = event.unwrap_or_else(|| panic!("event {name} missing from the IDL"));
+            assert_eq!(event.discriminator, *discriminator, "event {name} discriminator");
+            assert!(types.contains(name), "event type {name} missing from the IDL");
+        }
+
+        for (offset, error) in idl.errors.iter().enumerate() {
+            assert_eq!(error.code, 6000 + offset as u32, "unexpected code for {}", error.name);
+        }
+
+        assert!(
+            idl.instructions
+                .iter()
+                .all(|instruction| !instruction.name.contains("expose_types")),
+            "the IDL must not contain placeholder instructions"
+        );
+    }
+}
@@ -0,0 +1,28 @@
+#!/usr/bin/env bash
+# Build the baseline program for the `place_logo compute units` tests
+#
+# The program of the baseline commit is built with its program id replaced by
+# BASELINE_PROGRAM_ID, and `anchor test` loads it next to the current program
+# ([[test.genesis]] in Anchor.toml). Delete target/deploy/solplace_baseline.so to rebuild.
+set -euo pipefail
+
+BASELINE_COMMIT=4d115c5
+BASELINE_PROGRAM_ID=CzsZbLqwJGuhumExU2YNnu6XNFg3gHAs6sc5jCb87stF
+
+cd "$(dirname "$0")/.."
+output=target/deploy/solplace_baseline.so
+if [ -f "$output" ]; then
+	exit 0
+fi
+
+worktree=$(mktemp -d)
+trap 'git worktree remove --force "$worktree"' EXIT
+git worktree add --detach "$worktree" "$BASELINE_COMMIT" >/dev/null
+
+baseline="$worktree/packages/program"
+sed -i.bak "s/declare_id!(\"[^\"]*\")/declare_id!(\"$BASELINE_PROGRAM_ID\")/" \
+	"$baseline/programs/program/src/lib.rs"
+cargo build-sbf --manifest-path "$baseline/programs/program/Cargo.toml"
+
+mkdir -p target/deploy
+cp "$baseline/target/deploy/program.so" "$output"
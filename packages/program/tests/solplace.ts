@@ -1,13 +1,21 @@
 import * as anchor from "@coral-xyz/anchor"
 import { Program } from "@coral-xyz/anchor"
-import { createMint, TOKEN_PROGRAM_ID } from "@solana/spl-token"
+import {
+	createMint,
+	getOrCreateAssociatedTokenAccount,
+	mintTo,
+	TOKEN_PROGRAM_ID
+} from "@solana/spl-token"
 import {
 	Keypair,
 	LAMPORTS_PER_SOL,
 	PublicKey,
-	SystemProgram
+	SystemProgram,
+	TransactionInstruction
 } from "@solana/web3.js"
 import { assert } from "chai"
+import { createHash } from "crypto"
+import { AdminProxy } from "../target/types/admin_proxy"
 import { SolplaceProgram } from "../target/types/solplace_program"
 
 describe("solplace", () => {
@@ -15,6 +23,7 @@ describe("solplace", () => {
 	anchor.setProvider(anchor.AnchorProvider.env())
 
 	const program = anchor.workspace.SolplaceProgram as Program<SolplaceProgram>
+	const adminProxy = anchor.workspace.AdminProxy as Program<AdminProxy>
 	const provider = anchor.getProvider()
 
 	// Helper function to generate logo placement PDA
@@ -33,6 +42,22 @@ describe("solplace", () => {
 		)
 	}
 
+	// Helper function to generate the restricted zone PDA of a cell's 1° tile
+	function getRestrictedZonePDA(
+		lat: number,
+		lng: number
+	): [PublicKey, number] {
+		const tileLatBuffer = Buffer.allocUnsafe(2)
+		tileLatBuffer.writeInt16LE(Math.floor(lat / 1_000_000))
+		const tileLngBuffer = Buffer.allocUnsafe(2)
+		tileLngBuffer.writeInt16LE(Math.floor(lng / 1_000_000))
+
+		return PublicKey.findProgramAddressSync(
+			[Buffer.from("restricted_zone"), tileLatBuffer, tileLngBuffer],
+			program.programId
+		)
+	}
+
 	// Helper function to generate user cooldown PDA
 	function getUserCooldownPDA(userPublicKey: PublicKey): [PublicKey, number] {
 		return PublicKey.findProgramAddressSync(
@@ -41,6 +66,165 @@ describe("solplace", () => {
 		)
 	}
 
+	// Helper function to generate mint profile PDA
+	function getMintProfilePDA(mint: PublicKey): [PublicKey, number] {
+		return PublicKey.findProgramAddressSync(
+			[Buffer.from("mint_profile"), mint.toBuffer()],
+			program.programId
+		)
+	}
+
+	// Helper function to generate deny list PDAs of a mint and a user
+	function getDenyListPDAs(
+		mint: PublicKey,
+		user: PublicKey
+	): { deniedMint: [PublicKey, number]; deniedUser: [PublicKey, number] } {
+		return {
+			deniedMint: PublicKey.findProgramAddressSync(
+				[Buffer.from("denied_mint"), mint.toBuffer()],
+				program.programId
+			),
+			deniedUser: PublicKey.findProgramAddressSync(
+				[Buffer.from("denied_user"), user.toBuffer()],
+				program.programId
+			)
+		}
+	}
+
+	// Helper function to build the place_logo PDA accounts
+	function getPlacementAccounts(
+		lat: number,
+		lng: number,
+		user: PublicKey,
+		mint: PublicKey = tokenMint
+	) {
+		const { deniedMint, deniedUser } = getDenyListPDAs(mint, user)
+		return {
+			logoPlacement: getLogoPlacementPDA(lat, lng)[0],
+			userCooldown: getUserCooldownPDA(user)[0],
+			mintProfile: getMintProfilePDA(mint)[0],
+			deniedMint: deniedMint[0],
+			deniedUser: deniedUser[0],
+			restrictedZone: getRestrictedZonePDA(lat, lng)[0]
+		}
+	}
+
+	// Helper function to collect the canonical bumps of the PDAs place_logo takes
+	function getPlacementBumps(
+		lat: number,
+		lng: number,
+		user: PublicKey,
+		mint: PublicKey = tokenMint
+	) {
+		const { deniedMint, deniedUser } = getDenyListPDAs(mint, user)
+		return {
+			logoPlacement: getLogoPlacementPDA(lat, lng)[1],
+			userCooldown: getUserCooldownPDA(user)[1],
+			deniedMint: deniedMint[1],
+			deniedUser: deniedUser[1],
+			restrictedZone: getRestrictedZonePDA(lat, lng)[1]
+		}
+	}
+
+	// Helper function to generate program config PDA
+	function getConfigPDA(): [PublicKey, number] {
+		return PublicKey.findProgramAddressSync(
+			[Buffer.from("config")],
+			program.programId
+		)
+	}
+
+	// Helper function to find the program data account (holds the upgrade authority)
+	function getProgramDataAddress(): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[program.programId.toBuffer()],
+			new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+		)[0]
+	}
+
+	// Helper function to hash logo image bytes (tests use placeholder content)
+	function getLogoHash(imageContent: string): number[] {
+		return Array.from(createHash("sha256").update(imageContent).digest())
+	}
+
+	// Helper function to fund a fresh wallet, which starts without a cooldown
+	async function fundedKeypair(
+		keypair: Keypair = Keypair.generate()
+	): Promise<Keypair> {
+		await provider.sendAndConfirm(
+			new anchor.web3.Transaction().add(
+				SystemProgram.transfer({
+					fromPubkey: payer.publicKey,
+					toPubkey: keypair.publicKey,
+					lamports: 0.1 * LAMPORTS_PER_SOL
+				})
+			),
+			[payer]
+		)
+		return keypair
+	}
+
+	// Helper function to place a placeholder logo of `mint` from a wallet
+	// `previousMint` is the token shown on the cell when another token overwrites it
+	async function placeLogoAs(
+		lat: number,
+		lng: number,
+		user: Keypair,
+		mint: PublicKey = tokenMint,
+		previousMint: PublicKey | null = null
+	) {
+		const logoUri = "https://example.com/placed-logo.png"
+		return program.methods
+			.placeLogo(
+				lat,
+				lng,
+				getPlacementBumps(lat, lng, user.publicKey, mint),
+				mint,
+				logoUri,
+				getLogoHash(logoUri)
+			)
+			.accountsPartial({
+				...getPlacementAccounts(lat, lng, user.publicKey, mint),
+				previousMintProfile: previousMint
+					? getMintProfilePDA(previousMint)[0]
+					: null,
+				tokenMint: mint,
+				treasury: provider.publicKey,
+				user: user.publicKey,
+				systemProgram: SystemProgram.programId
+			})
+			.signers([user])
+			.rpc()
+	}
+
+	// Helper function to decode the events a transaction emitted through self-CPI
+	async function getCpiEvents(signature: string) {
+		await provider.connection.confirmTransaction(signature, "confirmed")
+		const tx = await provider.connection.getTransaction(signature, {
+			commitment: "confirmed",
+			maxSupportedTransactionVersion: 0
+		})
+		const [eventAuthority] = PublicKey.findProgramAddressSync(
+			[Buffer.from("__event_authority")],
+			program.programId
+		)
+		const accountKeys = tx.transaction.message.getAccountKeys()
+		return tx.meta.innerInstructions
+			.flatMap((group) => group.instructions)
+			.filter(
+				(ix) =>
+					accountKeys.get(ix.programIdIndex).equals(program.programId) &&
+					accountKeys.get(ix.accounts[0]).equals(eventAuthority)
+			)
+			.map((ix) => {
+				// Skip the 8-byte event instruction tag
+				const data = anchor.utils.bytes.bs58.decode(ix.data).subarray(8)
+				return program.coder.events.decode(
+					anchor.utils.bytes.base64.encode(Buffer.from(data))
+				)
+			})
+	}
+
 	// Test accounts - separate wallets to avoid cooldown conflicts
 	let payer: Keypair
 	let userKeypair: Keypair
@@ -139,6 +323,21 @@ describe("solplace", () => {
 			console.log("⚠️ Token creation failed, using TOKEN_PROGRAM_ID")
 			tokenMint = TOKEN_PROGRAM_ID
 		}
+
+		// Initialize the program config once per deployment
+		const [configPda] = getConfigPDA()
+		const existingConfig = await provider.connection.getAccountInfo(configPda)
+		if (!existingConfig) {
+			await program.methods
+				.initialize()
+				.accountsPartial({
+					config: configPda,
+					admin: payer.publicKey,
+					programData: getProgramDataAddress()
+				})
+				.rpc()
+			console.log("✅ Program config initialized:", configPda.toString())
+		}
 	})
 
 	after(async () => {
@@ -223,10 +422,16 @@ describe("solplace", () => {
 		try {
 			// Place logo
 			const tx = await program.methods
-				.placeLogo(lat, lng, tokenMint, logoUri)
+				.placeLogo(
+					lat,
+					lng,
+					getPlacementBumps(lat, lng, userKeypair.publicKey),
+					tokenMint,
+					logoUri,
+					getLogoHash(logoUri)
+				)
 				.accountsPartial({
-					logoPlacement: logoPlacementPda,
-					userCooldown: userCooldownPda,
+					...getPlacementAccounts(lat, lng, userKeypair.publicKey),
 					tokenMint: tokenMint,
 					treasury: provider.publicKey, // Using provider as treasury for tests
 					user: userKeypair.publicKey,
@@ -271,6 +476,35 @@ describe("solplace", () => {
 				cooldownAccount.placementCount
 			)
 
+			// Verify the events were emitted through self-CPI with the fee breakdown
+			const [placed, cooldown, fee] = await getCpiEvents(tx)
+			assert.equal(placed.name, "logoPlacedEvent")
+			assert.equal(placed.data.user.toString(), testUser.publicKey.toString())
+			assert.equal(placed.data.cell.toString(), logoPlacementPda.toString())
+			assert.equal(placed.data.lat, lat)
+			assert.equal(
+				placed.data.overwriteCount,
+				logoPlacementAccount.overwriteCount
+			)
+			assert.equal(cooldown.name, "cooldownUpdatedEvent")
+			assert.equal(
+				cooldown.data.placementCount,
+				cooldownAccount.placementCount
+			)
+			assert.equal(fee.name, "feeCollectedEvent")
+			assert.equal(
+				fee.data.feePaid.toNumber(),
+				placed.data.feePaid.toNumber()
+			)
+			assert.equal(
+				placed.data.feePaid.toNumber(),
+				placed.data.baseFee.toNumber() - placed.data.holderDiscount.toNumber()
+			)
+			assert.equal(
+				placed.data.previousTokenMint !== null,
+				placed.data.isOverwrite
+			)
+
 			console.log("✅ Test passed: Logo placed and verified successfully")
 			console.log("📊 Cost analysis:")
 			console.log(
@@ -292,16 +526,19 @@ describe("solplace", () => {
 		const lng = -75_000_000 // -75.0 degrees
 		const logoUri = "https://example.com/cooldown-test.png"
 
-		const [logoPlacementPda] = getLogoPlacementPDA(lat, lng)
-		const [userCooldownPda] = getUserCooldownPDA(testUser2.publicKey)
-
 		try {
 			// First placement should succeed
 			await program.methods
-				.placeLogo(lat, lng, tokenMint, logoUri)
+				.placeLogo(
+					lat,
+					lng,
+					getPlacementBumps(lat, lng, testUser2.publicKey),
+					tokenMint,
+					logoUri,
+					getLogoHash(logoUri)
+				)
 				.accountsPartial({
-					logoPlacement: logoPlacementPda,
-					userCooldown: userCooldownPda,
+					...getPlacementAccounts(lat, lng, testUser2.publicKey),
 					tokenMint: tokenMint,
 					treasury: provider.publicKey,
 					user: testUser2.publicKey,
@@ -317,15 +554,19 @@ describe("solplace", () => {
 			const lng2 = -75_100_000
 			const logoUri2 = "https://example.com/cooldown-test-2.png"
 
-			const [logoPlacementPda2] = getLogoPlacementPDA(lat2, lng2)
-
 			let cooldownErrorThrown = false
 			try {
 				await program.methods
-					.placeLogo(lat2, lng2, tokenMint, logoUri2)
+					.placeLogo(
+						lat2,
+						lng2,
+						getPlacementBumps(lat2, lng2, testUser2.publicKey),
+						tokenMint,
+						logoUri2,
+						getLogoHash(logoUri2)
+					)
 					.accountsPartial({
-						logoPlacement: logoPlacementPda2,
-						userCooldown: userCooldownPda,
+						...getPlacementAccounts(lat2, lng2, testUser2.publicKey),
 						tokenMint: tokenMint,
 						treasury: provider.publicKey,
 						user: testUser2.publicKey,
@@ -363,15 +604,20 @@ describe("solplace", () => {
 		const newLogoUri = "https://example.com/overwrite-logo.png"
 
 		const [logoPlacementPda] = getLogoPlacementPDA(lat, lng)
-		const [userCooldownPda] = getUserCooldownPDA(testUser3.publicKey)
 
 		try {
 			// First place initial logo
 			await program.methods
-				.placeLogo(lat, lng, tokenMint, initialLogoUri)
+				.placeLogo(
+					lat,
+					lng,
+					getPlacementBumps(lat, lng, testUser3.publicKey),
+					tokenMint,
+					initialLogoUri,
+					getLogoHash(initialLogoUri)
+				)
 				.accountsPartial({
-					logoPlacement: logoPlacementPda,
-					userCooldown: userCooldownPda,
+					...getPlacementAccounts(lat, lng, testUser3.publicKey),
 					tokenMint: tokenMint,
 					treasury: provider.publicKey,
 					user: testUser3.publicKey,
@@ -391,10 +637,16 @@ describe("solplace", () => {
 
 			// Place new logo at same coordinates (overwrite)
 			const tx = await program.methods
-				.placeLogo(lat, lng, tokenMint, newLogoUri)
+				.placeLogo(
+					lat,
+					lng,
+					getPlacementBumps(lat, lng, testUser3.publicKey),
+					tokenMint,
+					newLogoUri,
+					getLogoHash(newLogoUri)
+				)
 				.accountsPartial({
-					logoPlacement: logoPlacementPda,
-					userCooldown: userCooldownPda,
+					...getPlacementAccounts(lat, lng, testUser3.publicKey),
 					tokenMint: tokenMint,
 					treasury: provider.publicKey,
 					user: testUser3.publicKey,
@@ -426,4 +678,1631 @@ describe("solplace", () => {
 			throw error
 		}
 	})
+
+	it("Should reject logo URIs with unsupported schemes", async () => {
+		const lat = 43_000_000
+		const lng = -72_000_000
+		const logoUri = "javascript:alert(1)"
+
+		let schemeErrorThrown = false
+		try {
+			await program.methods
+				.placeLogo(
+					lat,
+					lng,
+					getPlacementBumps(lat, lng, userKeypair.publicKey),
+					tokenMint,
+					logoUri,
+					getLogoHash(logoUri)
+				)
+				.accountsPartial({
+					...getPlacementAccounts(lat, lng, userKeypair.publicKey),
+					tokenMint: tokenMint,
+					treasury: provider.publicKey,
+					user: userKeypair.publicKey,
+					systemProgram: SystemProgram.programId
+				})
+				.signers([userKeypair])
+				.rpc()
+		} catch (error) {
+			schemeErrorThrown = error.message.includes("UnsupportedUriScheme")
+			console.log("✅ URI scheme error correctly thrown:", error.message)
+		}
+
+		assert.isTrue(
+			schemeErrorThrown,
+			"Expected UnsupportedUriScheme error to be thrown"
+		)
+	})
+
+	it("Should report a placement and refund the stake when upheld", async () => {
+		// Reports the logo placed by user3 in the overwrite test
+		const lat = 42_000_000
+		const lng = -71_000_000
+		const reporter = user2Keypair
+
+		const [logoPlacementPda] = getLogoPlacementPDA(lat, lng)
+		const [reportPda] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("report"),
+				logoPlacementPda.toBuffer(),
+				reporter.publicKey.toBuffer()
+			],
+			program.programId
+		)
+		const [reportTallyPda] = PublicKey.findProgramAddressSync(
+			[Buffer.from("report_tally"), logoPlacementPda.toBuffer()],
+			program.programId
+		)
+
+		await program.methods
+			.reportPlacement(lat, lng, { spam: {} })
+			.accountsPartial({
+				logoPlacement: logoPlacementPda,
+				report: reportPda,
+				reportTally: reportTallyPda,
+				reporter: reporter.publicKey
+			})
+			.signers([reporter])
+			.rpc()
+
+		const report = await program.account.placementReport.fetch(reportPda)
+		const tally = await program.account.reportTally.fetch(reportTallyPda)
+		assert.equal(report.reporter.toString(), reporter.publicKey.toString())
+		assert.equal(report.stake.toNumber(), 0.01 * LAMPORTS_PER_SOL)
+		assert.equal(tally.reportCount, 1)
+		assert.isFalse(tally.hidden)
+
+		// The admin is the initial moderator
+		const balanceBefore = await provider.connection.getBalance(
+			reporter.publicKey
+		)
+		await program.methods
+			.resolveReport({ upheld: {} })
+			.accountsPartial({
+				report: reportPda,
+				reportTally: reportTallyPda,
+				reporter: reporter.publicKey,
+				moderator: payer.publicKey
+			})
+			.rpc()
+
+		const balanceAfter = await provider.connection.getBalance(
+			reporter.publicKey
+		)
+		assert.isNull(await provider.connection.getAccountInfo(reportPda))
+		assert.isAbove(balanceAfter - balanceBefore, 0.01 * LAMPORTS_PER_SOL)
+	})
+
+	it("Should reject placements of denied mints", async () => {
+		const lat = 44_000_000
+		const lng = -73_000_000
+		const logoUri = "https://example.com/denied-logo.png"
+
+		const [deniedMintPda] = PublicKey.findProgramAddressSync(
+			[Buffer.from("denied_mint"), tokenMint.toBuffer()],
+			program.programId
+		)
+
+		// The admin is the initial moderator
+		await program.methods
+			.denyMint(tokenMint, { scam: {} })
+			.accountsPartial({
+				deniedMint: deniedMintPda,
+				moderator: payer.publicKey,
+				payer: payer.publicKey
+			})
+			.rpc()
+
+		let mintDeniedErrorThrown = false
+		try {
+			await program.methods
+				.placeLogo(
+					lat,
+					lng,
+					getPlacementBumps(lat, lng, user2Keypair.publicKey),
+					tokenMint,
+					logoUri,
+					getLogoHash(logoUri)
+				)
+				.accountsPartial({
+					...getPlacementAccounts(lat, lng, user2Keypair.publicKey),
+					tokenMint: tokenMint,
+					treasury: provider.publicKey,
+					user: user2Keypair.publicKey,
+					systemProgram: SystemProgram.programId
+				})
+				.signers([user2Keypair])
+				.rpc()
+		} catch (error) {
+			mintDeniedErrorThrown = error.message.includes("MintDenied")
+			console.log("✅ Denied mint error correctly thrown:", error.message)
+		} finally {
+			await program.methods
+				.allowMint(tokenMint)
+				.accountsPartial({
+					deniedMint: deniedMintPda,
+					moderator: payer.publicKey,
+					payer: payer.publicKey
+				})
+				.rpc()
+		}
+
+		assert.isTrue(
+			mintDeniedErrorThrown,
+			"Expected MintDenied error to be thrown"
+		)
+	})
+
+	it("Should reject placements inside restricted zones", async () => {
+		const lat = 45_500_000
+		const lng = -74_500_000
+		const logoUri = "https://example.com/restricted-logo.png"
+
+		const [restrictedZonePda] = getRestrictedZonePDA(lat, lng)
+
+		// Restrict a small box around the target cell of the 45°N 75°W tile
+		await program.methods
+			.setRestrictedZone(45, -75, [
+				{
+					boundingBox: {
+						min: [45_400_000, -74_600_000],
+						max: [45_600_000, -74_400_000]
+					}
+				}
+			])
+			.accountsPartial({
+				restrictedZone: restrictedZonePda,
+				admin: payer.publicKey,
+				payer: payer.publicKey
+			})
+			.rpc()
+
+		let restrictedErrorThrown = false
+		try {
+			await program.methods
+				.placeLogo(
+					lat,
+					lng,
+					getPlacementBumps(lat, lng, user2Keypair.publicKey),
+					tokenMint,
+					logoUri,
+					getLogoHash(logoUri)
+				)
+				.accountsPartial({
+					...getPlacementAccounts(lat, lng, user2Keypair.publicKey),
+					tokenMint: tokenMint,
+					treasury: provider.publicKey,
+					user: user2Keypair.publicKey,
+					systemProgram: SystemProgram.programId
+				})
+				.signers([user2Keypair])
+				.rpc()
+		} catch (error) {
+			restrictedErrorThrown = error.message.includes("PlacementRestricted")
+			console.log(
+				"✅ Restricted zone error correctly thrown:",
+				error.message
+			)
+		} finally {
+			await program.methods
+				.clearRestrictedZone(45, -75)
+				.accountsPartial({
+					restrictedZone: restrictedZonePda,
+					admin: payer.publicKey
+				})
+				.rpc()
+		}
+
+		assert.isTrue(
+			restrictedErrorThrown,
+			"Expected PlacementRestricted error to be thrown"
+		)
+	})
+
+	it("Should refuse placements while paused", async () => {
+		const lat = 46_000_000
+		const lng = -75_000_000
+		const logoUri = "https://example.com/paused-logo.png"
+
+		const pauseTx = await program.methods
+			.setPaused(true)
+			.accountsPartial({ admin: payer.publicKey })
+			.rpc()
+
+		// Admin events travel through self-CPI like placement events
+		const [paused] = await getCpiEvents(pauseTx)
+		assert.equal(paused.name, "programPausedEvent")
+		assert.isTrue(paused.data.paused)
+
+		let pausedErrorThrown = false
+		try {
+			await program.methods
+				.placeLogo(
+					lat,
+					lng,
+					getPlacementBumps(lat, lng, user2Keypair.publicKey),
+					tokenMint,
+					logoUri,
+					getLogoHash(logoUri)
+				)
+				.accountsPartial({
+					...getPlacementAccounts(lat, lng, user2Keypair.publicKey),
+					tokenMint: tokenMint,
+					treasury: provider.publicKey,
+					user: user2Keypair.publicKey,
+					systemProgram: SystemProgram.programId
+				})
+				.signers([user2Keypair])
+				.rpc()
+		} catch (error) {
+			pausedErrorThrown = error.message.includes("ProgramPaused")
+			console.log("✅ Paused error correctly thrown:", error.message)
+		} finally {
+			await program.methods
+				.setPaused(false)
+				.accountsPartial({ admin: payer.publicKey })
+				.rpc()
+		}
+
+		assert.isTrue(
+			pausedErrorThrown,
+			"Expected ProgramPaused error to be thrown"
+		)
+	})
+
+	it("Should hand admin over to a PDA that signs through CPI", async () => {
+		// The proxy fixture is only deployed on localnet
+		const proxyProgram = await provider.connection.getAccountInfo(
+			adminProxy.programId
+		)
+		if (!proxyProgram) {
+			console.log("⏭️ Admin proxy not deployed, skipping")
+			return
+		}
+
+		const [configPda] = getConfigPDA()
+		const [proxyAuthority] = PublicKey.findProgramAddressSync(
+			[Buffer.from("authority")],
+			adminProxy.programId
+		)
+
+		// A different signer cannot accept the proposal
+		await program.methods
+			.proposeAdmin(proxyAuthority)
+			.accountsPartial({ admin: payer.publicKey })
+			.rpc()
+
+		let unauthorizedErrorThrown = false
+		try {
+			await program.methods
+				.acceptAdmin()
+				.accountsPartial({ pendingAdmin: user2Keypair.publicKey })
+				.signers([user2Keypair])
+				.rpc()
+		} catch (error) {
+			unauthorizedErrorThrown = error.message.includes("Unauthorized")
+		}
+		assert.isTrue(unauthorizedErrorThrown, "Expected Unauthorized error")
+
+		// The PDA accepts and administers through CPI
+		await adminProxy.methods
+			.acceptAdmin()
+			.accountsPartial({ config: configPda })
+			.rpc()
+		let config = await program.account.programConfig.fetch(configPda)
+		assert.equal(config.admin.toString(), proxyAuthority.toString())
+		assert.isNull(config.pendingAdmin)
+
+		await adminProxy.methods
+			.setPaused(true)
+			.accountsPartial({ config: configPda })
+			.rpc()
+		config = await program.account.programConfig.fetch(configPda)
+		assert.isTrue(config.paused)
+
+		await adminProxy.methods
+			.setPaused(false)
+			.accountsPartial({ config: configPda })
+			.rpc()
+
+		// The old admin lost its rights
+		let formerAdminErrorThrown = false
+		try {
+			await program.methods
+				.setPaused(true)
+				.accountsPartial({ admin: payer.publicKey })
+				.rpc()
+		} catch (error) {
+			formerAdminErrorThrown = error.message.includes("Unauthorized")
+		}
+		assert.isTrue(formerAdminErrorThrown, "Expected Unauthorized error")
+
+		// Hand admin back to the test wallet
+		await adminProxy.methods
+			.proposeAdmin(payer.publicKey)
+			.accountsPartial({ config: configPda })
+			.rpc()
+		await program.methods
+			.acceptAdmin()
+			.accountsPartial({ pendingAdmin: payer.publicKey })
+			.rpc()
+		config = await program.account.programConfig.fetch(configPda)
+		assert.equal(config.admin.toString(), payer.publicKey.toString())
+	})
+
+	describe("mint profiles", () => {
+		let profileMint: PublicKey
+		let otherMint: PublicKey
+
+		before(async () => {
+			profileMint = await createMint(
+				provider.connection,
+				payer,
+				mintAuthority.publicKey,
+				null,
+				6
+			)
+			otherMint = await createMint(
+				provider.connection,
+				payer,
+				mintAuthority.publicKey,
+				null,
+				6
+			)
+		})
+
+		async function fetchProfile(mint: PublicKey) {
+			return program.account.mintProfile.fetch(getMintProfilePDA(mint)[0])
+		}
+
+		it("Should count cells held and placements of each token", async () => {
+			const lat = 50_000_000
+			const lng = -79_000_000
+
+			// A first placement takes the cell
+			await placeLogoAs(lat, lng, await fundedKeypair(), profileMint)
+			let profile = await fetchProfile(profileMint)
+			assert.equal(profile.tokenMint.toString(), profileMint.toString())
+			assert.equal(profile.cellsHeld, 1)
+			assert.equal(profile.placementCount.toNumber(), 1)
+			assert.equal(profile.totalFeesPaid.toNumber(), 1_000_000)
+
+			// Overwriting with the same token keeps the cell count
+			await placeLogoAs(lat, lng, await fundedKeypair(), profileMint)
+			profile = await fetchProfile(profileMint)
+			assert.equal(profile.cellsHeld, 1)
+			assert.equal(profile.placementCount.toNumber(), 2)
+			assert.equal(profile.totalFeesPaid.toNumber(), 6_000_000)
+
+			// Overwriting with another token moves the cell
+			await placeLogoAs(
+				lat,
+				lng,
+				await fundedKeypair(),
+				otherMint,
+				profileMint
+			)
+			profile = await fetchProfile(profileMint)
+			const other = await fetchProfile(otherMint)
+			assert.equal(profile.cellsHeld, 0)
+			assert.equal(profile.placementCount.toNumber(), 2)
+			assert.equal(other.cellsHeld, 1)
+			assert.equal(other.placementCount.toNumber(), 1)
+			assert.equal(other.totalFeesPaid.toNumber(), 5_000_000)
+		})
+
+		it("Should require the displaced token's profile on overwrites by another token", async () => {
+			const lat = 50_100_000
+			const lng = -79_000_000
+			await placeLogoAs(lat, lng, await fundedKeypair(), profileMint)
+
+			let missingProfileErrorThrown = false
+			try {
+				await placeLogoAs(lat, lng, await fundedKeypair(), otherMint)
+			} catch (error) {
+				missingProfileErrorThrown = error.message.includes(
+					"MissingPreviousMintProfile"
+				)
+			}
+			assert.isTrue(
+				missingProfileErrorThrown,
+				"Expected MissingPreviousMintProfile error to be thrown"
+			)
+		})
+	})
+
+	describe("set_canonical_logo", () => {
+		let canonicalMint: PublicKey
+		let holder: Keypair
+		let whale: Keypair
+
+		// Token account of `owner` holding `amount` of the canonical mint
+		async function holdTokens(owner: Keypair, amount: number) {
+			const account = await getOrCreateAssociatedTokenAccount(
+				provider.connection,
+				payer,
+				canonicalMint,
+				owner.publicKey
+			)
+			await mintTo(
+				provider.connection,
+				payer,
+				canonicalMint,
+				account.address,
+				mintAuthority,
+				amount
+			)
+			return account.address
+		}
+
+		async function setCanonical(
+			user: Keypair,
+			logoUri: string,
+			userTokenAccount: PublicKey | null
+		) {
+			return program.methods
+				.setCanonicalLogo(logoUri, getLogoHash(logoUri))
+				.accountsPartial({
+					mintProfile: getMintProfilePDA(canonicalMint)[0],
+					tokenMint: canonicalMint,
+					userTokenAccount,
+					user: user.publicKey,
+					systemProgram: SystemProgram.programId
+				})
+				.signers([user])
+				.rpc()
+		}
+
+		async function expectCanonicalError(
+			attempt: Promise<string>,
+			expectedError: string
+		) {
+			let errorThrown = false
+			try {
+				await attempt
+			} catch (error) {
+				errorThrown = error.message.includes(expectedError)
+				console.log("✅ Canonical logo change refused:", error.message)
+			}
+			assert.isTrue(errorThrown, `Expected ${expectedError} error`)
+		}
+
+		before(async () => {
+			canonicalMint = await createMint(
+				provider.connection,
+				payer,
+				mintAuthority.publicKey,
+				null,
+				6
+			)
+			holder = await fundedKeypair()
+			whale = await fundedKeypair()
+		})
+
+		it("Should refuse a first claim without holding the token", async () => {
+			await expectCanonicalError(
+				setCanonical(whale, "https://example.com/squatted-logo.png", null),
+				"CanonicalClaimWithoutBalance"
+			)
+		})
+
+		it("Should let a holder claim an unset canonical logo", async () => {
+			const holderTokens = await holdTokens(holder, 100)
+			await setCanonical(
+				holder,
+				"https://example.com/holder-logo.png",
+				holderTokens
+			)
+
+			const profile = await program.account.mintProfile.fetch(
+				getMintProfilePDA(canonicalMint)[0]
+			)
+			assert.equal(profile.canonicalUri, "https://example.com/holder-logo.png")
+			assert.equal(profile.canonicalSetBy.toString(), holder.publicKey.toString())
+			assert.equal(profile.canonicalClaimBalance.toNumber(), 100)
+			assert.isFalse(profile.canonicalLocked)
+		})
+
+		it("Should refuse challenges without a larger balance", async () => {
+			await expectCanonicalError(
+				setCanonical(whale, "https://example.com/whale-logo.png", null),
+				"CanonicalClaimWithoutBalance"
+			)
+			const whaleTokens = await holdTokens(whale, 100)
+			await expectCanonicalError(
+				setCanonical(
+					whale,
+					"https://example.com/whale-logo.png",
+					whaleTokens
+				),
+				"CanonicalLogoLocked"
+			)
+		})
+
+		it("Should refuse token accounts of another holder", async () => {
+			const holderTokens = await getOrCreateAssociatedTokenAccount(
+				provider.connection,
+				payer,
+				canonicalMint,
+				holder.publicKey
+			)
+			await expectCanonicalError(
+				setCanonical(
+					whale,
+					"https://example.com/whale-logo.png",
+					holderTokens.address
+				),
+				"InvalidHolderTokenAccount"
+			)
+		})
+
+		it("Should let a larger holder challenge the claim", async () => {
+			const whaleTokens = await holdTokens(whale, 1)
+			await setCanonical(
+				whale,
+				"https://example.com/whale-logo.png",
+				whaleTokens
+			)
+
+			const profile = await program.account.mintProfile.fetch(
+				getMintProfilePDA(canonicalMint)[0]
+			)
+			assert.equal(profile.canonicalSetBy.toString(), whale.publicKey.toString())
+			assert.equal(profile.canonicalClaimBalance.toNumber(), 101)
+		})
+
+		it("Should lock the logo once the mint authority sets it", async () => {
+			await provider.sendAndConfirm(
+				new anchor.web3.Transaction().add(
+					SystemProgram.transfer({
+						fromPubkey: payer.publicKey,
+						toPubkey: mintAuthority.publicKey,
+						lamports: 0.1 * LAMPORTS_PER_SOL
+					})
+				),
+				[payer]
+			)
+			await setCanonical(
+				mintAuthority,
+				"https://example.com/official-logo.png",
+				null
+			)
+
+			const profile = await program.account.mintProfile.fetch(
+				getMintProfilePDA(canonicalMint)[0]
+			)
+			assert.equal(profile.canonicalUri, "https://example.com/official-logo.png")
+			assert.isTrue(profile.canonicalLocked)
+
+			const whaleTokens = await holdTokens(whale, 1_000_000)
+			await expectCanonicalError(
+				setCanonical(
+					whale,
+					"https://example.com/whale-logo.png",
+					whaleTokens
+				),
+				"CanonicalLogoLocked"
+			)
+		})
+
+		it("Should only place the token with its canonical logo", async () => {
+			const lat = 50_900_000
+			const lng = -80_000_000
+			const placer = await fundedKeypair()
+			const placeWith = (logoUri: string) =>
+				program.methods
+					.placeLogo(
+						lat,
+						lng,
+						getPlacementBumps(lat, lng, placer.publicKey, canonicalMint),
+						canonicalMint,
+						logoUri,
+						getLogoHash(logoUri)
+					)
+					.accountsPartial({
+						...getPlacementAccounts(
+							lat,
+							lng,
+							placer.publicKey,
+							canonicalMint
+						),
+						tokenMint: canonicalMint,
+						treasury: provider.publicKey,
+						user: placer.publicKey,
+						systemProgram: SystemProgram.programId
+					})
+					.signers([placer])
+					.rpc()
+
+			// The placer's own logo is refused rather than replaced
+			let mismatchErrorThrown = false
+			try {
+				await placeWith("https://example.com/placer-logo.png")
+			} catch (error) {
+				mismatchErrorThrown = error.message.includes("CanonicalLogoMismatch")
+			}
+			assert.isTrue(
+				mismatchErrorThrown,
+				"Expected CanonicalLogoMismatch error to be thrown"
+			)
+
+			await placeWith("https://example.com/official-logo.png")
+			const logo = await program.account.logoPlacement.fetch(
+				getLogoPlacementPDA(lat, lng)[0]
+			)
+			assert.equal(logo.logoUri, "https://example.com/official-logo.png")
+		})
+	})
+
+	describe("deny list", () => {
+		let deniedListMint: PublicKey
+
+		before(async () => {
+			deniedListMint = await createMint(
+				provider.connection,
+				payer,
+				mintAuthority.publicKey,
+				null,
+				6
+			)
+		})
+
+		async function expectPlacementError(
+			lat: number,
+			lng: number,
+			user: Keypair,
+			expectedError: string
+		) {
+			let errorThrown = false
+			try {
+				await placeLogoAs(lat, lng, user, deniedListMint)
+			} catch (error) {
+				errorThrown = error.message.includes(expectedError)
+				console.log("✅ Denied placement refused:", error.message)
+			}
+			assert.isTrue(errorThrown, `Expected ${expectedError} error`)
+		}
+
+		it("Should block a mint until it is allowed again", async () => {
+			const lat = 53_000_000
+			const lng = -82_000_000
+			const user = await fundedKeypair()
+			const rentPayer = await fundedKeypair()
+			const { deniedMint } = getDenyListPDAs(deniedListMint, user.publicKey)
+
+			await program.methods
+				.denyMint(deniedListMint, { scam: {} })
+				.accountsPartial({
+					deniedMint: deniedMint[0],
+					moderator: payer.publicKey,
+					payer: rentPayer.publicKey
+				})
+				.signers([rentPayer])
+				.rpc()
+			const entry = await program.account.denyListEntry.fetch(deniedMint[0])
+			assert.equal(entry.address.toString(), deniedListMint.toString())
+			assert.equal(entry.deniedBy.toString(), payer.publicKey.toString())
+			assert.equal(entry.payer.toString(), rentPayer.publicKey.toString())
+			assert.deepEqual(entry.reason, { scam: {} })
+			assert.equal(entry.bump, deniedMint[1])
+
+			await expectPlacementError(lat, lng, user, "MintDenied")
+
+			// The rent goes back to the wallet that paid it, not to the moderator
+			let wrongPayerRejected = false
+			try {
+				await program.methods
+					.allowMint(deniedListMint)
+					.accountsPartial({
+						deniedMint: deniedMint[0],
+						moderator: payer.publicKey,
+						payer: payer.publicKey
+					})
+					.rpc()
+			} catch (error) {
+				wrongPayerRejected = error.message.includes("InvalidRentPayer")
+			}
+			assert.isTrue(wrongPayerRejected, "Expected InvalidRentPayer error")
+
+			const entryRent = (await provider.connection.getAccountInfo(deniedMint[0]))
+				.lamports
+			const rentPayerBefore = await provider.connection.getBalance(
+				rentPayer.publicKey
+			)
+			await program.methods
+				.allowMint(deniedListMint)
+				.accountsPartial({
+					deniedMint: deniedMint[0],
+					moderator: payer.publicKey,
+					payer: rentPayer.publicKey
+				})
+				.rpc()
+			assert.isNull(await provider.connection.getAccountInfo(deniedMint[0]))
+			assert.equal(
+				(await provider.connection.getBalance(rentPayer.publicKey)) -
+					rentPayerBefore,
+				entryRent
+			)
+			await placeLogoAs(lat, lng, user, deniedListMint)
+		})
+
+		it("Should block a user until they are allowed again", async () => {
+			const lat = 53_100_000
+			const lng = -82_000_000
+			const user = await fundedKeypair()
+			const { deniedUser } = getDenyListPDAs(deniedListMint, user.publicKey)
+
+			await program.methods
+				.denyUser(user.publicKey, { offensive: {} })
+				.accountsPartial({
+					deniedUser: deniedUser[0],
+					moderator: payer.publicKey,
+					payer: payer.publicKey
+				})
+				.rpc()
+			const entry = await program.account.denyListEntry.fetch(deniedUser[0])
+			assert.equal(entry.address.toString(), user.publicKey.toString())
+			assert.equal(entry.deniedBy.toString(), payer.publicKey.toString())
+			assert.equal(entry.payer.toString(), payer.publicKey.toString())
+			assert.deepEqual(entry.reason, { offensive: {} })
+			assert.equal(entry.bump, deniedUser[1])
+
+			await expectPlacementError(lat, lng, user, "UserDenied")
+
+			await program.methods
+				.allowUser(user.publicKey)
+				.accountsPartial({
+					deniedUser: deniedUser[0],
+					moderator: payer.publicKey,
+					payer: payer.publicKey
+				})
+				.rpc()
+			assert.isNull(await provider.connection.getAccountInfo(deniedUser[0]))
+			await placeLogoAs(lat, lng, user, deniedListMint)
+		})
+
+		it("Should refuse deny list changes from other wallets", async () => {
+			const outsider = await fundedKeypair()
+			const { deniedMint, deniedUser } = getDenyListPDAs(
+				deniedListMint,
+				outsider.publicKey
+			)
+			const attempts = [
+				program.methods.denyMint(deniedListMint, { spam: {} }).accountsPartial({
+					deniedMint: deniedMint[0],
+					moderator: outsider.publicKey,
+					payer: outsider.publicKey
+				}),
+				program.methods.denyUser(outsider.publicKey, { spam: {} }).accountsPartial({
+					deniedUser: deniedUser[0],
+					moderator: outsider.publicKey,
+					payer: outsider.publicKey
+				})
+			]
+			for (const attempt of attempts) {
+				let unauthorized = false
+				try {
+					await attempt.signers([outsider]).rpc()
+				} catch (error) {
+					unauthorized = error.message.includes("Unauthorized")
+				}
+				assert.isTrue(unauthorized, "Expected Unauthorized error")
+			}
+		})
+	})
+
+	describe("resolve_report", () => {
+		const lat = 52_000_000
+		const lng = -81_000_000
+		const [logoPlacementPda] = getLogoPlacementPDA(lat, lng)
+		const [reportTallyPda] = PublicKey.findProgramAddressSync(
+			[Buffer.from("report_tally"), logoPlacementPda.toBuffer()],
+			program.programId
+		)
+
+		function getReportPDA(reporter: PublicKey): PublicKey {
+			return PublicKey.findProgramAddressSync(
+				[
+					Buffer.from("report"),
+					logoPlacementPda.toBuffer(),
+					reporter.toBuffer()
+				],
+				program.programId
+			)[0]
+		}
+
+		async function reportAs(reporter: Keypair) {
+			await program.methods
+				.reportPlacement(lat, lng, { spam: {} })
+				.accountsPartial({
+					logoPlacement: logoPlacementPda,
+					report: getReportPDA(reporter.publicKey),
+					reportTally: reportTallyPda,
+					reporter: reporter.publicKey
+				})
+				.signers([reporter])
+				.rpc()
+		}
+
+		it("Should slash the stake and unhide the logo when rejected", async () => {
+			await placeLogoAs(lat, lng, await fundedKeypair())
+
+			// Three reports reach the hide threshold
+			const reporters = [
+				await fundedKeypair(),
+				await fundedKeypair(),
+				await fundedKeypair()
+			]
+			for (const reporter of reporters) {
+				await reportAs(reporter)
+			}
+			let tally = await program.account.reportTally.fetch(reportTallyPda)
+			assert.equal(tally.reportCount, 3)
+			assert.isTrue(tally.hidden)
+
+			const reporter = reporters[0]
+			const reportPda = getReportPDA(reporter.publicKey)
+			const reportRent = (await provider.connection.getAccountInfo(reportPda))
+				.lamports - 0.01 * LAMPORTS_PER_SOL
+			const reporterBefore = await provider.connection.getBalance(
+				reporter.publicKey
+			)
+			const treasuryBefore = await provider.connection.getBalance(
+				provider.publicKey
+			)
+
+			// The moderator is the treasury wallet and pays the fee
+			const signature = await program.methods
+				.resolveReport({ rejected: {} })
+				.accountsPartial({
+					report: reportPda,
+					reportTally: reportTallyPda,
+					reporter: reporter.publicKey,
+					treasury: provider.publicKey,
+					moderator: payer.publicKey
+				})
+				.rpc({ commitment: "confirmed" })
+			const tx = await provider.connection.getTransaction(signature, {
+				commitment: "confirmed",
+				maxSupportedTransactionVersion: 0
+			})
+
+			assert.isNull(await provider.connection.getAccountInfo(reportPda))
+			// The reporter only gets the report rent back, the stake goes to the treasury
+			assert.equal(
+				(await provider.connection.getBalance(reporter.publicKey)) -
+					reporterBefore,
+				reportRent
+			)
+			assert.equal(
+				(await provider.connection.getBalance(provider.publicKey)) -
+					treasuryBefore,
+				0.01 * LAMPORTS_PER_SOL - tx.meta.fee
+			)
+
+			tally = await program.account.reportTally.fetch(reportTallyPda)
+			assert.equal(tally.reportCount, 2)
+			assert.isFalse(tally.hidden)
+		})
+	})
+
+	describe("moderate_logo", () => {
+		let moderatedMint: PublicKey
+
+		before(async () => {
+			moderatedMint = await createMint(
+				provider.connection,
+				payer,
+				mintAuthority.publicKey,
+				null,
+				6
+			)
+		})
+
+		// Upload a single-color 16x16 bitmap for the placement of `user`
+		async function writeBitmap(lat: number, lng: number, user: Keypair) {
+			const payload = Buffer.from([
+				1, 16, 16, 1, 1, // version, width, height, RLE encoding, palette size
+				255, 0, 0, 255, // red
+				...new Array(16).fill(0xf0) // 16 runs of 16 pixels
+			])
+			await program.methods
+				.writeLogoBitmap(lat, lng, payload.length, 0, payload)
+				.accountsPartial({
+					logoPlacement: getLogoPlacementPDA(lat, lng)[0],
+					user: user.publicKey
+				})
+				.signers([user])
+				.rpc()
+			return getLogoBitmapPDA(lat, lng)
+		}
+
+		function getLogoBitmapPDA(lat: number, lng: number): PublicKey {
+			const latBuffer = Buffer.allocUnsafe(4)
+			latBuffer.writeInt32LE(lat)
+			const lngBuffer = Buffer.allocUnsafe(4)
+			lngBuffer.writeInt32LE(lng)
+			return PublicKey.findProgramAddressSync(
+				[Buffer.from("logo_bitmap"), latBuffer, lngBuffer],
+				program.programId
+			)[0]
+		}
+
+		function getReportTallyPDA(lat: number, lng: number): PublicKey {
+			return PublicKey.findProgramAddressSync(
+				[
+					Buffer.from("report_tally"),
+					getLogoPlacementPDA(lat, lng)[0].toBuffer()
+				],
+				program.programId
+			)[0]
+		}
+
+		async function moderate(
+			lat: number,
+			lng: number,
+			action: { blank: {} } | { close: {} },
+			placer: PublicKey
+		) {
+			return program.methods
+				.moderateLogo(lat, lng, { offensive: {} }, action)
+				.accountsPartial({
+					logoPlacement: getLogoPlacementPDA(lat, lng)[0],
+					mintProfile: getMintProfilePDA(moderatedMint)[0],
+					logoBitmap: getLogoBitmapPDA(lat, lng),
+					reportTally: getReportTallyPDA(lat, lng),
+					placer,
+					treasury: provider.publicKey,
+					moderator: payer.publicKey
+				})
+				.rpc()
+		}
+
+		// Rent held by the placement of a cell and its bitmap
+		async function getCellRent(lat: number, lng: number): Promise<number> {
+			return (
+				(await provider.connection.getBalance(
+					getLogoPlacementPDA(lat, lng)[0]
+				)) +
+				(await provider.connection.getBalance(getLogoBitmapPDA(lat, lng)))
+			)
+		}
+
+		it("Should blank a placement and close its bitmap", async () => {
+			const lat = 51_000_000
+			const lng = -80_000_000
+			const placer = await fundedKeypair()
+			await placeLogoAs(lat, lng, placer, moderatedMint)
+			const logoBitmapPda = await writeBitmap(lat, lng, placer)
+			const cellsHeld = (
+				await program.account.mintProfile.fetch(
+					getMintProfilePDA(moderatedMint)[0]
+				)
+			).cellsHeld
+
+			await moderate(lat, lng, { blank: {} }, placer.publicKey)
+
+			const [logoPlacementPda] = getLogoPlacementPDA(lat, lng)
+			const logo = await program.account.logoPlacement.fetch(logoPlacementPda)
+			assert.equal(logo.tokenMint.toString(), PublicKey.default.toString())
+			assert.equal(logo.logoUri, "")
+			assert.isNull(await provider.connection.getAccountInfo(logoBitmapPda))
+			const profile = await program.account.mintProfile.fetch(
+				getMintProfilePDA(moderatedMint)[0]
+			)
+			assert.equal(profile.cellsHeld, cellsHeld - 1)
+
+			// A blank cell cannot be blanked twice
+			let alreadyBlankErrorThrown = false
+			try {
+				await moderate(lat, lng, { blank: {} }, placer.publicKey)
+			} catch (error) {
+				alreadyBlankErrorThrown = error.message.includes(
+					"PlacementAlreadyBlank"
+				)
+			}
+			assert.isTrue(
+				alreadyBlankErrorThrown,
+				"Expected PlacementAlreadyBlank error to be thrown"
+			)
+		})
+
+		it("Should refund the rent of a closed placement to the placer", async () => {
+			const lat = 51_100_000
+			const lng = -80_000_000
+			const placer = await fundedKeypair()
+			await placeLogoAs(lat, lng, placer, moderatedMint)
+			const logoBitmapPda = await writeBitmap(lat, lng, placer)
+
+			const rent = await getCellRent(lat, lng)
+			const placerBefore = await provider.connection.getBalance(
+				placer.publicKey
+			)
+			const treasuryBefore = await provider.connection.getBalance(
+				provider.publicKey
+			)
+
+			const signature = await moderate(
+				lat,
+				lng,
+				{ close: {} },
+				placer.publicKey
+			)
+			await provider.connection.confirmTransaction(signature, "confirmed")
+			const tx = await provider.connection.getTransaction(signature, {
+				commitment: "confirmed",
+				maxSupportedTransactionVersion: 0
+			})
+
+			assert.isNull(
+				await provider.connection.getAccountInfo(
+					getLogoPlacementPDA(lat, lng)[0]
+				)
+			)
+			assert.isNull(await provider.connection.getAccountInfo(logoBitmapPda))
+			assert.equal(
+				(await provider.connection.getBalance(placer.publicKey)) - placerBefore,
+				rent
+			)
+			// The test wallet is both the treasury and the moderator paying the fee
+			assert.equal(
+				(await provider.connection.getBalance(provider.publicKey)) -
+					treasuryBefore,
+				-tx.meta.fee
+			)
+		})
+
+		it("Should keep the rent in the treasury once a report was upheld", async () => {
+			const lat = 51_200_000
+			const lng = -80_000_000
+			const [logoPlacementPda] = getLogoPlacementPDA(lat, lng)
+			const reportTallyPda = getReportTallyPDA(lat, lng)
+			const placer = await fundedKeypair()
+			await placeLogoAs(lat, lng, placer, moderatedMint)
+			const logoBitmapPda = await writeBitmap(lat, lng, placer)
+
+			// A moderator upholds a report against the placement
+			const reporter = await fundedKeypair()
+			const [reportPda] = PublicKey.findProgramAddressSync(
+				[
+					Buffer.from("report"),
+					logoPlacementPda.toBuffer(),
+					reporter.publicKey.toBuffer()
+				],
+				program.programId
+			)
+			await program.methods
+				.reportPlacement(lat, lng, { offensive: {} })
+				.accountsPartial({
+					logoPlacement: logoPlacementPda,
+					report: reportPda,
+					reportTally: reportTallyPda,
+					reporter: reporter.publicKey
+				})
+				.signers([reporter])
+				.rpc()
+			await program.methods
+				.resolveReport({ upheld: {} })
+				.accountsPartial({
+					report: reportPda,
+					reportTally: reportTallyPda,
+					reporter: reporter.publicKey,
+					treasury: provider.publicKey,
+					moderator: payer.publicKey
+				})
+				.rpc()
+			const tally = await program.account.reportTally.fetch(reportTallyPda)
+			assert.equal(tally.upheldCount, 1)
+
+			// Only the wallet of the placement can be named as the placer
+			let invalidPlacerErrorThrown = false
+			try {
+				await moderate(lat, lng, { close: {} }, reporter.publicKey)
+			} catch (error) {
+				invalidPlacerErrorThrown = error.message.includes("InvalidPlacer")
+			}
+			assert.isTrue(
+				invalidPlacerErrorThrown,
+				"Expected InvalidPlacer error to be thrown"
+			)
+
+			const rent = await getCellRent(lat, lng)
+			const placerBefore = await provider.connection.getBalance(
+				placer.publicKey
+			)
+			const treasuryBefore = await provider.connection.getBalance(
+				provider.publicKey
+			)
+
+			const signature = await moderate(
+				lat,
+				lng,
+				{ close: {} },
+				placer.publicKey
+			)
+			const events = await getCpiEvents(signature)
+			const tx = await provider.connection.getTransaction(signature, {
+				commitment: "confirmed",
+				maxSupportedTransactionVersion: 0
+			})
+
+			assert.isNull(await provider.connection.getAccountInfo(logoPlacementPda))
+			assert.isNull(await provider.connection.getAccountInfo(logoBitmapPda))
+			assert.equal(
+				await provider.connection.getBalance(placer.publicKey),
+				placerBefore
+			)
+			assert.equal(
+				(await provider.connection.getBalance(provider.publicKey)) -
+					treasuryBefore,
+				rent - tx.meta.fee
+			)
+			const moderated = events.find(
+				(event) => event.name === "logoModeratedEvent"
+			)
+			assert.equal(moderated.data.reclaimedLamports.toNumber(), rent)
+			assert.equal(
+				moderated.data.rentTo.toString(),
+				provider.publicKey.toString()
+			)
+		})
+	})
+
+	describe("place_logo account validation", () => {
+		// Placement attempt with overridable accounts, used to feed invalid accounts
+		async function placeLogoWith(
+			lat: number,
+			lng: number,
+			user: Keypair,
+			accounts: { [name: string]: PublicKey }
+		) {
+			const logoUri = "https://example.com/validation-logo.png"
+			return program.methods
+				.placeLogo(
+					lat,
+					lng,
+					getPlacementBumps(lat, lng, user.publicKey),
+					tokenMint,
+					logoUri,
+					getLogoHash(logoUri)
+				)
+				.accountsPartial({
+					...getPlacementAccounts(lat, lng, user.publicKey),
+					...accounts,
+					tokenMint: tokenMint,
+					treasury: provider.publicKey,
+					user: user.publicKey,
+					systemProgram: SystemProgram.programId
+				})
+				.signers([user])
+				.rpc()
+		}
+
+		async function expectRejected(
+			placement: Promise<string>,
+			expectedErrors: string[]
+		) {
+			let rejected = false
+			try {
+				await placement
+			} catch (error) {
+				rejected = expectedErrors.some((name) =>
+					error.message.includes(name)
+				)
+				console.log(
+					"✅ Invalid account correctly rejected:",
+					error.message
+				)
+			}
+			assert.isTrue(
+				rejected,
+				`Expected one of ${expectedErrors.join(", ")}`
+			)
+		}
+
+		it("Should create program-owned v1 placement and cooldown accounts", async () => {
+			const lat = 47_000_000
+			const lng = -76_000_000
+
+			await placeLogoWith(lat, lng, user2Keypair, {})
+
+			const [logoPlacementPda, logoBump] = getLogoPlacementPDA(lat, lng)
+			const [userCooldownPda, cooldownBump] = getUserCooldownPDA(
+				user2Keypair.publicKey
+			)
+			const logoInfo =
+				await provider.connection.getAccountInfo(logoPlacementPda)
+			const cooldownInfo =
+				await provider.connection.getAccountInfo(userCooldownPda)
+			assert.equal(logoInfo.owner.toString(), program.programId.toString())
+			assert.equal(
+				cooldownInfo.owner.toString(),
+				program.programId.toString()
+			)
+			assert.equal(logoInfo.data.length, 328) // LogoPlacement::SIZE
+			assert.equal(cooldownInfo.data.length, 54) // UserCooldown::SIZE
+
+			const logo =
+				await program.account.logoPlacement.fetch(logoPlacementPda)
+			const cooldown =
+				await program.account.userCooldown.fetch(userCooldownPda)
+			assert.equal(logo.version, 1)
+			assert.equal(logo.bump, logoBump)
+			assert.deepEqual(logo.coordinates, [lat, lng])
+			assert.equal(logo.overwriteCount, 0)
+			assert.equal(cooldown.version, 1)
+			assert.equal(cooldown.bump, cooldownBump)
+			assert.equal(
+				cooldown.user.toString(),
+				user2Keypair.publicKey.toString()
+			)
+			assert.equal(cooldown.placementCount, 1)
+		})
+
+		it("Should reject a foreign-owned account passed as logo_placement", async () => {
+			// The token mint is owned by the token program
+			await expectRejected(
+				placeLogoWith(48_000_000, -77_000_000, user3Keypair, {
+					logoPlacement: tokenMint
+				}),
+				["ConstraintSeeds", "AccountOwnedByWrongProgram"]
+			)
+
+			const mintInfo = await provider.connection.getAccountInfo(tokenMint)
+			assert.equal(mintInfo.owner.toString(), TOKEN_PROGRAM_ID.toString())
+		})
+
+		it("Should reject a placement PDA of other coordinates", async () => {
+			await expectRejected(
+				placeLogoWith(48_000_000, -77_000_000, user3Keypair, {
+					logoPlacement: getLogoPlacementPDA(48_000_001, -77_000_000)[0]
+				}),
+				["ConstraintSeeds"]
+			)
+		})
+
+		it("Should reject the cooldown account of another user", async () => {
+			await expectRejected(
+				placeLogoWith(48_000_000, -77_000_000, user3Keypair, {
+					userCooldown: getUserCooldownPDA(userKeypair.publicKey)[0]
+				}),
+				["ConstraintSeeds"]
+			)
+		})
+
+		it("Should not create a placement at a non-canonical bump", async () => {
+			const lat = 48_100_000
+			const lng = -77_000_000
+			const latBuffer = Buffer.allocUnsafe(4)
+			latBuffer.writeInt32LE(lat)
+			const lngBuffer = Buffer.allocUnsafe(4)
+			lngBuffer.writeInt32LE(lng)
+
+			// Highest valid bump below the canonical one
+			const [, canonicalBump] = getLogoPlacementPDA(lat, lng)
+			let lowerBump = canonicalBump
+			let lowerPda: PublicKey | null = null
+			while (lowerPda === null) {
+				lowerBump--
+				try {
+					lowerPda = PublicKey.createProgramAddressSync(
+						[
+							Buffer.from("logo_placement"),
+							latBuffer,
+							lngBuffer,
+							Buffer.from([lowerBump])
+						],
+						program.programId
+					)
+				} catch {
+					// Not off the curve, try the next bump
+				}
+			}
+
+			const logoUri = "https://example.com/validation-logo.png"
+			await expectRejected(
+				program.methods
+					.placeLogo(
+						lat,
+						lng,
+						{
+							...getPlacementBumps(lat, lng, user3Keypair.publicKey),
+							logoPlacement: lowerBump
+						},
+						tokenMint,
+						logoUri,
+						getLogoHash(logoUri)
+					)
+					.accountsPartial({
+						...getPlacementAccounts(lat, lng, user3Keypair.publicKey),
+						logoPlacement: lowerPda,
+						tokenMint: tokenMint,
+						treasury: provider.publicKey,
+						user: user3Keypair.publicKey,
+						systemProgram: SystemProgram.programId
+					})
+					.signers([user3Keypair])
+					.rpc(),
+				["NonCanonicalBump"]
+			)
+			assert.isNull(await provider.connection.getAccountInfo(lowerPda))
+		})
+	})
+
+	describe("place_logo compute units", () => {
+		// The baseline program is the handler before bump arguments, built by
+		// scripts/build-baseline.sh and loaded by Anchor.toml. It finds the
+		// placement and cooldown PDAs with find_program_address, which pays for a
+		// create_program_address per bump tried below 255. Both programs place the
+		// same token from users whose cooldown already exists, once with those PDAs
+		// at bump 255 and once at the same lower bumps under both program ids.
+		const baselineProgramId = new PublicKey(
+			"CzsZbLqwJGuhumExU2YNnu6XNFg3gHAs6sc5jCb87stF"
+		)
+		// TREASURY_ADDRESS of the baseline program
+		const baselineTreasury = new PublicKey(
+			"3ojcMQjKYfME4qGmgE8Qb9odKcu4kSes1xhcVXD7DXCd"
+		)
+		// Cost of a create_program_address syscall
+		const bumpUnits = 1_500
+		const lowBump = 250
+		const cooldownPeriod = 30
+		const logoUri = "https://example.com/benchmark-logo.png"
+
+		type Cell = [number, number]
+		type Setup = { user: Keypair; newCell: Cell; overwriteCell: Cell }
+		type Units = { baseline: number; current: number }
+		let mint: PublicKey
+		let top: Setup
+		let low: Setup
+
+		function cellSeeds([lat, lng]: Cell): Buffer[] {
+			const latBuffer = Buffer.allocUnsafe(4)
+			latBuffer.writeInt32LE(lat)
+			const lngBuffer = Buffer.allocUnsafe(4)
+			lngBuffer.writeInt32LE(lng)
+			return [Buffer.from("logo_placement"), latBuffer, lngBuffer]
+		}
+
+		function cooldownSeeds(user: PublicKey): Buffer[] {
+			return [Buffer.from("cooldown"), user.toBuffer()]
+		}
+
+		// The bump of a PDA under both programs, null when they differ
+		function sharedBump(seeds: Buffer[]): number | null {
+			const [current, baseline] = [program.programId, baselineProgramId].map(
+				(programId) => PublicKey.findProgramAddressSync(seeds, programId)[1]
+			)
+			return current === baseline ? current : null
+		}
+
+		function atBump(seeds: Buffer[], accept: (bump: number) => boolean) {
+			const bump = sharedBump(seeds)
+			return bump !== null && accept(bump)
+		}
+
+		// Bumps below 255 find_program_address tries for the cooldown and the cell
+		function searchedBumps({ user }: Setup, cell: Cell): number {
+			return (
+				510 -
+				sharedBump(cooldownSeeds(user.publicKey)) -
+				sharedBump(cellSeeds(cell))
+			)
+		}
+
+		// Generate keys until `accept` holds for their PDAs
+		function keypairWhere(accept: (keypair: Keypair) => boolean): Keypair {
+			for (;;) {
+				const keypair = Keypair.generate()
+				if (accept(keypair)) {
+					return keypair
+				}
+			}
+		}
+
+		// The first cell north of `from` whose placement PDAs pass `accept`
+		function cellWhere(from: Cell, accept: (bump: number) => boolean): Cell {
+			let [lat, lng] = from
+			while (!atBump(cellSeeds([lat, lng]), accept)) {
+				lat++
+			}
+			return [lat, lng]
+		}
+
+		// Anchor's place_logo instruction of the baseline program
+		function baselinePlaceLogo(
+			[lat, lng]: Cell,
+			user: PublicKey
+		): anchor.web3.Transaction {
+			const uri = Buffer.from(logoUri)
+			const data = Buffer.alloc(8 + 4 + 4 + 32 + 4 + uri.length)
+			createHash("sha256")
+				.update("global:place_logo")
+				.digest()
+				.copy(data, 0, 0, 8)
+			data.writeInt32LE(lat, 8)
+			data.writeInt32LE(lng, 12)
+			mint.toBuffer().copy(data, 16)
+			data.writeUInt32LE(uri.length, 48)
+			uri.copy(data, 52)
+
+			const pda = (seeds: Buffer[]) =>
+				PublicKey.findProgramAddressSync(seeds, baselineProgramId)[0]
+			return new anchor.web3.Transaction().add(
+				new TransactionInstruction({
+					programId: baselineProgramId,
+					keys: [
+						{
+							pubkey: pda(cellSeeds([lat, lng])),
+							isSigner: false,
+							isWritable: true
+						},
+						{
+							pubkey: pda(cooldownSeeds(user)),
+							isSigner: false,
+							isWritable: true
+						},
+						{ pubkey: mint, isSigner: false, isWritable: false },
+						{
+							pubkey: baselineTreasury,
+							isSigner: false,
+							isWritable: true
+						},
+						{ pubkey: user, isSigner: true, isWritable: true },
+						{
+							pubkey: SystemProgram.programId,
+							isSigner: false,
+							isWritable: false
+						}
+					],
+					data
+				})
+			)
+		}
+
+		function currentPlaceLogo(
+			[lat, lng]: Cell,
+			user: PublicKey
+		): Promise<anchor.web3.Transaction> {
+			return program.methods
+				.placeLogo(
+					lat,
+					lng,
+					getPlacementBumps(lat, lng, user, mint),
+					mint,
+					logoUri,
+					getLogoHash(logoUri)
+				)
+				.accountsPartial({
+					...getPlacementAccounts(lat, lng, user, mint),
+					tokenMint: mint,
+					treasury: provider.publicKey,
+					user,
+					systemProgram: SystemProgram.programId
+				})
+				.transaction()
+		}
+
+		// Place the benchmarked token on `cell` with both programs
+		async function placeOnBoth(cell: Cell, user: Keypair) {
+			await provider.sendAndConfirm(baselinePlaceLogo(cell, user.publicKey), [
+				user
+			])
+			await provider.sendAndConfirm(
+				await currentPlaceLogo(cell, user.publicKey),
+				[user]
+			)
+		}
+
+		async function simulateUnits(
+			tx: anchor.web3.Transaction,
+			user: Keypair
+		): Promise<number> {
+			tx.feePayer = user.publicKey
+			const { value } = await provider.connection.simulateTransaction(tx, [
+				user
+			])
+			assert.isNull(value.err, value.logs?.join("\n"))
+			return value.unitsConsumed
+		}
+
+		async function measure(
+			{ user }: Setup,
+			cell: Cell
+		): Promise<Units> {
+			return {
+				baseline: await simulateUnits(
+					baselinePlaceLogo(cell, user.publicKey),
+					user
+				),
+				current: await simulateUnits(
+					await currentPlaceLogo(cell, user.publicKey),
+					user
+				)
+			}
+		}
+
+		async function setup(
+			from: Cell,
+			accept: (bump: number) => boolean
+		): Promise<Setup> {
+			const user = await fundedKeypair(
+				keypairWhere(({ publicKey }) =>
+					atBump(cooldownSeeds(publicKey), accept)
+				)
+			)
+			const newCell = cellWhere(from, accept)
+			const overwriteCell = cellWhere([newCell[0] + 1, newCell[1]], accept)
+
+			// The user's first placement creates their cooldowns, another
+			// wallet creates the overwritten cell
+			await placeOnBoth([from[0] + 900_000, from[1]], user)
+			await placeOnBoth(overwriteCell, await fundedKeypair())
+			return { user, newCell, overwriteCell }
+		}
+
+		function report(path: string, topUnits: Units, lowUnits: Units) {
+			console.log(
+				`📊 ${path}: ${lowUnits.baseline} CU before, ${lowUnits.current} CU after ` +
+					`at lower bumps (${topUnits.baseline} CU before, ` +
+					`${topUnits.current} CU after at bump 255)`
+			)
+			return {
+				baseline: lowUnits.baseline - topUnits.baseline,
+				current: lowUnits.current - topUnits.current
+			}
+		}
+
+		before(async function () {
+			this.timeout(120_000)
+
+			const mintKeypair = keypairWhere(
+				({ publicKey }) => getMintProfilePDA(publicKey)[1] === 255
+			)
+			mint = await createMint(
+				provider.connection,
+				payer,
+				mintAuthority.publicKey,
+				null,
+				6,
+				mintKeypair
+			)
+			top = await setup([70_000_000, 10_000_000], (bump) => bump === 255)
+			low = await setup([71_000_000, 10_000_000], (bump) => bump <= lowBump)
+
+			// Let the cooldowns of the first placements run out
+			await new Promise((resolve) =>
+				setTimeout(resolve, (cooldownPeriod + 1) * 1_000)
+			)
+		})
+
+		it("Should only search the bump of new cells", async () => {
+			const extra = report(
+				"new cell",
+				await measure(top, top.newCell),
+				await measure(low, low.newCell)
+			)
+			// Creating the cell still proves its bump is canonical, the existing
+			// cooldown is loaded at its bump
+			assert.isAtLeast(
+				extra.baseline,
+				bumpUnits * searchedBumps(low, low.newCell)
+			)
+			assert.isBelow(extra.current, extra.baseline)
+		})
+
+		it("Should not pay for a bump search on overwrites", async () => {
+			const extra = report(
+				"overwrite",
+				await measure(top, top.overwriteCell),
+				await measure(low, low.overwriteCell)
+			)
+			assert.isAtLeast(
+				extra.baseline,
+				bumpUnits * searchedBumps(low, low.overwriteCell)
+			)
+			assert.isBelow(Math.abs(extra.current), bumpUnits)
+		})
+	})
 })
@@ -1,5 +1,5 @@
 import { AnchorProvider, Idl, Program } from "@coral-xyz/anchor"
-import { getMint } from "@solana/spl-token"
+import { getAssociatedTokenAddressSync, getMint } from "@solana/spl-token"
 import {
 	Connection,
 	PublicKey,
@@ -11,7 +11,11 @@ import { tokenMetadataResolver } from "../token-metadata-resolver"
 import type { LogoPlacement, PlacementFee, UserCooldown } from "../types"
 import {
 	calculatePlacementFee,
+	getLogoHash,
 	getLogoPlacementPDA,
+	getMintProfilePDA,
+	getPlacementBumps,
+	getRestrictedZonePDA,
 	getUserCooldownPDA
 } from "../utils"
 import { convertAnchorCooldown } from "./converters"
@@ -234,27 +238,47 @@ export class CoreClient {
 			const latMicro = Math.round(lat * 1_000_000)
 			const lngMicro = Math.round(lng * 1_000_000)
 
-			// Calculate PDAs using the new architecture
-			const logoPlacementPDA = getLogoPlacementPDA(
-				lat,
-				lng,
-				this.programId
-			)
-			const userCooldownPDA = getUserCooldownPDA(
-				this.wallet.publicKey,
-				this.programId
+			// Token holders get a discount, the program checks the account when it is passed
+			const userTokenAccount = getAssociatedTokenAddressSync(
+				tokenMint,
+				this.wallet.publicKey
 			)
+			const userTokenAccountInfo =
+				await this.connection.getAccountInfo(userTokenAccount)
 
-			// Execute transaction using new architecture
+			// Overwriting another token's logo also updates that token's mint profile
+			const existingLogo = await this.loadLogoAtCoordinates(lat, lng)
+			const previousMint =
+				existingLogo && existingLogo.tokenMint !== tokenMint.toString()
+					? new PublicKey(existingLogo.tokenMint)
+					: null
+
+			// Logo placement, cooldown, mint profile and deny list PDAs are derived from the IDL seeds
 			const txSignature = await program.methods
-				.placeLogo(latMicro, lngMicro, tokenMint, logoUri)
-				.accounts({
-					logoPlacement: logoPlacementPDA.publicKey,
-					userCooldown: userCooldownPDA.publicKey,
+				.placeLogo(
+					latMicro,
+					lngMicro,
+					getPlacementBumps(
+						lat,
+						lng,
+						tokenMint,
+						this.wallet.publicKey,
+						this.programId
+					),
+					tokenMint,
+					logoUri,
+					await getLogoHash(logoUri)
+				)
+				.accountsPartial({
 					tokenMint: tokenMint,
+					userTokenAccount: userTokenAccountInfo ? userTokenAccount : null,
+					previousMintProfile: previousMint
+						? getMintProfilePDA(previousMint, this.programId).publicKey
+						: null,
+					restrictedZone: getRestrictedZonePDA(lat, lng, this.programId)
+						.publicKey,
 					treasury: treasuryAddress,
 					user: this.wallet.publicKey
-					// systemProgram is automatically included by Anchor
 				})
 				.rpc()
 
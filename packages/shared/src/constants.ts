@@ -9,6 +9,10 @@ export const COOLDOWN_PERIOD = 30 // seconds
 // PDA Seeds (must match Rust program)
 export const LOGO_PLACEMENT_SEED = "logo_placement"
 export const COOLDOWN_SEED = "cooldown"
+export const MINT_PROFILE_SEED = "mint_profile"
+export const RESTRICTED_ZONE_SEED = "restricted_zone"
+export const DENIED_MINT_SEED = "denied_mint"
+export const DENIED_USER_SEED = "denied_user"
 export const CLUSTER_SEED = "cluster" // Temporary for transitional support
 
 // Coordinate Constraints (in microdegrees)
@@ -16,9 +20,12 @@ export const MIN_LATITUDE = -90_000_000 // -90° in microdegrees
 export const MAX_LATITUDE = 90_000_000 // +90° in microdegrees
 export const MIN_LONGITUDE = -180_000_000 // -180° in microdegrees
 export const MAX_LONGITUDE = 180_000_000 // +180° in microdegrees
+export const ZONE_TILE_SIZE = 1_000_000 // 1° restricted zone tiles in microdegrees
 
 // Logo Constraints
 export const MAX_LOGO_URI_LENGTH = 200 // Max characters for logo URI
+export const IPFS_GATEWAY = "https://ipfs.io/ipfs/" // Fetches ipfs:// logos
+export const ARWEAVE_GATEWAY = "https://arweave.net/" // Fetches ar:// logos
 
 // Cluster Configuration (temporary for transitional support)
 export const CLUSTER_RESOLUTION = 100_000 // ~10km in microdegrees
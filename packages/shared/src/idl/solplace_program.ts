@@ -14,96 +14,512 @@ export type SolplaceProgram = {
   },
   "instructions": [
     {
-      "name": "exposeTypes",
-      "docs": [
-        "This instruction exists solely to expose LogoPlacement and UserCooldown types to the IDL",
-        "It should never be called directly"
-      ],
+      "name": "acceptAdmin",
       "discriminator": [
-        252,
-        180,
-        222,
-        102,
-        33,
-        96,
-        232,
-        36
+        112,
+        42,
+        45,
+        90,
+        116,
+        181,
+        13,
+        170
       ],
       "accounts": [
         {
-          "name": "logoPlacement",
-          "docs": [
-            "Expose LogoPlacement type to IDL"
-          ]
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "userCooldown",
+          "name": "pendingAdmin",
           "docs": [
-            "Expose UserCooldown type to IDL"
-          ]
+            "Proposed admin, proves control of the key by signing"
+          ],
+          "signer": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
         }
       ],
       "args": []
     },
     {
-      "name": "initialize",
+      "name": "allowMint",
       "discriminator": [
-        175,
-        175,
-        109,
-        31,
-        13,
-        152,
-        155,
-        237
+        240,
+        28,
+        240,
+        70,
+        124,
+        240,
+        245,
+        225
       ],
-      "accounts": [],
-      "args": []
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "deniedMint",
+          "docs": [
+            "Deny list entry being lifted, rent goes back to whoever paid it"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  110,
+                  105,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "moderator",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "Wallet that paid the rent of the entry"
+          ],
+          "writable": true,
+          "relations": [
+            "deniedMint"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenMint",
+          "type": "pubkey"
+        }
+      ]
     },
     {
-      "name": "placeLogo",
+      "name": "allowUser",
       "discriminator": [
-        117,
-        193,
-        212,
-        91,
+        154,
+        37,
+        46,
+        208,
         93,
-        151,
-        217,
-        151
+        118,
+        124,
+        3
       ],
       "accounts": [
         {
-          "name": "logoPlacement",
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "deniedUser",
           "docs": [
-            "Individual logo placement account (PDA from coordinates)"
+            "Deny list entry being lifted, rent goes back to whoever paid it"
           ],
-          "writable": true
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  110,
+                  105,
+                  101,
+                  100,
+                  95,
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "user"
+              }
+            ]
+          }
         },
         {
-          "name": "userCooldown",
+          "name": "moderator",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "payer",
           "docs": [
-            "User cooldown account (PDA from user address)"
+            "Wallet that paid the rent of the entry"
           ],
-          "writable": true
+          "writable": true,
+          "relations": [
+            "deniedUser"
+          ]
         },
         {
-          "name": "tokenMint",
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "user",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "clearRestrictedZone",
+      "discriminator": [
+        65,
+        51,
+        233,
+        205,
+        112,
+        3,
+        120,
+        97
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "restrictedZone",
           "docs": [
-            "The token mint account that must be a valid SPL token"
+            "Restricted zone being removed, rent goes to the admin"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  116,
+                  114,
+                  105,
+                  99,
+                  116,
+                  101,
+                  100,
+                  95,
+                  122,
+                  111,
+                  110,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tileLat"
+              },
+              {
+                "kind": "arg",
+                "path": "tileLng"
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
           ]
         },
         {
-          "name": "treasury",
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "tileLat",
+          "type": "i16"
+        },
+        {
+          "name": "tileLng",
+          "type": "i16"
+        }
+      ]
+    },
+    {
+      "name": "denyMint",
+      "discriminator": [
+        13,
+        143,
+        172,
+        192,
+        64,
+        100,
+        165,
+        43
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "deniedMint",
           "docs": [
-            "Treasury account that receives all fees"
+            "Deny list entry (PDA from token mint)"
           ],
-          "writable": true
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  110,
+                  105,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenMint"
+              }
+            ]
+          }
         },
         {
-          "name": "user",
+          "name": "moderator",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "payer",
           "docs": [
-            "User account that pays for the placement"
+            "Pays rent, separate from the moderator so PDA authorities holding data can sign"
           ],
           "writable": true,
           "signer": true
@@ -111,241 +527,3885 @@ export type SolplaceProgram = {
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
         }
       ],
       "args": [
         {
-          "name": "lat",
-          "type": "i32"
+          "name": "tokenMint",
+          "type": "pubkey"
         },
         {
-          "name": "lng",
-          "type": "i32"
+          "name": "reason",
+          "type": {
+            "defined": {
+              "name": "moderationReason"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "denyUser",
+      "discriminator": [
+        203,
+        184,
+        203,
+        106,
+        24,
+        165,
+        238,
+        77
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "tokenMint",
+          "name": "deniedUser",
+          "docs": [
+            "Deny list entry (PDA from user)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  110,
+                  105,
+                  101,
+                  100,
+                  95,
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "moderator",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "Pays rent, separate from the moderator so PDA authorities holding data can sign"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "user",
           "type": "pubkey"
         },
         {
-          "name": "logoUri",
-          "type": "string"
+          "name": "reason",
+          "type": {
+            "defined": {
+              "name": "moderationReason"
+            }
+          }
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "logoPlacement",
+      "name": "initialize",
       "discriminator": [
-        73,
-        115,
-        45,
-        46,
-        171,
-        45,
-        114,
-        126
-      ]
+        175,
+        175,
+        109,
+        31,
+        13,
+        152,
+        155,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "Program configuration account (PDA)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "docs": [
+            "Initial admin, must be the program upgrade authority"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "program",
+          "address": "Fw64bA7dMN1nzc1X82vsLydU63yHuBSNGHyozsZAqQBP"
+        },
+        {
+          "name": "programData"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "userCooldown",
+      "name": "migrateAccount",
       "discriminator": [
-        198,
-        114,
-        196,
-        140,
-        118,
-        165,
-        129,
-        190
-      ]
-    }
-  ],
-  "events": [
-    {
-      "name": "logoPlacedEvent",
+        177,
+        228,
+        60,
+        125,
+        13,
+        116,
+        44,
+        84
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "Program configuration (PDA), migrations wait like placements while paused"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "account",
+          "docs": [
+            "`LogoPlacement` or `UserCooldown` account still in the v0 layout",
+            "`place_logo` upgrades the accounts it touches itself, this upgrades any account in bulk"
+          ],
+          "writable": true
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "Anyone may migrate an account, the payer covers the extra rent"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "moderateLogo",
       "discriminator": [
-        98,
-        67,
-        200,
-        17,
-        51,
-        0,
-        21,
-        192
+        76,
+        31,
+        101,
+        251,
+        87,
+        43,
+        182,
+        74
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "logoPlacement",
+          "docs": [
+            "Placement being taken down"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  103,
+                  111,
+                  95,
+                  112,
+                  108,
+                  97,
+                  99,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "lat"
+              },
+              {
+                "kind": "arg",
+                "path": "lng"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mintProfile",
+          "docs": [
+            "Mint profile of the moderated token, required unless the placement is already blank"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "logoBitmap",
+          "docs": [
+            "Companion bitmap of the placement (PDA from coordinates), closed when it exists"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  103,
+                  111,
+                  95,
+                  98,
+                  105,
+                  116,
+                  109,
+                  97,
+                  112
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "lat"
+              },
+              {
+                "kind": "arg",
+                "path": "lng"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reportTally",
+          "docs": [
+            "Report counter of the placement, decides who gets the rent of closed accounts"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  111,
+                  114,
+                  116,
+                  95,
+                  116,
+                  97,
+                  108,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "logoPlacement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "placer",
+          "docs": [
+            "Wallet that made the placement, refunded the rent of closed accounts unless a report",
+            "against the placement was upheld"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury account that keeps the rent of closed accounts once a report was upheld"
+          ],
+          "writable": true,
+          "address": "3ojcMQjKYfME4qGmgE8Qb9odKcu4kSes1xhcVXD7DXCd"
+        },
+        {
+          "name": "moderator",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "lat",
+          "type": "i32"
+        },
+        {
+          "name": "lng",
+          "type": "i32"
+        },
+        {
+          "name": "reason",
+          "type": {
+            "defined": {
+              "name": "moderationReason"
+            }
+          }
+        },
+        {
+          "name": "action",
+          "type": {
+            "defined": {
+              "name": "moderationAction"
+            }
+          }
+        }
       ]
-    }
-  ],
-  "errors": [
+    },
     {
-      "code": 6000,
-      "name": "invalidLatitude",
-      "msg": "Invalid coordinates: latitude must be between -90° and +90°"
+      "name": "placeLogo",
+      "discriminator": [
+        117,
+        193,
+        212,
+        91,
+        93,
+        151,
+        217,
+        151
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "Program configuration (PDA), placements are refused while paused"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "logoPlacement",
+          "docs": [
+            "Individual logo placement account (PDA from coordinates), created on the first placement",
+            "Accounts still in the v0 layout are upgraded in place, the user pays the extra rent"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  103,
+                  111,
+                  95,
+                  112,
+                  108,
+                  97,
+                  99,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "lat"
+              },
+              {
+                "kind": "arg",
+                "path": "lng"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userCooldown",
+          "docs": [
+            "User cooldown account (PDA from user address), created on the user's first placement",
+            "Accounts still in the v0 layout are upgraded in place, the user pays the extra rent"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  111,
+                  108,
+                  100,
+                  111,
+                  119,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenMint",
+          "docs": [
+            "The token mint account that must be a valid SPL token"
+          ]
+        },
+        {
+          "name": "userTokenAccount",
+          "docs": [
+            "Optional user token account for `token_mint`, unlocks holder fee discounts"
+          ],
+          "optional": true
+        },
+        {
+          "name": "mintProfile",
+          "docs": [
+            "Mint profile of the placed token (PDA from token mint)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "previousMintProfile",
+          "docs": [
+            "Mint profile of the token being overwritten, required when it differs from `token_mint`"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "deniedMint",
+          "docs": [
+            "Deny list entry of the token mint, must be passed even when the mint is not blocked"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  110,
+                  105,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "deniedUser",
+          "docs": [
+            "Deny list entry of the user, must be passed even when the user is not blocked"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  110,
+                  105,
+                  101,
+                  100,
+                  95,
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "restrictedZone",
+          "docs": [
+            "Restricted zone of the target cell's tile, must be passed even when the tile has none"
+          ]
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury account that receives all fees"
+          ],
+          "writable": true,
+          "address": "3ojcMQjKYfME4qGmgE8Qb9odKcu4kSes1xhcVXD7DXCd"
+        },
+        {
+          "name": "user",
+          "docs": [
+            "User account that pays for the placement"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "lat",
+          "type": "i32"
+        },
+        {
+          "name": "lng",
+          "type": "i32"
+        },
+        {
+          "name": "bumps",
+          "type": {
+            "defined": {
+              "name": "placementBumps"
+            }
+          }
+        },
+        {
+          "name": "tokenMint",
+          "type": "pubkey"
+        },
+        {
+          "name": "logoUri",
+          "type": "string"
+        },
+        {
+          "name": "logoHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeAdmin",
+      "discriminator": [
+        121,
+        214,
+        199,
+        212,
+        87,
+        39,
+        117,
+        234
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "reportPlacement",
+      "discriminator": [
+        67,
+        6,
+        220,
+        214,
+        147,
+        201,
+        255,
+        189
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "Program configuration (PDA), reports are refused while paused"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "logoPlacement",
+          "docs": [
+            "Placement being reported"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  103,
+                  111,
+                  95,
+                  112,
+                  108,
+                  97,
+                  99,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "lat"
+              },
+              {
+                "kind": "arg",
+                "path": "lng"
+              }
+            ]
+          }
+        },
+        {
+          "name": "report",
+          "docs": [
+            "Report account (PDA from placement and reporter)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  111,
+                  114,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "logoPlacement"
+              },
+              {
+                "kind": "account",
+                "path": "reporter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reportTally",
+          "docs": [
+            "Report counter of the placement (PDA from placement)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  111,
+                  114,
+                  116,
+                  95,
+                  116,
+                  97,
+                  108,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "logoPlacement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reporter",
+          "docs": [
+            "User filing the report, pays rent and stake"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "lat",
+          "type": "i32"
+        },
+        {
+          "name": "lng",
+          "type": "i32"
+        },
+        {
+          "name": "reason",
+          "type": {
+            "defined": {
+              "name": "moderationReason"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "resolveReport",
+      "discriminator": [
+        78,
+        107,
+        3,
+        113,
+        222,
+        99,
+        178,
+        59
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "report",
+          "docs": [
+            "Report being resolved, closed to the reporter"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  111,
+                  114,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "report.logo_placement",
+                "account": "placementReport"
+              },
+              {
+                "kind": "account",
+                "path": "reporter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reportTally",
+          "docs": [
+            "Report counter of the reported placement"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  111,
+                  114,
+                  116,
+                  95,
+                  116,
+                  97,
+                  108,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "report.logo_placement",
+                "account": "placementReport"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reporter",
+          "docs": [
+            "User that filed the report, receives rent and refunded stake"
+          ],
+          "writable": true,
+          "relations": [
+            "report"
+          ]
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury account that receives slashed stakes"
+          ],
+          "writable": true,
+          "address": "3ojcMQjKYfME4qGmgE8Qb9odKcu4kSes1xhcVXD7DXCd"
+        },
+        {
+          "name": "moderator",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "resolution",
+          "type": {
+            "defined": {
+              "name": "reportResolution"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCanonicalLogo",
+      "discriminator": [
+        22,
+        151,
+        245,
+        185,
+        9,
+        90,
+        54,
+        133
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "Program configuration (PDA), changes are refused while paused"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mintProfile",
+          "docs": [
+            "Mint profile account (PDA from token mint)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenMint",
+          "docs": [
+            "The token mint whose canonical logo is being set"
+          ]
+        },
+        {
+          "name": "userTokenAccount",
+          "docs": [
+            "User token account for `token_mint`, required unless the user is the mint authority",
+            "A claim or challenge needs a nonzero balance"
+          ],
+          "optional": true
+        },
+        {
+          "name": "user",
+          "docs": [
+            "Mint authority, or any user claiming/challenging the canonical logo"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "logoUri",
+          "type": "string"
+        },
+        {
+          "name": "logoHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "setDomainAllowlist",
+      "discriminator": [
+        56,
+        122,
+        240,
+        119,
+        33,
+        17,
+        215,
+        132
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "allowedDomains",
+          "type": {
+            "vec": "string"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setModerator",
+      "discriminator": [
+        142,
+        48,
+        168,
+        55,
+        125,
+        196,
+        149,
+        56
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "moderator",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setPaused",
+      "discriminator": [
+        91,
+        60,
+        125,
+        192,
+        176,
+        225,
+        166,
+        218
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setRestrictedZone",
+      "discriminator": [
+        173,
+        33,
+        90,
+        161,
+        233,
+        217,
+        216,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "restrictedZone",
+          "docs": [
+            "Restricted zone of the tile (PDA from tile indices)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  116,
+                  114,
+                  105,
+                  99,
+                  116,
+                  101,
+                  100,
+                  95,
+                  122,
+                  111,
+                  110,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tileLat"
+              },
+              {
+                "kind": "arg",
+                "path": "tileLng"
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "Pays rent, separate from the admin so PDA authorities holding data can sign"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "tileLat",
+          "type": "i16"
+        },
+        {
+          "name": "tileLng",
+          "type": "i16"
+        },
+        {
+          "name": "shapes",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "zoneShape"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "writeLogoBitmap",
+      "discriminator": [
+        250,
+        130,
+        195,
+        81,
+        159,
+        217,
+        175,
+        205
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "Program configuration (PDA), uploads are refused while paused"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "logoPlacement",
+          "docs": [
+            "Logo placement the bitmap belongs to"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  103,
+                  111,
+                  95,
+                  112,
+                  108,
+                  97,
+                  99,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "lat"
+              },
+              {
+                "kind": "arg",
+                "path": "lng"
+              }
+            ]
+          }
+        },
+        {
+          "name": "logoBitmap",
+          "docs": [
+            "Companion bitmap account (PDA from coordinates)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  103,
+                  111,
+                  95,
+                  98,
+                  105,
+                  116,
+                  109,
+                  97,
+                  112
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "lat"
+              },
+              {
+                "kind": "arg",
+                "path": "lng"
+              }
+            ]
+          }
+        },
+        {
+          "name": "user",
+          "docs": [
+            "Current placer of the logo, pays for the bitmap account"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "lat",
+          "type": "i32"
+        },
+        {
+          "name": "lng",
+          "type": "i32"
+        },
+        {
+          "name": "totalLen",
+          "type": "u16"
+        },
+        {
+          "name": "offset",
+          "type": "u16"
+        },
+        {
+          "name": "chunk",
+          "type": "bytes"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "denyListEntry",
+      "discriminator": [
+        157,
+        113,
+        104,
+        28,
+        47,
+        236,
+        70,
+        220
+      ]
+    },
+    {
+      "name": "logoBitmap",
+      "discriminator": [
+        201,
+        49,
+        0,
+        14,
+        173,
+        251,
+        68,
+        83
+      ]
+    },
+    {
+      "name": "logoPlacement",
+      "discriminator": [
+        151,
+        161,
+        85,
+        172,
+        29,
+        86,
+        7,
+        224
+      ]
+    },
+    {
+      "name": "mintProfile",
+      "discriminator": [
+        120,
+        236,
+        132,
+        66,
+        110,
+        65,
+        46,
+        161
+      ]
+    },
+    {
+      "name": "placementReport",
+      "discriminator": [
+        84,
+        165,
+        194,
+        162,
+        121,
+        239,
+        108,
+        139
+      ]
+    },
+    {
+      "name": "programConfig",
+      "discriminator": [
+        196,
+        210,
+        90,
+        231,
+        144,
+        149,
+        140,
+        63
+      ]
+    },
+    {
+      "name": "reportTally",
+      "discriminator": [
+        208,
+        72,
+        95,
+        100,
+        219,
+        15,
+        181,
+        248
+      ]
+    },
+    {
+      "name": "restrictedZone",
+      "discriminator": [
+        113,
+        49,
+        19,
+        121,
+        39,
+        27,
+        248,
+        4
+      ]
+    },
+    {
+      "name": "userCooldown",
+      "discriminator": [
+        61,
+        93,
+        53,
+        54,
+        160,
+        242,
+        44,
+        74
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "accountMigratedEvent",
+      "discriminator": [
+        109,
+        3,
+        25,
+        119,
+        155,
+        108,
+        69,
+        61
+      ]
+    },
+    {
+      "name": "adminProposedEvent",
+      "discriminator": [
+        212,
+        163,
+        91,
+        28,
+        223,
+        95,
+        2,
+        102
+      ]
+    },
+    {
+      "name": "adminTransferredEvent",
+      "discriminator": [
+        158,
+        233,
+        64,
+        41,
+        184,
+        122,
+        98,
+        76
+      ]
+    },
+    {
+      "name": "canonicalLogoSetEvent",
+      "discriminator": [
+        130,
+        67,
+        11,
+        63,
+        137,
+        211,
+        189,
+        111
+      ]
+    },
+    {
+      "name": "cooldownUpdatedEvent",
+      "discriminator": [
+        164,
+        196,
+        157,
+        236,
+        240,
+        46,
+        206,
+        62
+      ]
+    },
+    {
+      "name": "feeCollectedEvent",
+      "discriminator": [
+        142,
+        253,
+        94,
+        133,
+        187,
+        191,
+        46,
+        40
+      ]
+    },
+    {
+      "name": "logoBitmapWrittenEvent",
+      "discriminator": [
+        122,
+        46,
+        196,
+        150,
+        140,
+        194,
+        106,
+        67
+      ]
+    },
+    {
+      "name": "logoModeratedEvent",
+      "discriminator": [
+        45,
+        254,
+        54,
+        222,
+        179,
+        1,
+        6,
+        143
+      ]
+    },
+    {
+      "name": "logoPlacedEvent",
+      "discriminator": [
+        98,
+        67,
+        200,
+        17,
+        51,
+        0,
+        21,
+        192
+      ]
+    },
+    {
+      "name": "mintAllowedEvent",
+      "discriminator": [
+        140,
+        149,
+        49,
+        29,
+        206,
+        37,
+        4,
+        37
+      ]
+    },
+    {
+      "name": "mintDeniedEvent",
+      "discriminator": [
+        227,
+        112,
+        250,
+        37,
+        111,
+        222,
+        13,
+        172
+      ]
+    },
+    {
+      "name": "placementHiddenEvent",
+      "discriminator": [
+        177,
+        235,
+        136,
+        50,
+        112,
+        6,
+        75,
+        25
+      ]
+    },
+    {
+      "name": "placementReportedEvent",
+      "discriminator": [
+        38,
+        172,
+        51,
+        140,
+        17,
+        146,
+        172,
+        116
+      ]
+    },
+    {
+      "name": "placementUnhiddenEvent",
+      "discriminator": [
+        238,
+        142,
+        247,
+        136,
+        211,
+        45,
+        114,
+        181
+      ]
+    },
+    {
+      "name": "programPausedEvent",
+      "discriminator": [
+        184,
+        151,
+        142,
+        204,
+        81,
+        195,
+        210,
+        30
+      ]
+    },
+    {
+      "name": "reportResolvedEvent",
+      "discriminator": [
+        97,
+        209,
+        162,
+        54,
+        119,
+        220,
+        49,
+        103
+      ]
+    },
+    {
+      "name": "restrictedZoneClearedEvent",
+      "discriminator": [
+        67,
+        141,
+        188,
+        71,
+        201,
+        73,
+        236,
+        164
+      ]
+    },
+    {
+      "name": "restrictedZoneSetEvent",
+      "discriminator": [
+        109,
+        242,
+        131,
+        212,
+        99,
+        230,
+        67,
+        147
+      ]
+    },
+    {
+      "name": "userAllowedEvent",
+      "discriminator": [
+        12,
+        34,
+        77,
+        229,
+        70,
+        13,
+        72,
+        80
+      ]
+    },
+    {
+      "name": "userDeniedEvent",
+      "discriminator": [
+        135,
+        12,
+        131,
+        253,
+        65,
+        99,
+        97,
+        11
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "invalidLatitude",
+      "msg": "Invalid coordinates: latitude must be between -90° and +90°"
+    },
+    {
+      "code": 6001,
+      "name": "invalidLongitude",
+      "msg": "Invalid coordinates: longitude must be between -180° and +180°"
+    },
+    {
+      "code": 6002,
+      "name": "invalidCoordinates",
+      "msg": "Invalid coordinates provided"
+    },
+    {
+      "code": 6003,
+      "name": "invalidTokenMint",
+      "msg": "Invalid token mint: account does not exist or is not a valid SPL token"
+    },
+    {
+      "code": 6004,
+      "name": "uninitializedMint",
+      "msg": "Token mint is not initialized"
+    },
+    {
+      "code": 6005,
+      "name": "userOnCooldown",
+      "msg": "User is still on cooldown. Please wait before placing another logo"
+    },
+    {
+      "code": 6006,
+      "name": "logoUriTooLong",
+      "msg": "Logo URI is too long. Maximum 200 characters allowed"
+    },
+    {
+      "code": 6007,
+      "name": "insufficientFunds",
+      "msg": "Insufficient funds to pay placement fee"
+    },
+    {
+      "code": 6008,
+      "name": "invalidTreasury",
+      "msg": "Invalid treasury account"
+    },
+    {
+      "code": 6009,
+      "name": "invalidLogoPlacement",
+      "msg": "Invalid logo placement PDA"
+    },
+    {
+      "code": 6010,
+      "name": "invalidCooldown",
+      "msg": "Invalid cooldown PDA"
+    },
+    {
+      "code": 6011,
+      "name": "invalidAccount",
+      "msg": "Invalid account: discriminator mismatch"
+    },
+    {
+      "code": 6012,
+      "name": "invalidHolderTokenAccount",
+      "msg": "Holder token account must belong to the user and match the token mint"
+    },
+    {
+      "code": 6013,
+      "name": "emptyLogoUri",
+      "msg": "Logo URI must not be empty"
+    },
+    {
+      "code": 6014,
+      "name": "canonicalLogoLocked",
+      "msg": "Canonical logo can only be replaced by the mint authority or a larger holder"
+    },
+    {
+      "code": 6015,
+      "name": "invalidMintProfile",
+      "msg": "Invalid mint profile account"
+    },
+    {
+      "code": 6016,
+      "name": "missingPreviousMintProfile",
+      "msg": "Previous mint profile is required when overwriting another token"
+    },
+    {
+      "code": 6017,
+      "name": "missingLogoHash",
+      "msg": "Logo hash is required: SHA-256 of the logo image bytes"
+    },
+    {
+      "code": 6018,
+      "name": "notLogoPlacer",
+      "msg": "Only the current placer of the logo can do this"
+    },
+    {
+      "code": 6019,
+      "name": "invalidLogoBitmap",
+      "msg": "Invalid logo bitmap: unsupported version, size, encoding or palette"
+    },
+    {
+      "code": 6020,
+      "name": "logoBitmapTooLarge",
+      "msg": "Logo bitmap exceeds the maximum payload size"
+    },
+    {
+      "code": 6021,
+      "name": "invalidLogoBitmapChunk",
+      "msg": "Logo bitmap chunk is out of order or belongs to another upload"
+    },
+    {
+      "code": 6022,
+      "name": "unauthorized",
+      "msg": "Signer is not authorized to perform this action"
+    },
+    {
+      "code": 6023,
+      "name": "unsupportedUriScheme",
+      "msg": "Logo URI must use the https, ipfs or ar scheme"
+    },
+    {
+      "code": 6024,
+      "name": "invalidUriCharacters",
+      "msg": "Logo URI contains control characters or backslashes"
+    },
+    {
+      "code": 6025,
+      "name": "invalidUriHost",
+      "msg": "Logo URI host is missing or invalid"
+    },
+    {
+      "code": 6026,
+      "name": "uriDomainNotAllowed",
+      "msg": "Logo URI host is not in the domain allowlist"
+    },
+    {
+      "code": 6027,
+      "name": "invalidDomain",
+      "msg": "Invalid allowlist domain: use a lowercase hostname of at most 64 characters"
+    },
+    {
+      "code": 6028,
+      "name": "tooManyDomains",
+      "msg": "Too many allowlist domains"
+    },
+    {
+      "code": 6029,
+      "name": "invalidPlacer",
+      "msg": "Placer account does not match the logo placement"
+    },
+    {
+      "code": 6030,
+      "name": "placementAlreadyBlank",
+      "msg": "Placement is already blank"
+    },
+    {
+      "code": 6031,
+      "name": "cannotReportOwnPlacement",
+      "msg": "You cannot report your own placement"
+    },
+    {
+      "code": 6032,
+      "name": "invalidReporter",
+      "msg": "Reporter account does not match the report"
+    },
+    {
+      "code": 6033,
+      "name": "mintDenied",
+      "msg": "This token mint is blocked from placements"
+    },
+    {
+      "code": 6034,
+      "name": "userDenied",
+      "msg": "This wallet is blocked from placements"
+    },
+    {
+      "code": 6035,
+      "name": "placementRestricted",
+      "msg": "These coordinates are inside a restricted zone"
+    },
+    {
+      "code": 6036,
+      "name": "invalidRestrictedZone",
+      "msg": "Invalid restricted zone account"
+    },
+    {
+      "code": 6037,
+      "name": "invalidZoneTile",
+      "msg": "Zone tile is out of range"
+    },
+    {
+      "code": 6038,
+      "name": "invalidZoneShape",
+      "msg": "Invalid restricted zone shape"
+    },
+    {
+      "code": 6039,
+      "name": "zoneOutsideTile",
+      "msg": "Restricted zone shape does not overlap its tile"
+    },
+    {
+      "code": 6040,
+      "name": "tooManyZoneShapes",
+      "msg": "Too many shapes in restricted zone tile"
+    },
+    {
+      "code": 6041,
+      "name": "programPaused",
+      "msg": "Program is paused"
+    },
+    {
+      "code": 6042,
+      "name": "accountAlreadyMigrated",
+      "msg": "Account already uses the current layout"
+    },
+    {
+      "code": 6043,
+      "name": "invalidRentPayer",
+      "msg": "Rent payer does not match the deny list entry"
+    },
+    {
+      "code": 6044,
+      "name": "canonicalClaimWithoutBalance",
+      "msg": "Claiming a canonical logo requires holding the token"
+    },
+    {
+      "code": 6045,
+      "name": "canonicalLogoMismatch",
+      "msg": "Logo must match the token's canonical logo"
+    },
+    {
+      "code": 6046,
+      "name": "nonCanonicalBump",
+      "msg": "Bump is not the canonical bump of the account address"
+    }
+  ],
+  "types": [
+    {
+      "name": "accountMigratedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "account",
+            "type": "pubkey"
+          },
+          {
+            "name": "fromVersion",
+            "type": "u8"
+          },
+          {
+            "name": "toVersion",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminProposedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminTransferredEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "canonicalLogoSetEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "logoUri",
+            "type": "string"
+          },
+          {
+            "name": "logoHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "setBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "isMintAuthority",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "cooldownUpdatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "lastPlacement",
+            "type": "i64"
+          },
+          {
+            "name": "placementCount",
+            "docs": [
+              "Placements by the user, this one included"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "cooldownEndsAt",
+            "docs": [
+              "First timestamp the user can place again"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "denyListEntry",
+      "docs": [
+        "An address blocked from placing logos, a token mint (PDA from `DENIED_MINT_SEED` and the mint)",
+        "or a wallet (PDA from `DENIED_USER_SEED` and the wallet)",
+        "The account existing is what blocks the address, closing it lifts the block"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "docs": [
+              "Blocked token mint or user wallet address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "docs": [
+              "Why the address was blocked"
+            ],
+            "type": {
+              "defined": {
+                "name": "moderationReason"
+              }
+            }
+          },
+          {
+            "name": "deniedBy",
+            "docs": [
+              "Moderator that blocked the address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "deniedAt",
+            "docs": [
+              "Unix timestamp when blocked"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent, refunded when the block is lifted"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeCollectedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "cell",
+            "docs": [
+              "Logo placement PDA of the cell"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "baseFee",
+            "docs": [
+              "Fee before the holder discount"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "holderDiscount",
+            "docs": [
+              "Lamports taken off `base_fee` by the holder discount"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "holderDiscountBps",
+            "type": "u16"
+          },
+          {
+            "name": "feePaid",
+            "docs": [
+              "Lamports transferred to the treasury"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "isOverwrite",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "logoBitmap",
+      "docs": [
+        "Compact logo image stored next to a `LogoPlacement` (PDA from coordinates)",
+        "Lets the map be rendered from chain state alone when the logo URI stops resolving"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "coordinates",
+            "docs": [
+              "Coordinates in microdegrees [lat, lng]"
+            ],
+            "type": {
+              "array": [
+                "i32",
+                2
+              ]
+            }
+          },
+          {
+            "name": "placedAt",
+            "docs": [
+              "`placed_at` of the placement the bitmap was uploaded for"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "uploader",
+            "docs": [
+              "User wallet address that uploaded the bitmap"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "totalLen",
+            "docs": [
+              "Expected payload length in bytes"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "data",
+            "docs": [
+              "Encoded payload: header, RGBA palette and pixel data (max 581 bytes)"
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "logoBitmapWrittenEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lat",
+            "type": "i32"
+          },
+          {
+            "name": "lng",
+            "type": "i32"
+          },
+          {
+            "name": "uploader",
+            "type": "pubkey"
+          },
+          {
+            "name": "placedAt",
+            "type": "i64"
+          },
+          {
+            "name": "size",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "logoModeratedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lat",
+            "type": "i32"
+          },
+          {
+            "name": "lng",
+            "type": "i32"
+          },
+          {
+            "name": "tokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "placedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "placedAt",
+            "docs": [
+              "`placed_at` of the taken down placement"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "moderator",
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "type": {
+              "defined": {
+                "name": "moderationReason"
+              }
+            }
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "moderationAction"
+              }
+            }
+          },
+          {
+            "name": "reclaimedLamports",
+            "docs": [
+              "Rent of the closed placement and bitmap accounts"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rentTo",
+            "docs": [
+              "Placer refunded `reclaimed_lamports`, or the treasury once a report was upheld"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "logoPlacedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "cell",
+            "docs": [
+              "Logo placement PDA of the cell"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "lat",
+            "type": "i32"
+          },
+          {
+            "name": "lng",
+            "type": "i32"
+          },
+          {
+            "name": "tokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "logoUri",
+            "type": "string"
+          },
+          {
+            "name": "logoHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "previousTokenMint",
+            "docs": [
+              "Token shown before this placement, `None` for empty and moderated cells"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "previousPlacedBy",
+            "docs": [
+              "Wallet that placed `previous_token_mint`"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "previousPlacedAt",
+            "docs": [
+              "When `previous_token_mint` was placed"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "overwriteCount",
+            "docs": [
+              "Times the cell has been overwritten, this placement included"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "baseFee",
+            "docs": [
+              "Fee before the holder discount"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "holderDiscount",
+            "docs": [
+              "Lamports taken off `base_fee` by the holder discount"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "holderDiscountBps",
+            "type": "u16"
+          },
+          {
+            "name": "feePaid",
+            "docs": [
+              "Fee transferred to the treasury, `base_fee - holder_discount`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rentPaid",
+            "docs": [
+              "Rent deposited into the accounts the placement created"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "isOverwrite",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "logoPlacement",
+      "docs": [
+        "Individual logo placement account",
+        "Each logo gets its own account, costs ~0.2-0.3 SOL rent",
+        "The discriminator is sha256(\"account:LogoPlacementV1\")[..8] so v0 accounts never decode as v1"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Layout version, see `LogoPlacement::VERSION`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "coordinates",
+            "docs": [
+              "Coordinates in microdegrees [lat, lng]"
+            ],
+            "type": {
+              "array": [
+                "i32",
+                2
+              ]
+            }
+          },
+          {
+            "name": "tokenMint",
+            "docs": [
+              "Token contract address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "logoUri",
+            "docs": [
+              "Resolved logo URL (max 200 chars)"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "logoHash",
+            "docs": [
+              "SHA-256 of the logo image bytes, supplied by the placer for integrity verification"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "placedBy",
+            "docs": [
+              "User wallet address that placed this token"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "placedAt",
+            "docs": [
+              "Unix timestamp when placed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "overwriteCount",
+            "docs": [
+              "Number of times this position has been overwritten"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintAllowedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "moderator",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintDeniedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "type": {
+              "defined": {
+                "name": "moderationReason"
+              }
+            }
+          },
+          {
+            "name": "moderator",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintProfile",
+      "docs": [
+        "Per-mint registry account (PDA from token mint)",
+        "Holds the canonical logo used by every placement of the token and aggregate statistics"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenMint",
+            "docs": [
+              "Token contract address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "canonicalUri",
+            "docs": [
+              "Canonical logo URL (max 200 chars, empty until claimed)"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "canonicalHash",
+            "docs": [
+              "SHA-256 of the canonical logo image bytes"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "canonicalSetBy",
+            "docs": [
+              "Wallet that set the canonical logo"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "canonicalClaimBalance",
+            "docs": [
+              "Token balance the claimant proved when setting the canonical logo"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "canonicalLocked",
+            "docs": [
+              "True once the mint authority set the logo, which ends any challenge"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "canonicalSetAt",
+            "docs": [
+              "Unix timestamp when the canonical logo was last set"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "cellsHeld",
+            "docs": [
+              "Number of cells currently showing this token"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "placementCount",
+            "docs": [
+              "Total number of placements of this token"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesPaid",
+            "docs": [
+              "Total fees paid in lamports for placements of this token"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "invalidLongitude",
-      "msg": "Invalid coordinates: longitude must be between -180° and +180°"
+      "name": "moderationAction",
+      "docs": [
+        "What happens to a moderated placement"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "blank"
+          },
+          {
+            "name": "close"
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "invalidCoordinates",
-      "msg": "Invalid coordinates provided"
+      "name": "moderationReason",
+      "docs": [
+        "Why a placement was taken down"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "spam"
+          },
+          {
+            "name": "offensive"
+          },
+          {
+            "name": "impersonation"
+          },
+          {
+            "name": "scam"
+          },
+          {
+            "name": "illegal"
+          },
+          {
+            "name": "other"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "invalidTokenMint",
-      "msg": "Invalid token mint: account does not exist or is not a valid SPL token"
+      "name": "placementBumps",
+      "docs": [
+        "Bumps of the PDAs `place_logo` takes, found once by the client so the program checks each",
+        "address with `create_program_address` instead of searching for the bump",
+        "The mint profile is created by `init_if_needed`, which finds its canonical bump itself"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "logoPlacement",
+            "type": "u8"
+          },
+          {
+            "name": "userCooldown",
+            "type": "u8"
+          },
+          {
+            "name": "deniedMint",
+            "type": "u8"
+          },
+          {
+            "name": "deniedUser",
+            "type": "u8"
+          },
+          {
+            "name": "restrictedZone",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "uninitializedMint",
-      "msg": "Token mint is not initialized"
+      "name": "placementHiddenEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lat",
+            "type": "i32"
+          },
+          {
+            "name": "lng",
+            "type": "i32"
+          },
+          {
+            "name": "tokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "placedAt",
+            "docs": [
+              "`placed_at` of the hidden placement"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "reportCount",
+            "type": "u16"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "userOnCooldown",
-      "msg": "User is still on cooldown. Please wait before placing another logo"
+      "name": "placementReport",
+      "docs": [
+        "A user report against a placement (PDA from placement and reporter)",
+        "Holds the reporter's stake until a moderator resolves it"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "logoPlacement",
+            "docs": [
+              "Reported logo placement account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "reporter",
+            "docs": [
+              "User wallet address that filed the report"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "placedAt",
+            "docs": [
+              "`placed_at` of the reported placement"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "reason",
+            "docs": [
+              "Why the placement was reported"
+            ],
+            "type": {
+              "defined": {
+                "name": "moderationReason"
+              }
+            }
+          },
+          {
+            "name": "stake",
+            "docs": [
+              "Lamports staked on top of rent"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Unix timestamp when reported"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "logoUriTooLong",
-      "msg": "Logo URI is too long. Maximum 200 characters allowed"
+      "name": "placementReportedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lat",
+            "type": "i32"
+          },
+          {
+            "name": "lng",
+            "type": "i32"
+          },
+          {
+            "name": "reporter",
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "type": {
+              "defined": {
+                "name": "moderationReason"
+              }
+            }
+          },
+          {
+            "name": "reportCount",
+            "type": "u16"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "insufficientFunds",
-      "msg": "Insufficient funds to pay placement fee"
+      "name": "placementUnhiddenEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "logoPlacement",
+            "type": "pubkey"
+          },
+          {
+            "name": "placedAt",
+            "docs": [
+              "`placed_at` of the placement shown again"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "reportCount",
+            "type": "u16"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "invalidTreasury",
-      "msg": "Invalid treasury account"
+      "name": "programConfig",
+      "docs": [
+        "Global program configuration (PDA, single instance)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "Wallet allowed to change the configuration"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "docs": [
+              "Proposed next admin, becomes admin once it accepts"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "moderator",
+            "docs": [
+              "Wallet allowed to take down placements"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "allowedDomains",
+            "docs": [
+              "Hosts allowed in https logo URIs, empty allows any host"
+            ],
+            "type": {
+              "vec": "string"
+            }
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Emergency switch, blocks every instruction that creates or changes user content"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "invalidLogoPlacement",
-      "msg": "Invalid logo placement PDA"
+      "name": "programPausedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "invalidCooldown",
-      "msg": "Invalid cooldown PDA"
+      "name": "reportResolution",
+      "docs": [
+        "Moderator verdict on a report"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "upheld"
+          },
+          {
+            "name": "rejected"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "invalidAccount",
-      "msg": "Invalid account: discriminator mismatch"
-    }
-  ],
-  "types": [
-    {
-      "name": "logoPlacedEvent",
+      "name": "reportResolvedEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "user",
+            "name": "logoPlacement",
             "type": "pubkey"
           },
           {
-            "name": "lat",
-            "type": "i32"
+            "name": "reporter",
+            "type": "pubkey"
           },
           {
-            "name": "lng",
-            "type": "i32"
+            "name": "moderator",
+            "type": "pubkey"
           },
           {
-            "name": "tokenMint",
+            "name": "resolution",
+            "type": {
+              "defined": {
+                "name": "reportResolution"
+              }
+            }
+          },
+          {
+            "name": "stake",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "reportTally",
+      "docs": [
+        "Aggregate report counter of a placement (PDA from placement)",
+        "Resets whenever the cell gets a new placement"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "logoPlacement",
+            "docs": [
+              "Logo placement account the reports are about"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "logoUri",
-            "type": "string"
+            "name": "placedAt",
+            "docs": [
+              "`placed_at` of the placement being counted"
+            ],
+            "type": "i64"
           },
           {
-            "name": "feePaid",
-            "type": "u64"
+            "name": "reportCount",
+            "docs": [
+              "Open reports against the current placement"
+            ],
+            "type": "u16"
           },
           {
-            "name": "isOverwrite",
+            "name": "hidden",
+            "docs": [
+              "Set once `report_count` reaches the hide threshold, the indexer and API leave the logo out"
+            ],
             "type": "bool"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
+            "name": "upheldCount",
+            "docs": [
+              "Reports against the current placement a moderator upheld, a takedown then keeps the rent"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "logoPlacement",
+      "name": "restrictedZone",
       "docs": [
-        "Individual logo placement account",
-        "Each logo gets its own account, costs ~0.2-0.3 SOL rent"
+        "No-placement areas of one zone tile (PDA from tile indices)",
+        "A zone crossing tile borders is stored in every tile it touches"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "coordinates",
+            "name": "tile",
             "docs": [
-              "Coordinates in microdegrees [lat, lng]"
+              "Tile indices [tile_lat, tile_lng], see `geo::tile_of`"
             ],
             "type": {
               "array": [
-                "i32",
+                "i16",
                 2
               ]
             }
           },
           {
-            "name": "tokenMint",
+            "name": "shapes",
             "docs": [
-              "Token contract address"
+              "Restricted shapes overlapping the tile"
             ],
-            "type": "pubkey"
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "zoneShape"
+                }
+              }
+            }
           },
           {
-            "name": "logoUri",
+            "name": "bump",
             "docs": [
-              "Resolved logo URL (max 200 chars)"
+              "PDA bump seed"
             ],
-            "type": "string"
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "restrictedZoneClearedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tileLat",
+            "type": "i16"
           },
           {
-            "name": "logoHash",
+            "name": "tileLng",
+            "type": "i16"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "restrictedZoneSetEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tileLat",
+            "type": "i16"
+          },
+          {
+            "name": "tileLng",
+            "type": "i16"
+          },
+          {
+            "name": "shapeCount",
+            "type": "u8"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userAllowedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "moderator",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userCooldown",
+      "docs": [
+        "Placement cooldown of one wallet",
+        "The discriminator is sha256(\"account:UserCooldownV1\")[..8] so v0 accounts never decode as v1"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
             "docs": [
-              "Content hash for integrity verification"
+              "Layout version, see `UserCooldown::VERSION`"
             ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "type": "u8"
           },
           {
-            "name": "placedBy",
+            "name": "user",
             "docs": [
-              "User wallet address that placed this token"
+              "User wallet address"
             ],
             "type": "pubkey"
           },
           {
-            "name": "placedAt",
+            "name": "lastPlacement",
             "docs": [
-              "Unix timestamp when placed"
+              "Unix timestamp of last placement"
             ],
             "type": "i64"
           },
           {
-            "name": "overwriteCount",
+            "name": "placementCount",
             "docs": [
-              "Number of times this position has been overwritten"
+              "Total number of placements by user"
             ],
-            "type": "u16"
+            "type": "u32"
           },
           {
             "name": "bump",
@@ -358,7 +4418,7 @@ export type SolplaceProgram = {
       }
     },
     {
-      "name": "userCooldown",
+      "name": "userDeniedEvent",
       "type": {
         "kind": "struct",
         "fields": [
@@ -367,16 +4427,70 @@ export type SolplaceProgram = {
             "type": "pubkey"
           },
           {
-            "name": "lastPlacement",
-            "type": "i64"
+            "name": "reason",
+            "type": {
+              "defined": {
+                "name": "moderationReason"
+              }
+            }
           },
           {
-            "name": "placementCount",
-            "type": "u32"
+            "name": "moderator",
+            "type": "pubkey"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "zoneShape",
+      "docs": [
+        "A restricted area in microdegrees, edges included"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "boundingBox",
+            "fields": [
+              {
+                "name": "min",
+                "type": {
+                  "array": [
+                    "i32",
+                    2
+                  ]
+                }
+              },
+              {
+                "name": "max",
+                "type": {
+                  "array": [
+                    "i32",
+                    2
+                  ]
+                }
+              }
+            ]
+          },
+          {
+            "name": "polygon",
+            "fields": [
+              {
+                "name": "vertices",
+                "type": {
+                  "vec": {
+                    "array": [
+                      "i32",
+                      2
+                    ]
+                  }
+                }
+              }
+            ]
           }
         ]
       }
@@ -132,20 +132,45 @@ export interface LogoSource {
 
 /**
  * Event emitted when a logo is placed
+ * Mirrors the Rust LogoPlacedEvent, read from the self-CPI instruction data
  */
 export interface LogoPlacedEvent {
-	/** Cell coordinates in microdegrees */
-	coordinates: [number, number]
+	/** User who placed the logo */
+	user: string
+	/** Logo placement account of the cell */
+	cell: string
+	/** Latitude in microdegrees */
+	lat: number
+	/** Longitude in microdegrees */
+	lng: number
 	/** Token mint address */
 	tokenMint: string
-	/** User who placed the logo */
-	placedBy: string
-	/** Unix timestamp */
-	timestamp: number
-	/** Whether this was an overwrite */
-	wasOverwrite: boolean
+	/** Logo URL as stored on-chain */
+	logoUri: string
+	/** SHA-256 of the logo image bytes */
+	logoHash: Uint8Array
+	/** Token shown before an overwrite */
+	previousTokenMint: string | null
+	/** User who placed the overwritten logo */
+	previousPlacedBy: string | null
+	/** Unix timestamp of the overwritten placement */
+	previousPlacedAt: number | null
+	/** Number of times the cell has been overwritten */
+	overwriteCount: number
+	/** Fee before the holder discount, in lamports */
+	baseFee: number
+	/** Holder discount in lamports */
+	holderDiscount: number
+	/** Holder discount in basis points */
+	holderDiscountBps: number
 	/** Fee paid in lamports */
 	feePaid: number
+	/** Rent paid for accounts created by the placement, in lamports */
+	rentPaid: number
+	/** Whether this was an overwrite */
+	isOverwrite: boolean
+	/** Unix timestamp */
+	timestamp: number
 }
 
 /**
@@ -158,6 +183,18 @@ export interface PDAResult {
 	bump: number
 }
 
+/**
+ * Bumps of the PDAs place_logo takes, except the mint profile
+ * Mirrors the Rust PlacementBumps struct
+ */
+export interface PlacementBumps {
+	logoPlacement: number
+	userCooldown: number
+	deniedMint: number
+	deniedUser: number
+	restrictedZone: number
+}
+
 /**
  * Logo loading result for individual logos
  */
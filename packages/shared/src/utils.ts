@@ -1,21 +1,29 @@
 import { PublicKey } from "@solana/web3.js"
 import { Buffer } from "buffer"
 import {
+	ARWEAVE_GATEWAY,
 	BASE_PLACEMENT_FEE,
 	CLUSTER_RESOLUTION,
 	CLUSTER_SEED,
 	COOLDOWN_SEED,
+	DENIED_MINT_SEED,
+	DENIED_USER_SEED,
+	IPFS_GATEWAY,
 	LOGO_PLACEMENT_SEED,
 	MAX_LATITUDE,
 	MAX_LONGITUDE,
 	MIN_LATITUDE,
 	MIN_LONGITUDE,
-	OVERWRITE_MULTIPLIER
+	MINT_PROFILE_SEED,
+	OVERWRITE_MULTIPLIER,
+	RESTRICTED_ZONE_SEED,
+	ZONE_TILE_SIZE
 } from "./constants"
 import type {
 	Coordinates,
 	LogoPlacement,
 	PDAResult,
+	PlacementBumps,
 	PlacementFee
 } from "./types"
 
@@ -112,6 +120,112 @@ export function getUserCooldownPDA(
 	return { publicKey, bump }
 }
 
+/**
+ * Derive mint profile PDA
+ */
+export function getMintProfilePDA(
+	tokenMint: PublicKey,
+	programId: PublicKey
+): PDAResult {
+	const [publicKey, bump] = PublicKey.findProgramAddressSync(
+		[Buffer.from(MINT_PROFILE_SEED), tokenMint.toBuffer()],
+		programId
+	)
+
+	return { publicKey, bump }
+}
+
+/**
+ * Derive restricted zone PDA of the tile containing the coordinates
+ */
+export function getRestrictedZonePDA(
+	lat: number,
+	lng: number,
+	programId: PublicKey
+): PDAResult {
+	const [latMicro, lngMicro] = coordinatesToMicrodegrees(lat, lng)
+
+	const tileLatBuffer = Buffer.alloc(2)
+	const tileLngBuffer = Buffer.alloc(2)
+	tileLatBuffer.writeInt16LE(Math.floor(latMicro / ZONE_TILE_SIZE), 0)
+	tileLngBuffer.writeInt16LE(Math.floor(lngMicro / ZONE_TILE_SIZE), 0)
+
+	const [publicKey, bump] = PublicKey.findProgramAddressSync(
+		[Buffer.from(RESTRICTED_ZONE_SEED), tileLatBuffer, tileLngBuffer],
+		programId
+	)
+
+	return { publicKey, bump }
+}
+
+/**
+ * Derive deny list entry PDA of a token mint
+ */
+export function getDeniedMintPDA(
+	tokenMint: PublicKey,
+	programId: PublicKey
+): PDAResult {
+	const [publicKey, bump] = PublicKey.findProgramAddressSync(
+		[Buffer.from(DENIED_MINT_SEED), tokenMint.toBuffer()],
+		programId
+	)
+
+	return { publicKey, bump }
+}
+
+/**
+ * Derive deny list entry PDA of a user
+ */
+export function getDeniedUserPDA(
+	userPublicKey: PublicKey,
+	programId: PublicKey
+): PDAResult {
+	const [publicKey, bump] = PublicKey.findProgramAddressSync(
+		[Buffer.from(DENIED_USER_SEED), userPublicKey.toBuffer()],
+		programId
+	)
+
+	return { publicKey, bump }
+}
+
+/**
+ * Canonical bumps of the PDAs a placement takes, passed to place_logo
+ * so the program does not have to search for them
+ */
+export function getPlacementBumps(
+	lat: number,
+	lng: number,
+	tokenMint: PublicKey,
+	userPublicKey: PublicKey,
+	programId: PublicKey
+): PlacementBumps {
+	return {
+		logoPlacement: getLogoPlacementPDA(lat, lng, programId).bump,
+		userCooldown: getUserCooldownPDA(userPublicKey, programId).bump,
+		deniedMint: getDeniedMintPDA(tokenMint, programId).bump,
+		deniedUser: getDeniedUserPDA(userPublicKey, programId).bump,
+		restrictedZone: getRestrictedZonePDA(lat, lng, programId).bump
+	}
+}
+
+/**
+ * Discriminators of the v0 logo placement and user cooldown layouts
+ * place_logo upgrades the accounts it touches, migrate_account upgrades any of them
+ */
+const V0_ACCOUNT_DISCRIMINATORS = [
+	[73, 115, 45, 46, 171, 45, 114, 126], // LogoPlacement v0
+	[198, 114, 196, 140, 118, 165, 129, 190] // UserCooldown v0
+]
+
+/**
+ * Check whether account data is still in a v0 layout
+ */
+export function needsMigration(data: Buffer | Uint8Array): boolean {
+	return V0_ACCOUNT_DISCRIMINATORS.some((discriminator) =>
+		discriminator.every((byte, index) => data[index] === byte)
+	)
+}
+
 /**
  * Check if coordinates are occupied by looking up the logo placement
  * This would typically be used with a client to check on-chain data
@@ -231,6 +345,35 @@ export function calculateDistance(
 	return R * c
 }
 
+/**
+ * Resolve a logo URI to a fetchable URL, through public gateways for ipfs:// and ar://
+ */
+export function resolveLogoUrl(logoUri: string): string {
+	if (logoUri.startsWith("ipfs://")) {
+		return IPFS_GATEWAY + logoUri.slice("ipfs://".length)
+	}
+	if (logoUri.startsWith("ar://")) {
+		return ARWEAVE_GATEWAY + logoUri.slice("ar://".length)
+	}
+	return logoUri
+}
+
+/**
+ * SHA-256 of the logo image bytes, passed as `logo_hash` to `place_logo`
+ * Matches `solplace-logo hash`
+ */
+export async function getLogoHash(logoUri: string): Promise<number[]> {
+	const response = await fetch(resolveLogoUrl(logoUri))
+	if (!response.ok) {
+		throw new Error(`Failed to fetch logo ${logoUri}: HTTP ${response.status}`)
+	}
+	const digest = await crypto.subtle.digest(
+		"SHA-256",
+		await response.arrayBuffer()
+	)
+	return Array.from(new Uint8Array(digest))
+}
+
 // ===== TEMPORARY CLUSTER-BASED FUNCTIONS FOR TRANSITIONAL SUPPORT =====
 
 /**